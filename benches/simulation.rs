@@ -0,0 +1,104 @@
+//! Débit de la simulation sur une grande vague : `cargo bench --bench simulation`
+
+use std::{hint::black_box, sync::Arc, time::Instant};
+
+use rust_tower::{
+    application::engine::{monsters::basics::orc, towers::fire_tower::FireTower},
+    domain::{
+        entities::{game::Game, map::Map, position::Position, wave::Wave},
+        mediator::MediatorService,
+        services::notifications::recording::RecordingNotifier,
+    },
+};
+
+const MONSTERS: usize = 1_000;
+const TOWERS: usize = 200;
+const WIDTH: i32 = 64;
+const FRAME: f32 = 1.0 / 60.0;
+/// Temps de jeu laissé aux monstres pour apparaître et se répartir sur le chemin
+const WARMUP_FRAMES: usize = 600;
+const MEASURED_FRAMES: usize = 600;
+
+/// Chemin en lacets sur toute la largeur de la carte, une ligne sur huit
+fn serpentine() -> Vec<Position> {
+    let mut waypoints = Vec::new();
+    for row in 0..6 {
+        let y = 2 + row * 8;
+        let (from, to) = if row % 2 == 0 {
+            (0, WIDTH - 1)
+        } else {
+            (WIDTH - 1, 0)
+        };
+        waypoints.push(Position::new(from, y));
+        waypoints.push(Position::new(to, y));
+    }
+    waypoints
+}
+
+fn setup() -> Game {
+    let mediator = Arc::new(MediatorService::boxed(RecordingNotifier::new()));
+    let mut game = Game::new(mediator, vec![], i32::MAX, 1.0);
+    game.money = u32::MAX;
+
+    let waypoints = serpentine();
+    let start = waypoints[0];
+    game.current_map = Some(Map::new(
+        "Benchmark".to_string(),
+        String::new(),
+        "S".to_string(),
+        "E".to_string(),
+        waypoints,
+        WIDTH as u32,
+        48,
+        vec![orc(start)],
+        None,
+    ));
+
+    // Les tours occupent les lignes entre deux portions du chemin, hors des virages
+    let cells = (0..5).flat_map(|row| (1..WIDTH - 1).map(move |x| Position::new(x, 6 + row * 8)));
+    for position in cells.take(TOWERS) {
+        game.place_tower(FireTower::positionned(position), position)
+            .expect("case libre");
+    }
+
+    // Des monstres assez résistants pour survivre à toute la mesure
+    let monsters = (0..MONSTERS)
+        .map(|_| {
+            let mut monster = orc(start);
+            monster.hp = f32::MAX;
+            monster
+        })
+        .collect();
+    game.wave_index = 1;
+    game.current_wave = Some(Wave::with_staggered_spawn(monsters, 0.01));
+
+    game
+}
+
+fn main() {
+    let mut game = setup();
+    for _ in 0..WARMUP_FRAMES {
+        game.update(FRAME);
+    }
+
+    let alive = game
+        .current_wave
+        .as_ref()
+        .map_or(0, |wave| wave.monsters.len());
+    let start = Instant::now();
+    for _ in 0..MEASURED_FRAMES {
+        game.update(black_box(FRAME));
+    }
+    let elapsed = start.elapsed();
+
+    let per_frame = elapsed / MEASURED_FRAMES as u32;
+    println!(
+        "{} monstres, {} tours : {} images en {:.2?} ({:.2?} par image, {:.0} images/s)",
+        alive,
+        game.towers.len(),
+        MEASURED_FRAMES,
+        elapsed,
+        per_frame,
+        MEASURED_FRAMES as f64 / elapsed.as_secs_f64(),
+    );
+}
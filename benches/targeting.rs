@@ -0,0 +1,83 @@
+//! Recherche de cibles par la grille comparée au parcours de toute la vague :
+//! `cargo bench --bench targeting`
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use rust_tower::{
+    application::engine::monsters::basics::orc,
+    domain::entities::{
+        monster::Monster,
+        position::{Point, Position},
+        spatial::SpatialGrid,
+    },
+};
+
+const TOWERS: usize = 200;
+const RANGE: f32 = 5.0;
+const WIDTH: i32 = 64;
+const HEIGHT: i32 = 48;
+
+fn scatter(count: usize, rng: &mut StdRng) -> Vec<Position> {
+    (0..count)
+        .map(|_| Position::new(rng.random_range(0..WIDTH), rng.random_range(0..HEIGHT)))
+        .collect()
+}
+
+/// Parcours de toute la vague pour chaque tour, tel que le faisaient les tours avant la grille
+fn brute_force_nearest(monsters: &[Monster], tower: Point) -> Option<usize> {
+    let mut nearest = None;
+    let mut min_distance = f32::MAX;
+
+    for (slot, monster) in monsters.iter().enumerate() {
+        if !monster.is_alive() {
+            continue;
+        }
+
+        let distance = tower.distance_to(&monster.position);
+        if distance <= RANGE && distance < min_distance {
+            min_distance = distance;
+            nearest = Some(slot);
+        }
+    }
+
+    nearest
+}
+
+fn nearest_target(c: &mut Criterion) {
+    let mut rng = StdRng::seed_from_u64(42);
+    let towers: Vec<Point> = scatter(TOWERS, &mut rng)
+        .into_iter()
+        .map(Point::from)
+        .collect();
+    let mut group = c.benchmark_group("nearest_target");
+
+    for count in [100, 1_000, 5_000] {
+        let monsters: Vec<Monster> = scatter(count, &mut rng).into_iter().map(orc).collect();
+
+        group.bench_with_input(BenchmarkId::new("brute_force", count), &monsters, |b, m| {
+            b.iter(|| {
+                for tower in &towers {
+                    black_box(brute_force_nearest(m, *tower));
+                }
+            })
+        });
+
+        // La reconstruction fait partie du coût payé à chaque image
+        let mut grid = SpatialGrid::default();
+        group.bench_with_input(BenchmarkId::new("grid", count), &monsters, |b, m| {
+            b.iter(|| {
+                grid.rebuild(m);
+                for tower in &towers {
+                    black_box(grid.nearest(m, *tower, RANGE));
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, nearest_target);
+criterion_main!(benches);
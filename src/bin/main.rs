@@ -1,4 +1,6 @@
-use std::sync::Arc;
+use std::{env, fs, sync::Arc};
+
+use color_eyre::eyre::eyre;
 
 use rust_tower::{
     application::engine::{
@@ -6,17 +8,75 @@ use rust_tower::{
         towers::{fire_tower::FireTower, mine_tower::MineTower, sentinel_tower::SentinelTower},
     },
     domain::{
-        entities::position::Position, mediator::Mediator, services::notifications::NotifierAdapter,
+        entities::position::Position,
+        i18n::Locale,
+        mediator::MediatorService,
+        services::notifications::{
+            composite::CompositeNotifier, desktop::DesktopNotifier, file::FileNotifier,
+            toast::ToastNotifier, webhook::WebhookNotifier,
+        },
+    },
+    infrastructure::{
+        files::{
+            campaign_file, campaign_progress::CampaignProgress, glyphs_file, keymap_file,
+            map_file::MapFile, maps_dir, settings_file,
+        },
+        ui::{
+            app::App,
+            glyphs::{DisplayMode, Glyphs},
+            keymap::Keymap,
+            settings::Settings,
+            tui::Tui,
+        },
     },
-    infrastructure::ui::{app::App, tui::Tui},
 };
 
 fn main() -> color_eyre::Result<()> {
-    let notifier = NotifierAdapter::new();
-    let mediator = Arc::new(Mediator::new(notifier));
-
     color_eyre::install()?;
 
+    // La langue imposée par l'environnement prime sur celle des paramètres, sans les modifier
+    let env_locale = Locale::from_env();
+
+    let settings = match fs::read_to_string(settings_file()) {
+        Ok(content) => {
+            Settings::parse(&content, env_locale.unwrap_or_default()).map_err(|e| eyre!(e))?
+        }
+        Err(_) => Settings::default(),
+    };
+    let locale = env_locale.unwrap_or(settings.locale);
+
+    // Les notifications s'affichent sur le bureau et dans le jeu
+    let toasts = ToastNotifier::new();
+    let mut notifier = CompositeNotifier::new()
+        .with_notifier(DesktopNotifier::new().with_locale(locale))
+        .with_notifier(toasts.clone());
+    if let Ok(path) = env::var("TOWER_DEFENSE_NOTIFICATION_LOG") {
+        notifier = notifier.with_notifier(FileNotifier::new(path));
+    }
+    if let Some(url) = &settings.webhook_url {
+        notifier = notifier.with_notifier(WebhookNotifier::new(url, locale).map_err(|e| eyre!(e))?);
+    }
+    let mediator = Arc::new(MediatorService::boxed(notifier));
+
+    // Le mode passé en ligne de commande prime sur celui des paramètres, sans les modifier
+    let mut glyphs = Glyphs::new(display_mode_from_args().unwrap_or(settings.display_mode));
+    if let Ok(content) = fs::read_to_string(glyphs_file()) {
+        glyphs
+            .load_overrides(&content, locale)
+            .map_err(|e| eyre!(e))?;
+    }
+
+    let keymap = match fs::read_to_string(keymap_file()) {
+        Ok(content) => Keymap::parse(&content, locale).map_err(|e| eyre!(e))?,
+        Err(_) => Keymap::default(),
+    };
+
+    let campaign_progress =
+        CampaignProgress::load(&campaign_file(), locale).map_err(|e| eyre!(e))?;
+
+    let mut maps = vec![ForestMap::new(), DesertMap::new(), CaveMap::new()];
+    maps.extend(MapFile::load_dir(&maps_dir()));
+
     let mut app = App::new(
         mediator,
         vec![
@@ -24,8 +84,14 @@ fn main() -> color_eyre::Result<()> {
             SentinelTower::positionned(Position::new(0, 0)),
             MineTower::positionned(Position::new(0, 0)),
         ],
-        vec![ForestMap::new(), DesertMap::new(), CaveMap::new()],
-    );
+        maps,
+        glyphs,
+    )
+    .with_keymap(keymap)
+    .with_settings(settings)
+    .with_locale(locale)
+    .with_campaign_progress(campaign_progress)
+    .with_toasts(toasts);
 
     let mut tui = Tui::new()?;
     tui.init()?;
@@ -35,3 +101,12 @@ fn main() -> color_eyre::Result<()> {
 
     Ok(())
 }
+
+/// Lit le mode d'affichage depuis la ligne de commande (`--ascii` ou `--display=<emoji|ascii>`)
+fn display_mode_from_args() -> Option<DisplayMode> {
+    env::args().skip(1).find_map(|arg| match arg.as_str() {
+        "--ascii" => Some(DisplayMode::Ascii),
+        "--emoji" => Some(DisplayMode::Emoji),
+        _ => DisplayMode::from_name(arg.strip_prefix("--display=")?),
+    })
+}
@@ -1,3 +1,4 @@
+pub mod campaign;
 pub mod maps;
 pub mod monsters;
 pub mod towers;
@@ -0,0 +1,161 @@
+use std::collections::VecDeque;
+
+use crate::{
+    application::engine::{
+        maps::{
+            cave::CaveMap,
+            desert::DesertMap,
+            forest::ForestMap,
+            random::{MapDifficulty, RandomMap},
+        },
+        monsters::basics::monster_from_key,
+    },
+    domain::{
+        entities::{map::Map, position::Position, wave::Wave},
+        i18n::Locale,
+    },
+};
+
+/// Tours disponibles dès le premier niveau de la campagne
+pub const STARTING_TOWERS: [&str; 1] = ["Fire Tower"];
+
+/// Groupe de monstres d'une vague scriptée : clé du monstre et nombre
+pub type WaveGroup = (&'static str, u32);
+
+/// Niveau de la campagne : une carte et une suite de vagues à survivre
+pub struct CampaignLevel {
+    /// Identifiant stable utilisé pour enregistrer la progression
+    pub id: &'static str,
+    /// Identifiants des nom et description du niveau dans les traductions
+    pub name_id: &'static str,
+    pub description_id: &'static str,
+    pub map: fn() -> Map,
+    pub waves: Vec<Vec<WaveGroup>>,
+    /// Progression des points de vie des monstres d'une vague à l'autre
+    pub hp_growth: f32,
+    /// Tours débloquées lorsque le niveau est réussi
+    pub unlocks_towers: Vec<&'static str>,
+}
+
+impl CampaignLevel {
+    pub fn name(&self, locale: Locale) -> &'static str {
+        locale.text(self.name_id)
+    }
+
+    pub fn description(&self, locale: Locale) -> &'static str {
+        locale.text(self.description_id)
+    }
+
+    /// Construit les vagues scriptées du niveau pour la carte donnée
+    ///
+    /// La difficulté choisie module la progression des points de vie avec `wave_multiplier`.
+    pub fn build_waves(
+        &self,
+        map: &Map,
+        spawn_interval: f32,
+        wave_multiplier: f32,
+    ) -> VecDeque<Wave> {
+        let start_position = map
+            .waypoints
+            .first()
+            .copied()
+            .unwrap_or(Position::initial());
+
+        self.waves
+            .iter()
+            .enumerate()
+            .map(|(index, groups)| {
+                let hp_factor = 1.0 + index as f32 * self.hp_growth * wave_multiplier;
+                let monsters = groups
+                    .iter()
+                    .flat_map(|(key, count)| (0..*count).map(move |_| *key))
+                    .filter_map(|key| monster_from_key(key, start_position))
+                    .map(|mut monster| {
+                        monster.hp *= hp_factor;
+                        monster
+                    })
+                    .collect();
+
+                Wave::with_staggered_spawn(monsters, spawn_interval)
+            })
+            .collect()
+    }
+
+    /// Étoiles obtenues selon la part de vies restantes : 3 sans perte, 2 à partir de la moitié
+    pub fn stars(player_life: i32, starting_life: i32) -> u8 {
+        match player_life {
+            life if life <= 0 => 0,
+            life if life >= starting_life => 3,
+            life if life * 2 >= starting_life => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// Niveaux de la campagne, dans l'ordre
+pub fn levels() -> Vec<CampaignLevel> {
+    vec![
+        CampaignLevel {
+            id: "forest",
+            name_id: "campaign.forest",
+            description_id: "campaign.forest.description",
+            map: ForestMap::new,
+            waves: vec![
+                vec![("goblin", 3)],
+                vec![("goblin", 4)],
+                vec![("goblin", 3), ("orc", 1)],
+            ],
+            hp_growth: 0.2,
+            unlocks_towers: vec!["Mine"],
+        },
+        CampaignLevel {
+            id: "desert",
+            name_id: "campaign.desert",
+            description_id: "campaign.desert.description",
+            map: DesertMap::new,
+            waves: vec![
+                vec![("goblin", 4)],
+                vec![("orc", 2)],
+                vec![("goblin", 4), ("orc", 2)],
+                vec![("orc", 4)],
+            ],
+            hp_growth: 0.3,
+            unlocks_towers: vec!["Sentinel Tower"],
+        },
+        CampaignLevel {
+            id: "cave",
+            name_id: "campaign.cave",
+            description_id: "campaign.cave.description",
+            map: CaveMap::new,
+            waves: vec![
+                vec![("goblin", 5)],
+                vec![("goblin", 4), ("orc", 3)],
+                vec![("orc", 6)],
+                vec![("goblin", 6), ("orc", 4)],
+                vec![("orc", 8)],
+            ],
+            hp_growth: 0.4,
+            unlocks_towers: vec![],
+        },
+        CampaignLevel {
+            id: "labyrinth",
+            name_id: "campaign.labyrinth",
+            description_id: "campaign.labyrinth.description",
+            map: || {
+                RandomMap::new(4242, 20, 20, MapDifficulty::Hard)
+                    .generate()
+                    .with_label_id("map.labyrinth")
+            },
+            waves: vec![
+                vec![("goblin", 6)],
+                vec![("orc", 4)],
+                vec![("goblin", 8), ("orc", 4)],
+                vec![("orc", 8)],
+                vec![("goblin", 10), ("orc", 6)],
+                vec![("orc", 12)],
+            ],
+            hp_growth: 0.5,
+            unlocks_towers: vec![],
+        },
+    ]
+}
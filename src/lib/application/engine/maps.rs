@@ -1,3 +1,5 @@
 pub mod cave;
 pub mod desert;
 pub mod forest;
+pub mod random;
+pub mod terrain;
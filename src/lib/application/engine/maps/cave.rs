@@ -1,11 +1,15 @@
 use crate::{
     application::engine::monsters::basics::{goblin, orc},
-    domain::entities::{map::Map, position::Position},
+    domain::entities::{
+        map::{Map, WaveScaling},
+        position::Position,
+    },
 };
 
 pub struct CaveMap;
 
 impl CaveMap {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new() -> Map {
         let start_position = Position::new(0, 5);
 
@@ -26,12 +30,15 @@ impl CaveMap {
                 Position::new(18, 7),
                 Position::new(20, 7),
                 Position::new(20, 12),
-                Position::new(40, 17),
+                Position::new(39, 16),
             ],
             40, // largeur de la carte
             17, // hauteur de la carte
             vec![goblin(start_position), orc(start_position)],
             None,
         )
+        // Caverne : peu de monstres, mais plus résistants
+        .with_scaling(WaveScaling::new(1.2, 1.08, 0.08))
+        .with_label_id("map.cave")
     }
 }
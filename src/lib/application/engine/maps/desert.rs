@@ -1,11 +1,15 @@
 use crate::{
     application::engine::monsters::basics::{goblin, orc},
-    domain::entities::{map::Map, position::Position},
+    domain::entities::{
+        map::{Map, WaveScaling},
+        position::Position,
+    },
 };
 
 pub struct DesertMap;
 
 impl DesertMap {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new() -> Map {
         let start_position = Position::new(0, 10);
 
@@ -22,12 +26,15 @@ impl DesertMap {
                 Position::new(10, 15),
                 Position::new(15, 15),
                 Position::new(15, 5),
-                Position::new(20, 5),
+                Position::new(19, 5),
             ],
             20, // largeur de la carte
             20, // hauteur de la carte
             vec![goblin(start_position), orc(start_position)],
             None,
         )
+        // Désert : des vagues plus nombreuses
+        .with_scaling(WaveScaling::new(1.18, 1.12, 0.05))
+        .with_label_id("map.desert")
     }
 }
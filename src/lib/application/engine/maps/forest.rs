@@ -6,6 +6,7 @@ use crate::{
 pub struct ForestMap;
 
 impl ForestMap {
+    #[allow(clippy::new_ret_no_self)]
     pub fn new() -> Map {
         let start_position = Position::new(0, 7);
 
@@ -22,12 +23,13 @@ impl ForestMap {
                 Position::new(10, 12),
                 Position::new(15, 12),
                 Position::new(15, 7),
-                Position::new(20, 7),
+                Position::new(19, 7),
             ],
             20, // largeur de la carte
             20, // hauteur de la carte
             vec![goblin(start_position), orc(start_position)],
             None,
         )
+        .with_label_id("map.forest")
     }
 }
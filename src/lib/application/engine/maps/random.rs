@@ -0,0 +1,248 @@
+use rand::{Rng, SeedableRng, rngs::StdRng};
+
+use crate::{
+    application::engine::monsters::basics::{goblin, orc},
+    domain::{
+        entities::{
+            map::{Decoration, MAX_MAP_SIZE, Map},
+            monster::Monster,
+            position::Position,
+        },
+        i18n::Locale,
+    },
+};
+
+/// Niveau de difficulté d'une carte générée
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MapDifficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+impl MapDifficulty {
+    pub fn label(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            MapDifficulty::Easy => "map_difficulty.easy",
+            MapDifficulty::Normal => "map_difficulty.normal",
+            MapDifficulty::Hard => "map_difficulty.hard",
+        })
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            MapDifficulty::Easy => MapDifficulty::Normal,
+            MapDifficulty::Normal => MapDifficulty::Hard,
+            MapDifficulty::Hard => MapDifficulty::Easy,
+        }
+    }
+
+    pub fn previous(&self) -> Self {
+        match self {
+            MapDifficulty::Easy => MapDifficulty::Hard,
+            MapDifficulty::Normal => MapDifficulty::Easy,
+            MapDifficulty::Hard => MapDifficulty::Normal,
+        }
+    }
+
+    /// Nombre de virages visé : plus le chemin est sinueux, plus les tours ont le temps de tirer
+    fn turns(&self) -> u32 {
+        match self {
+            MapDifficulty::Easy => 8,
+            MapDifficulty::Normal => 6,
+            MapDifficulty::Hard => 4,
+        }
+    }
+
+    /// Longueur du chemin visée (en cases) pour une carte de la taille donnée
+    fn target_length(&self, width: u32, height: u32) -> u32 {
+        match self {
+            MapDifficulty::Easy => (width + height) * 2,
+            MapDifficulty::Normal => (width + height) * 3 / 2,
+            MapDifficulty::Hard => width + height / 2,
+        }
+    }
+
+    fn roster(&self, start_position: Position) -> Vec<Monster> {
+        match self {
+            MapDifficulty::Easy => vec![
+                goblin(start_position),
+                goblin(start_position),
+                orc(start_position),
+            ],
+            MapDifficulty::Normal => vec![goblin(start_position), orc(start_position)],
+            MapDifficulty::Hard => vec![
+                goblin(start_position),
+                orc(start_position),
+                orc(start_position),
+            ],
+        }
+    }
+}
+
+/// Thèmes visuels possibles pour une carte générée
+const THEMES: [(&str, &str, [&str; 3]); 3] = [
+    ("random_map.forest", "🌳", ["🌲", "🌳", "🍄"]),
+    ("random_map.desert", "🌵", ["🌵", "🪨", "🦴"]),
+    ("random_map.cave", "🦇", ["🪨", "💎", "🦇"]),
+];
+
+/// Taille minimale d'une carte générée, en deçà le chemin ne peut plus faire de virages
+pub const MIN_GENERATED_SIZE: u32 = 8;
+/// Taille par défaut d'une carte générée
+pub const DEFAULT_GENERATED_SIZE: u32 = 20;
+
+/// Générateur de cartes procédural, reproductible à partir d'une graine
+pub struct RandomMap {
+    pub seed: u64,
+    pub width: u32,
+    pub height: u32,
+    pub difficulty: MapDifficulty,
+    /// Langue du nom et de la description de la carte
+    pub locale: Locale,
+}
+
+impl RandomMap {
+    pub fn new(seed: u64, width: u32, height: u32, difficulty: MapDifficulty) -> Self {
+        Self {
+            seed,
+            width: width.clamp(MIN_GENERATED_SIZE, MAX_MAP_SIZE),
+            height: height.clamp(MIN_GENERATED_SIZE, MAX_MAP_SIZE),
+            difficulty,
+            locale: Locale::default(),
+        }
+    }
+
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Génère la carte : la même graine produit toujours la même carte
+    pub fn generate(&self) -> Map {
+        let mut rng = StdRng::seed_from_u64(self.seed);
+
+        // Le chemin traverse la carte de gauche à droite ou de haut en bas
+        let vertical = rng.random_bool(0.5);
+        let (along, across) = if vertical {
+            (self.height, self.width)
+        } else {
+            (self.width, self.height)
+        };
+
+        let waypoints = self
+            .generate_path(&mut rng, along, across)
+            .into_iter()
+            .map(|p| if vertical { Position::new(p.y, p.x) } else { p })
+            .collect::<Vec<_>>();
+
+        let (theme, start_symbol, decoration_symbols) = THEMES[rng.random_range(0..THEMES.len())];
+        let start_position = waypoints[0];
+
+        let map = Map::new(
+            self.locale
+                .format("random_map.name", &[("seed", &self.seed)]),
+            self.locale.format(
+                "random_map.description",
+                &[
+                    ("theme", &self.locale.text(theme)),
+                    ("width", &self.width),
+                    ("height", &self.height),
+                    (
+                        "difficulty",
+                        &self.difficulty.label(self.locale).to_lowercase(),
+                    ),
+                ],
+            ),
+            start_symbol.to_string(),
+            "🏠".to_string(),
+            waypoints,
+            self.width,
+            self.height,
+            self.difficulty.roster(start_position),
+            None,
+        );
+
+        let decorations = self.generate_decorations(&mut rng, &map, &decoration_symbols);
+        map.with_decorations(decorations)
+    }
+
+    /// Construit un chemin monotone le long de l'axe principal, ce qui garantit
+    /// qu'il ne se croise jamais lui-même
+    fn generate_path(&self, rng: &mut StdRng, along: u32, across: u32) -> Vec<Position> {
+        let along = along as i32;
+        let across = across as i32;
+
+        // Chaque détour vertical ajoute deux virages, espacés d'au moins deux cases
+        let max_detours = ((along - 4) / 3).max(1) as u32;
+        let detours = (self.difficulty.turns() / 2).clamp(1, max_detours) as i32;
+
+        let horizontal_length = along - 1;
+        let mut vertical_budget =
+            (self.difficulty.target_length(along as u32, across as u32) as i32 - horizontal_length)
+                .max(detours);
+
+        let mut y = rng.random_range(1..across - 1);
+        let mut waypoints = vec![Position::new(0, y)];
+
+        let slot = (along - 4) / detours;
+        for i in 0..detours {
+            let slot_start = 2 + i * slot;
+            let x = rng.random_range(slot_start..=slot_start + (slot - 2).max(0));
+
+            let wanted = vertical_budget / (detours - i);
+            let jitter = rng.random_range(-(wanted / 3)..=(wanted / 3));
+            let wanted = (wanted + jitter).max(1);
+
+            // Aller vers le côté qui laisse le plus de place, en restant dans la carte
+            let room_up = y;
+            let room_down = across - 1 - y;
+            let dy = if room_down >= room_up {
+                wanted.min(room_down)
+            } else {
+                -wanted.min(room_up)
+            };
+
+            waypoints.push(Position::new(x, y));
+            y += dy;
+            waypoints.push(Position::new(x, y));
+            vertical_budget -= dy.abs();
+        }
+
+        waypoints.push(Position::new(along - 1, y));
+        waypoints
+    }
+
+    fn generate_decorations(
+        &self,
+        rng: &mut StdRng,
+        map: &Map,
+        symbols: &[&str],
+    ) -> Vec<Decoration> {
+        let count = (self.width * self.height / 15) as usize;
+        let mut decorations: Vec<Decoration> = Vec::with_capacity(count);
+
+        for _ in 0..count * 4 {
+            if decorations.len() >= count {
+                break;
+            }
+
+            let position = Position::new(
+                rng.random_range(0..self.width as i32),
+                rng.random_range(0..self.height as i32),
+            );
+
+            let taken = map.is_position_on_path(&position)
+                || decorations
+                    .iter()
+                    .any(|d| d.position.x == position.x && d.position.y == position.y);
+
+            if !taken {
+                let symbol = symbols[rng.random_range(0..symbols.len())];
+                decorations.push(Decoration::new(position, symbol.to_string()));
+            }
+        }
+
+        decorations
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use crate::domain::entities::{map::TerrainModifier, position::Position};
+
+/// Marécage : les monstres y avancent plus lentement
+pub struct Swamp;
+
+impl TerrainModifier for Swamp {
+    fn key(&self) -> &'static str {
+        "swamp"
+    }
+
+    fn speed_factor(&self, _position: Position) -> f32 {
+        0.75
+    }
+}
+
+/// Route pavée : les monstres y avancent plus vite
+pub struct Road;
+
+impl TerrainModifier for Road {
+    fn key(&self) -> &'static str {
+        "road"
+    }
+
+    fn speed_factor(&self, _position: Position) -> f32 {
+        1.25
+    }
+}
+
+/// Fonction construisant un terrain
+pub type TerrainFactory = fn() -> Arc<dyn TerrainModifier>;
+
+/// Terrains disponibles, identifiés par la clé utilisée dans les fichiers de carte
+pub const TERRAINS: [(&str, TerrainFactory); 2] =
+    [("swamp", || Arc::new(Swamp)), ("road", || Arc::new(Road))];
+
+/// Construit un terrain à partir de sa clé
+pub fn terrain_from_key(key: &str) -> Option<Arc<dyn TerrainModifier>> {
+    TERRAINS
+        .iter()
+        .find(|(terrain_key, _)| *terrain_key == key)
+        .map(|(_, build)| build())
+}
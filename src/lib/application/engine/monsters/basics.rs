@@ -24,3 +24,25 @@ pub fn orc(position: Position) -> Monster {
         2,
     )
 }
+
+/// Fonction construisant un monstre à sa position de départ
+pub type MonsterFactory = fn(Position) -> Monster;
+
+/// Monstres disponibles, identifiés par une clé stable utilisée dans les fichiers de carte
+pub const ROSTER: [(&str, MonsterFactory); 2] = [("goblin", goblin), ("orc", orc)];
+
+/// Construit un monstre à partir de sa clé
+pub fn monster_from_key(key: &str, position: Position) -> Option<Monster> {
+    ROSTER
+        .iter()
+        .find(|(roster_key, _)| *roster_key == key)
+        .map(|(_, build)| build(position))
+}
+
+/// Retrouve la clé d'un monstre à partir de son nom
+pub fn monster_key(monster: &Monster) -> Option<&'static str> {
+    ROSTER
+        .iter()
+        .find(|(_, build)| build(monster.cell()).name == monster.name)
+        .map(|(key, _)| *key)
+}
@@ -20,7 +20,7 @@ impl FireTower {
             vec![
                 TowerStats {
                     stat_type: TowerStatType::Range,
-                    label: "Range".to_string(),
+                    label_id: "stat.range".to_string(),
                     icon: "🔥".to_string(),
                     base: 5.0,
                     level: 1,
@@ -33,7 +33,7 @@ impl FireTower {
                 },
                 TowerStats {
                     stat_type: TowerStatType::Damage,
-                    label: "Damage".to_string(),
+                    label_id: "stat.damage".to_string(),
                     icon: "🔥".to_string(),
                     base: 10.0,
                     level: 1,
@@ -46,7 +46,7 @@ impl FireTower {
                 },
                 TowerStats {
                     stat_type: TowerStatType::AttackSpeed,
-                    label: "Attack Speed".to_string(),
+                    label_id: "stat.attack_speed".to_string(),
                     icon: "🔥".to_string(),
                     base: 0.5,
                     level: 1,
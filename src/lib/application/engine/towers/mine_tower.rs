@@ -1,10 +1,18 @@
-use std::{rc::Rc, vec};
+use std::{sync::Arc, vec};
 
 use ratatui::style::Color;
 
-use crate::domain::entities::{
-    position::Position,
-    tower::{Tower, TowerStatType, TowerStatUpgrade, TowerStats, TowerUpgradeElementUnit},
+use crate::domain::{
+    entities::{
+        game::Game,
+        position::Position,
+        tower::{
+            Tower, TowerAbility, TowerStatType, TowerStatUpgrade, TowerStats,
+            TowerUpgradeElementUnit,
+        },
+    },
+    error::GameError,
+    mediator::MediatorService,
 };
 
 pub struct MineTower;
@@ -20,7 +28,7 @@ impl MineTower {
             vec![
                 TowerStats {
                     stat_type: TowerStatType::Money,
-                    label: "Money".to_string(),
+                    label_id: "stat.money".to_string(),
                     icon: "💰".to_string(),
                     base: 10.0,
                     level: 1,
@@ -33,7 +41,7 @@ impl MineTower {
                 },
                 TowerStats {
                     stat_type: TowerStatType::AttackSpeed,
-                    label: "Generation speed".to_string(),
+                    label_id: "stat.generation_speed".to_string(),
                     icon: "🔥".to_string(),
                     base: 0.5,
                     level: 1,
@@ -46,22 +54,33 @@ impl MineTower {
                 },
             ],
             None,
-            Some(Rc::new(|_, game, tower| {
-                tower.highlight = if tower.highlight.is_some() {
-                    None
-                } else {
-                    Some(Color::Yellow)
-                };
+            Some(Arc::new(MineIncome)),
+        )
+    }
+}
 
-                let money = tower
-                    .stats
-                    .iter()
-                    .find(|s| s.stat_type == TowerStatType::Money)
-                    .unwrap();
+/// Rapporte de l'argent à chaque cycle de production
+pub struct MineIncome;
 
-                game.money += money.base as u32;
-                Ok(())
-            })),
-        )
+impl TowerAbility for MineIncome {
+    fn on_shoot(
+        &self,
+        _mediator: &MediatorService,
+        game: &mut Game,
+        tower: &mut Tower,
+    ) -> Result<(), GameError> {
+        tower.highlight = if tower.highlight.is_some() {
+            None
+        } else {
+            Some(Color::Yellow)
+        };
+
+        let money = tower
+            .stat_block
+            .money
+            .ok_or(GameError::MissingStat(TowerStatType::Money))?;
+
+        game.add_money(money as u32);
+        Ok(())
     }
 }
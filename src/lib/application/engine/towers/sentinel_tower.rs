@@ -1,11 +1,14 @@
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::domain::{
     entities::{
-        position::Position,
-        tower::{Tower, TowerStatType, TowerStats},
+        game::Game,
+        position::{Point, Position},
+        tower::{Tower, TowerAbility, TowerStatType, TowerStats},
     },
-    ports::notifier::Notifier,
+    error::GameError,
+    mediator::MediatorService,
+    services::notifications::policy::{Notification, NotificationCategory},
 };
 
 pub struct SentinelTower;
@@ -21,7 +24,7 @@ impl SentinelTower {
             vec![
                 TowerStats {
                     stat_type: TowerStatType::Range,
-                    label: "Range".to_string(),
+                    label_id: "stat.range".to_string(),
                     icon: "🔭".to_string(),
                     base: 2.0,
                     level: 1,
@@ -29,7 +32,7 @@ impl SentinelTower {
                 },
                 TowerStats {
                     stat_type: TowerStatType::AttackSpeed,
-                    label: "Attack Speed".to_string(),
+                    label_id: "stat.attack_speed".to_string(),
                     icon: "⏰".to_string(),
                     base: 1.0,
                     level: 1,
@@ -37,49 +40,61 @@ impl SentinelTower {
                 },
             ],
             None,
-            Some(Rc::new(|mediator, game, tower| {
-                let mut monsters_detected = false;
-
-                if let Some(wave) = &mut game.current_wave {
-                    let monsters = wave
-                        .monsters
-                        .iter_mut()
-                        .filter(|monster| !monster.detected.contains(&tower.id));
+            Some(Arc::new(SentinelDetection)),
+        )
+    }
+}
 
-                    for monster in monsters {
-                        let distance = tower.position.distance_to(&monster.position);
-                        let range = tower
-                            .stats
-                            .iter()
-                            .find(|stat| stat.stat_type == TowerStatType::Range)
-                            .unwrap()
-                            .base;
+/// Notifie le joueur lorsqu'un monstre entre dans la portée de la sentinelle
+pub struct SentinelDetection;
 
-                        if distance.floor() <= range {
-                            monsters_detected = true;
-                            monster.detected.push(tower.id);
+impl TowerAbility for SentinelDetection {
+    fn on_shoot(
+        &self,
+        mediator: &MediatorService,
+        game: &mut Game,
+        tower: &mut Tower,
+    ) -> Result<(), GameError> {
+        let Some(wave) = &mut game.current_wave else {
+            return Ok(());
+        };
 
-                            break;
-                        }
-                    }
+        let range = tower
+            .stat_block
+            .range
+            .ok_or(GameError::MissingStat(TowerStatType::Range))?;
 
-                    if !monsters_detected {
-                        return Ok(());
-                    }
+        // Un monstre est repéré dès que la partie entière de sa distance atteint la portée
+        let origin = Point::from(tower.position);
+        let detected = game
+            .monster_grid
+            .within(&wave.monsters, origin, range.floor() + 1.0)
+            .into_iter()
+            .filter_map(|slot| wave.monsters.get(slot))
+            .find(|monster| {
+                origin.distance_to(&monster.position).floor() <= range
+                    && !monster.detected.contains(&tower.id)
+            })
+            .map(|monster| monster.id);
 
-                    mediator.notifier.send_notification(
-                        "⚠️ Monstre détecté",
-                        &format!(
-                            "Un monstre approche de la tour Sentinelle en ({}, {})",
-                            tower.position.x, tower.position.y
-                        ),
-                    );
+        let Some(monster) = detected.and_then(|id| wave.monsters.entity_mut(id)) else {
+            return Ok(());
+        };
+        monster.detected.push(tower.id);
 
-                    Ok(())
-                } else {
-                    Ok(())
-                }
-            })),
+        let notification = Notification::new(
+            NotificationCategory::MonsterDetected,
+            format!("⚠️ {}", game.locale.text("sentinel.title")),
+            game.locale.format(
+                "sentinel.message",
+                &[("x", &tower.position.x), ("y", &tower.position.y)],
+            ),
+            game.locale,
         )
+        .with_context(game.notification_context());
+
+        mediator
+            .notify(notification)
+            .map_err(GameError::Notification)
     }
 }
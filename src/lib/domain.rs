@@ -1,4 +1,6 @@
 pub mod entities;
+pub mod error;
+pub mod i18n;
 pub mod mediator;
 pub mod ports;
 pub mod services;
@@ -1,8 +1,14 @@
 pub mod behavior;
+pub mod command;
+pub mod components;
 pub mod element;
+pub mod endless;
+pub mod event;
 pub mod game;
 pub mod map;
 pub mod monster;
 pub mod position;
+pub mod rules;
+pub mod spatial;
 pub mod tower;
 pub mod wave;
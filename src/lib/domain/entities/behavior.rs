@@ -1,4 +1,4 @@
-use super::monster::Monster;
+use super::{element::Element, monster::Monster};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TowerBehavior {
@@ -24,6 +24,17 @@ impl TowerBehavior {
         }
     }
 
+    /// Élément des dégâts infligés par ce comportement
+    pub fn element(&self) -> Element {
+        match self {
+            TowerBehavior::Basic => Element::Neutral,
+            TowerBehavior::Frost { .. } => Element::Ice,
+            TowerBehavior::Burning { .. } => Element::Fire,
+            TowerBehavior::Lightning { .. } => Element::Lightning,
+            TowerBehavior::Crusher { .. } => Element::Earth,
+        }
+    }
+
     pub fn name(&self) -> &str {
         match self {
             TowerBehavior::Basic => "Basic",
@@ -36,23 +47,12 @@ impl TowerBehavior {
 }
 
 fn apply_frost(monster: &mut Monster, damage: f32, slow_factor: f32) -> f32 {
-    let original_speed = monster.movement_speed;
     monster.movement_speed *= 1.0 - slow_factor;
-    println!(
-        "❄️ EFFET: Monstre ralenti de {:.1} à {:.1} (facteur {:.0}%)",
-        original_speed,
-        monster.movement_speed,
-        slow_factor * 100.0
-    );
     damage
 }
 
 fn apply_burning(monster: &mut Monster, damage: f32, dot_damage: f32) -> f32 {
     monster.hp -= dot_damage;
-    println!(
-        "☠️ EFFET: Poison inflige {:.1} dégâts supplémentaires",
-        dot_damage
-    );
     damage
 }
 
@@ -64,14 +64,7 @@ fn apply_lightning(_monster: &mut Monster, damage: f32, _stun_chance: f32) -> f3
 fn apply_crusher(monster: &mut Monster, damage: f32, armor_reduction: f32) -> f32 {
     let original_armor = monster.armor;
     monster.armor = (monster.armor * (1.0 - armor_reduction)).max(0.0);
-    let actual_damage = damage * (1.0 / (1.0 - (original_armor - monster.armor)));
-    println!(
-        "🪨 EFFET: Réduction d'armure de {:.1} à {:.1} (facteur {:.0}%)",
-        original_armor,
-        monster.armor,
-        armor_reduction * 100.0
-    );
-    actual_damage
+    damage * (1.0 / (1.0 - (original_armor - monster.armor)))
 }
 
 pub fn apply_slow(monster: &mut Monster, damage: f32, slow_factor: f32) -> f32 {
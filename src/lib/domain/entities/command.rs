@@ -0,0 +1,75 @@
+use crate::domain::error::GameError;
+
+use super::{
+    components::EntityId,
+    event::GameEvent,
+    game::Game,
+    position::Position,
+    tower::{TargetSelection, Tower, TowerStatType},
+};
+
+/// Action demandée à la partie par un joueur, un bot ou une interface
+#[derive(Clone)]
+pub enum GameCommand {
+    PlaceTower {
+        tower: Tower,
+        position: Position,
+    },
+    SellTower(Position),
+    UpgradeTower {
+        tower: EntityId,
+        stat: TowerStatType,
+    },
+    SetTargeting {
+        tower: EntityId,
+        target: TargetSelection,
+    },
+    /// Passe à la stratégie de ciblage suivante
+    CycleTargeting(EntityId),
+    /// Lance la vague suivante, éventuellement avant la fin de la vague en cours
+    StartWave,
+}
+
+/// Événements émis par une commande acceptée, ou la raison de son refus
+pub type CommandResult = Result<Vec<GameEvent>, GameError>;
+
+/// Point d'entrée unique de la simulation, partagé par toutes les interfaces
+///
+/// Les commandes sont validées par la partie : une commande refusée ne modifie rien.
+pub trait GameEngine {
+    fn execute(&mut self, command: GameCommand) -> CommandResult;
+
+    /// Fait avancer la simulation de `delta_time` secondes
+    fn tick(&mut self, delta_time: f32);
+}
+
+impl GameEngine for Game {
+    fn execute(&mut self, command: GameCommand) -> CommandResult {
+        let first = self.replay.recorded;
+
+        match command {
+            GameCommand::PlaceTower { tower, position } => self.place_tower(tower, position)?,
+            GameCommand::SellTower(position) => {
+                self.sell_tower(position)?;
+            }
+            GameCommand::UpgradeTower { tower, stat } => {
+                self.upgrade_tower(tower, stat)?;
+            }
+            GameCommand::SetTargeting { tower, target } => self.set_targeting(tower, target)?,
+            GameCommand::CycleTargeting(tower) => {
+                self.cycle_targeting(tower)?;
+            }
+            GameCommand::StartWave => self.start_wave()?,
+        }
+
+        Ok(self
+            .replay
+            .since(first)
+            .map(|recorded| recorded.event.clone())
+            .collect())
+    }
+
+    fn tick(&mut self, delta_time: f32) {
+        self.update(delta_time);
+    }
+}
@@ -0,0 +1,145 @@
+use std::{collections::HashMap, ops::Deref, slice};
+
+use uuid::Uuid;
+
+/// Identifiant stable d'une entité de la partie, indépendant de sa place dans le stockage
+pub type EntityId = Uuid;
+
+/// Entité rangée dans un stockage de composants
+pub trait Entity {
+    fn id(&self) -> EntityId;
+}
+
+/// Entités d'un même type, rangées de façon contiguë pour être parcourues à chaque image
+/// et retrouvées par identifiant sans parcourir tout le stockage
+///
+/// L'ordre d'insertion est conservé. La lecture passe par la tranche sous-jacente, les
+/// modifications par les méthodes du stockage afin de garder l'index à jour.
+#[derive(Debug, Clone)]
+pub struct Components<T> {
+    items: Vec<T>,
+    index: HashMap<EntityId, usize>,
+}
+
+impl<T> Default for Components<T> {
+    fn default() -> Self {
+        Self {
+            items: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+}
+
+impl<T: Entity> Components<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ajoute l'entité, ignorée et `None` si son identifiant est déjà utilisé
+    pub fn insert(&mut self, item: T) -> Option<EntityId> {
+        let id = item.id();
+        if self.index.contains_key(&id) {
+            return None;
+        }
+
+        self.index.insert(id, self.items.len());
+        self.items.push(item);
+        Some(id)
+    }
+
+    /// Place de l'entité dans le stockage, qui peut changer lors d'une suppression
+    pub fn position(&self, id: EntityId) -> Option<usize> {
+        self.index.get(&id).copied()
+    }
+
+    pub fn entity(&self, id: EntityId) -> Option<&T> {
+        self.position(id).map(|index| &self.items[index])
+    }
+
+    pub fn entity_mut(&mut self, id: EntityId) -> Option<&mut T> {
+        self.position(id).map(|index| &mut self.items[index])
+    }
+
+    pub fn remove(&mut self, id: EntityId) -> Option<T> {
+        let index = self.index.remove(&id)?;
+        let item = self.items.remove(index);
+
+        for (offset, moved) in self.items[index..].iter().enumerate() {
+            self.index.insert(moved.id(), index + offset);
+        }
+
+        Some(item)
+    }
+
+    /// Ne garde que les entités pour lesquelles `keep` renvoie `true`
+    pub fn retain(&mut self, keep: impl FnMut(&T) -> bool) {
+        let count = self.items.len();
+        self.items.retain(keep);
+
+        // Sans suppression, chaque entité garde sa place
+        if self.items.len() != count {
+            self.reindex();
+        }
+    }
+
+    /// Parcourt les entités pour les modifier, sans changer leur identifiant
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.items.iter_mut()
+    }
+
+    fn reindex(&mut self) {
+        self.index.clear();
+        for (index, item) in self.items.iter().enumerate() {
+            self.index.insert(item.id(), index);
+        }
+    }
+}
+
+impl<T> Deref for Components<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T: Entity> From<Vec<T>> for Components<T> {
+    /// Les entités dont l'identifiant est déjà utilisé sont ignorées, comme avec `insert`
+    fn from(items: Vec<T>) -> Self {
+        let mut components = Self::default();
+        components.extend(items);
+        components
+    }
+}
+
+impl<T: Entity> FromIterator<T> for Components<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Vec<_>>())
+    }
+}
+
+impl<T: Entity> Extend<T> for Components<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.insert(item);
+        }
+    }
+}
+
+impl<T> IntoIterator for Components<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Components<T> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.iter()
+    }
+}
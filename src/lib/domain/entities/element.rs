@@ -11,6 +11,17 @@ pub enum Element {
 }
 
 impl Element {
+    pub const ALL: [Element; 8] = [
+        Element::Neutral,
+        Element::Fire,
+        Element::Water,
+        Element::Earth,
+        Element::Air,
+        Element::Lightning,
+        Element::Ice,
+        Element::Poison,
+    ];
+
     pub fn get_name(&self) -> &str {
         match self {
             Element::Neutral => "neutral",
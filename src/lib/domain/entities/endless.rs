@@ -0,0 +1,122 @@
+use rand::Rng;
+
+use crate::domain::i18n::Locale;
+
+use super::{element::Element, monster::Monster};
+
+/// Nombre de monstres de la première vague du mode sans fin
+pub const ENDLESS_BASE_COUNT: u32 = 5;
+/// Nombre maximal de monstres dans une vague du mode sans fin
+pub const ENDLESS_MAX_COUNT: u32 = 60;
+
+/// Vague à partir de laquelle apparaissent les monstres élémentaires
+const ELEMENTAL_WAVE: u32 = 3;
+/// Vague à partir de laquelle apparaissent les monstres cuirassés
+const ARMORED_WAVE: u32 = 6;
+/// Vague à partir de laquelle les élites sont à la fois élémentaires et cuirassées
+const CHAMPION_WAVE: u32 = 10;
+
+/// Déroulement d'une partie
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GameMode {
+    /// Partie classique
+    #[default]
+    Standard,
+    /// Vagues de plus en plus fortes jusqu'à la défaite, avec un score
+    Endless,
+    /// Niveau de campagne : uniquement les vagues scriptées du niveau
+    Campaign,
+}
+
+impl GameMode {
+    pub fn label(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            GameMode::Standard => "mode.standard",
+            GameMode::Endless => "mode.endless",
+            GameMode::Campaign => "mode.campaign",
+        })
+    }
+
+    /// Alterne entre les modes proposés à la sélection de carte
+    pub fn toggle(&self) -> Self {
+        match self {
+            GameMode::Standard => GameMode::Endless,
+            GameMode::Endless | GameMode::Campaign => GameMode::Standard,
+        }
+    }
+}
+
+/// Statistiques d'une partie, utilisées pour le score du mode sans fin
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct RunScore {
+    pub waves_survived: u32,
+    pub monsters_killed: u32,
+    pub money_earned: u32,
+    /// Monstres arrivés au bout du chemin
+    pub leaks: u32,
+}
+
+impl RunScore {
+    /// Score final : les vagues comptent le plus, chaque fuite est pénalisée
+    pub fn points(&self) -> u32 {
+        (self.waves_survived * 100 + self.monsters_killed * 10 + self.money_earned / 10)
+            .saturating_sub(self.leaks * 50)
+    }
+}
+
+/// Transforme un monstre en élite selon la vague : élémentaire, cuirassé, ou les deux
+pub fn make_elite(monster: &mut Monster, wave: u32, locale: Locale, rng: &mut impl Rng) {
+    let kinds = match wave {
+        w if w >= CHAMPION_WAVE => 3,
+        w if w >= ARMORED_WAVE => 2,
+        w if w >= ELEMENTAL_WAVE => 1,
+        _ => return,
+    };
+
+    match rng.random_range(0..kinds) {
+        0 => make_elemental(monster, locale, rng),
+        1 => make_armored(monster, wave, locale),
+        _ => {
+            make_elemental(monster, locale, rng);
+            make_armored(monster, wave, locale);
+        }
+    }
+}
+
+/// Résiste fortement à un élément tiré au hasard, mais craint l'élément qui le contre
+fn make_elemental(monster: &mut Monster, locale: Locale, rng: &mut impl Rng) {
+    let (element, name_id) = match rng.random_range(0..4) {
+        0 => (Element::Fire, "elite.fire"),
+        1 => (Element::Water, "elite.water"),
+        2 => (Element::Earth, "elite.earth"),
+        _ => (Element::Air, "elite.air"),
+    };
+
+    let resistances = &mut monster.resistances;
+    match element {
+        Element::Fire => {
+            resistances.fire = 0.6;
+            resistances.water = -0.25;
+        }
+        Element::Water => {
+            resistances.water = 0.6;
+            resistances.air = -0.25;
+        }
+        Element::Earth => {
+            resistances.earth = 0.6;
+            resistances.fire = -0.25;
+        }
+        _ => {
+            resistances.air = 0.6;
+            resistances.earth = -0.25;
+        }
+    }
+
+    monster.name = locale.format(name_id, &[("name", &monster.name)]);
+}
+
+/// L'armure augmente avec les vagues, jusqu'à 60%
+fn make_armored(monster: &mut Monster, wave: u32, locale: Locale) {
+    monster.armor = monster.armor.max((0.1 + 0.02 * wave as f32).min(0.6));
+    monster.name = locale.format("elite.armored", &[("name", &monster.name)]);
+}
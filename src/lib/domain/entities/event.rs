@@ -0,0 +1,311 @@
+use std::{collections::VecDeque, sync::Arc};
+
+use crate::domain::{
+    i18n::Locale,
+    mediator::MediatorService,
+    ports::notifier::NotificationContext,
+    services::notifications::policy::{Notification, NotificationCategory},
+};
+
+use super::{
+    endless::RunScore,
+    position::Position,
+    tower::{TargetSelection, TowerStatType},
+};
+
+/// Événement émis par la partie, consommé par ses abonnés
+#[derive(Debug, Clone)]
+pub enum GameEvent {
+    MonsterSpawned {
+        name: String,
+        hp: f32,
+    },
+    MonsterKilled {
+        name: String,
+        reward: u32,
+    },
+    /// Un monstre est arrivé au bout du chemin
+    MonsterLeaked {
+        name: String,
+        damage: u32,
+        life: i32,
+    },
+    TowerPlaced {
+        name: String,
+        position: Position,
+        cost: u32,
+    },
+    TowerUpgraded {
+        name: String,
+        stat: TowerStatType,
+        cost: u32,
+    },
+    TowerSold {
+        name: String,
+        refund: u32,
+    },
+    /// La stratégie de ciblage d'une tour a changé
+    TargetingChanged {
+        name: String,
+        target: TargetSelection,
+    },
+    /// Le solde du joueur a changé, quelle qu'en soit la raison
+    MoneyChanged {
+        delta: i64,
+        total: u32,
+    },
+    WaveStarted {
+        index: u32,
+        monsters: usize,
+        /// Vague appelée avant la fin de la précédente
+        early: bool,
+        /// Dernière vague de la partie
+        last: bool,
+    },
+    WaveCleared {
+        index: u32,
+        bonus: u32,
+    },
+    GameOver {
+        waves: u32,
+    },
+    Victory {
+        waves: u32,
+    },
+}
+
+impl GameEvent {
+    /// Message affiché dans le journal de la partie, `None` pour les événements silencieux
+    pub fn message(&self, locale: Locale) -> Option<String> {
+        let message = match self {
+            GameEvent::MonsterSpawned { name, hp } => locale.format(
+                "event.monster_spawned",
+                &[("name", name), ("hp", &format!("{:.1}", hp))],
+            ),
+            GameEvent::MonsterKilled { name, reward } => {
+                locale.plural("event.monster_killed", *reward, &[("name", name)])
+            }
+            GameEvent::MonsterLeaked { name, damage, life } => locale.plural(
+                "event.monster_leaked",
+                *damage,
+                &[("name", name), ("life", life)],
+            ),
+            GameEvent::TowerPlaced {
+                name,
+                position,
+                cost,
+            } => locale.plural(
+                "event.tower_placed",
+                *cost,
+                &[("name", name), ("x", &position.x), ("y", &position.y)],
+            ),
+            GameEvent::TowerUpgraded { name, cost, .. } => {
+                locale.plural("event.tower_upgraded", *cost, &[("name", name)])
+            }
+            GameEvent::TowerSold { name, refund } => {
+                locale.plural("event.tower_sold", *refund, &[("name", name)])
+            }
+            GameEvent::TargetingChanged { name, target } => locale.format(
+                "log.targeting_changed",
+                &[("name", name), ("target", &target.label(locale))],
+            ),
+            // Le solde est déjà affiché dans l'en-tête
+            GameEvent::MoneyChanged { .. } => return None,
+            GameEvent::WaveStarted {
+                index,
+                monsters,
+                early,
+                ..
+            } => locale.plural(
+                match early {
+                    false => "event.wave_started",
+                    true => "event.wave_called",
+                },
+                *monsters as u32,
+                &[("wave", index)],
+            ),
+            GameEvent::WaveCleared { index, bonus } => {
+                locale.plural("event.wave_cleared", *bonus, &[("wave", index)])
+            }
+            GameEvent::GameOver { .. } => locale.text("event.game_over").to_string(),
+            GameEvent::Victory { waves } => locale.plural("event.victory", *waves, &[]),
+        };
+
+        Some(message)
+    }
+}
+
+/// Abonné aux événements d'une partie
+pub trait GameEventSubscriber: Send {
+    /// Reçoit un événement, `time` étant le temps de jeu écoulé en secondes
+    fn on_event(&mut self, event: &GameEvent, time: f32);
+}
+
+/// Les statistiques de la partie sont tenues à jour à partir des événements
+impl GameEventSubscriber for RunScore {
+    fn on_event(&mut self, event: &GameEvent, _time: f32) {
+        match event {
+            GameEvent::MonsterKilled { reward, .. } => {
+                self.monsters_killed += 1;
+                self.money_earned += reward;
+            }
+            GameEvent::MonsterLeaked { .. } => self.leaks += 1,
+            GameEvent::WaveCleared { index, bonus } => {
+                // Les vagues appelées en avance comptent aussi
+                self.waves_survived = self.waves_survived.max(*index);
+                self.money_earned += bonus;
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Nombre de vies en dessous duquel le joueur est prévenu
+pub const LOW_LIFE: i32 = 3;
+
+/// Notifie le joueur des moments importants de la partie
+pub struct NotificationSubscriber {
+    mediator: Arc<MediatorService>,
+    locale: Locale,
+    /// État de la partie, suivi au fil des événements
+    context: Option<NotificationContext>,
+}
+
+impl NotificationSubscriber {
+    pub fn new(mediator: Arc<MediatorService>, locale: Locale) -> Self {
+        Self {
+            mediator,
+            locale,
+            context: None,
+        }
+    }
+
+    pub fn with_context(mut self, context: NotificationContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+
+    fn track(&mut self, event: &GameEvent) {
+        let Some(context) = &mut self.context else {
+            return;
+        };
+
+        match event {
+            GameEvent::WaveStarted { index, .. } => context.wave = *index,
+            GameEvent::MonsterLeaked { life, .. } => context.life = *life,
+            _ => {}
+        }
+    }
+}
+
+impl NotificationSubscriber {
+    /// Catégorie et message de la notification associée à un événement
+    fn notification(&self, event: &GameEvent) -> Option<(NotificationCategory, String)> {
+        let locale = self.locale;
+
+        match event {
+            GameEvent::MonsterLeaked { life, .. } if (1..=LOW_LIFE).contains(life) => Some((
+                NotificationCategory::LowLife,
+                locale.plural("notification.low_life", *life as u32, &[]),
+            )),
+            GameEvent::WaveStarted {
+                index, last: true, ..
+            } => Some((
+                NotificationCategory::Boss,
+                locale.format("notification.boss", &[("wave", index)]),
+            )),
+            GameEvent::WaveCleared { .. } => {
+                Some((NotificationCategory::WaveCleared, event.message(locale)?))
+            }
+            GameEvent::GameOver { .. } | GameEvent::Victory { .. } => {
+                Some((NotificationCategory::GameOver, event.message(locale)?))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl GameEventSubscriber for NotificationSubscriber {
+    fn on_event(&mut self, event: &GameEvent, _time: f32) {
+        self.track(event);
+
+        let Some((category, message)) = self.notification(event) else {
+            return;
+        };
+
+        let mut notification = Notification::new(category, "Tower Defense", message, self.locale);
+        if let Some(context) = self.context {
+            notification = notification.with_context(context);
+        }
+
+        // Une notification perdue ne doit pas interrompre la partie
+        let _ = self.mediator.notify(notification);
+    }
+}
+
+/// Événement horodaté d'un enregistrement de partie
+#[derive(Debug, Clone)]
+pub struct RecordedEvent {
+    /// Temps de jeu écoulé en secondes
+    pub time: f32,
+    pub event: GameEvent,
+}
+
+/// Nombre d'événements conservés par défaut dans un enregistrement
+pub const REPLAY_EVENT_LIMIT: usize = 10_000;
+
+/// Enregistrement des derniers événements d'une partie, pour la revoir ou l'analyser
+///
+/// Une partie sans fin émet des événements sans limite : au-delà de `limit`,
+/// les plus anciens sont oubliés.
+#[derive(Debug, Clone)]
+pub struct Replay {
+    pub events: VecDeque<RecordedEvent>,
+    pub limit: usize,
+    /// Nombre d'événements enregistrés depuis le début, oubliés compris
+    pub recorded: usize,
+}
+
+impl Default for Replay {
+    fn default() -> Self {
+        Self {
+            events: VecDeque::new(),
+            limit: REPLAY_EVENT_LIMIT,
+            recorded: 0,
+        }
+    }
+}
+
+impl Replay {
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Événements conservés enregistrés après les `count` premiers
+    pub fn since(&self, count: usize) -> impl Iterator<Item = &RecordedEvent> {
+        let forgotten = self.recorded - self.events.len();
+        self.events.iter().skip(count.saturating_sub(forgotten))
+    }
+
+    /// Événements survenus jusqu'au temps donné, dans l'ordre
+    pub fn until(&self, time: f32) -> impl Iterator<Item = &RecordedEvent> {
+        self.events
+            .iter()
+            .take_while(move |recorded| recorded.time <= time)
+    }
+}
+
+impl GameEventSubscriber for Replay {
+    fn on_event(&mut self, event: &GameEvent, time: f32) {
+        self.events.push_back(RecordedEvent {
+            time,
+            event: event.clone(),
+        });
+        self.recorded += 1;
+
+        while self.events.len() > self.limit {
+            self.events.pop_front();
+        }
+    }
+}
@@ -6,15 +6,38 @@ use std::{
 
 use rand::{Rng, rng};
 
-use crate::domain::{mediator::MediatorService, ports::notifier::Notifier};
+use crate::domain::{
+    error::GameError, i18n::Locale, mediator::MediatorService, ports::notifier::NotificationContext,
+};
+use uuid::Uuid;
 
 use super::{
+    components::{Components, EntityId},
+    endless::{ENDLESS_BASE_COUNT, ENDLESS_MAX_COUNT, GameMode, RunScore, make_elite},
+    event::{GameEvent, GameEventSubscriber, Replay},
     map::Map,
     position::Position,
-    tower::{Tower, TowerStatType},
+    rules::{Mutator, RunRules},
+    spatial::SpatialGrid,
+    tower::{TargetSelection, Tower, TowerStatType},
     wave::Wave,
 };
 
+/// Part du coût d'une tour rendue au joueur lors de sa vente
+pub const SELL_REFUND_RATIO: f32 = 0.5;
+
+/// Nombre de vagues à repousser pour gagner une partie standard
+pub const STANDARD_WAVE_COUNT: u32 = 20;
+
+/// Issue d'une partie terminée
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameOutcome {
+    /// Toutes les vagues prévues ont été repoussées
+    Victory,
+    /// Le joueur n'a plus de vie
+    Defeat,
+}
+
 /// Structure représentant un log d'événement du jeu
 #[derive(Debug, Clone)]
 pub struct GameLog {
@@ -25,9 +48,11 @@ pub struct GameLog {
 }
 
 pub struct Game {
+    /// Identifiant de la partie, transmis avec les notifications
+    pub id: Uuid,
     pub mediator: Arc<MediatorService>,
     pub current_map: Option<Map>,
-    pub towers: Vec<Tower>,
+    pub towers: Components<Tower>,
     pub waves: Option<VecDeque<Wave>>,
     pub current_wave: Option<Wave>,
     pub wave_index: u32,
@@ -38,8 +63,31 @@ pub struct Game {
     pub logs: Vec<GameLog>,
     pub log_limit: usize,
     pub money: u32,
+    /// Difficulté et modificateurs de la partie
+    pub rules: RunRules,
+    pub mode: GameMode,
+    /// Langue des messages du journal
+    pub locale: Locale,
+    /// Statistiques de la partie, tenues à jour par les événements
+    pub score: RunScore,
+    /// Événements de la partie, enregistrés au fil du jeu
+    pub replay: Replay,
+    /// Abonnés supplémentaires aux événements, par exemple les notifications
+    pub subscribers: Vec<Box<dyn GameEventSubscriber>>,
+    /// Nombre de vagues à repousser pour gagner, sans limite si `None`
+    pub wave_limit: Option<u32>,
+    /// Issue de la partie, définie une seule fois lorsqu'elle se termine
+    pub outcome: Option<GameOutcome>,
+    /// Position des monstres de la vague, reconstruite avant les tirs de chaque image
+    pub monster_grid: SpatialGrid,
 }
 
+/// La partie doit pouvoir être simulée sur un autre thread que celui de l'interface
+const _: fn() = || {
+    fn assert_send<T: Send>() {}
+    assert_send::<Game>();
+};
+
 impl Game {
     pub fn new(
         mediator: Arc<MediatorService>,
@@ -48,9 +96,10 @@ impl Game {
         wave_multiplier: f32,
     ) -> Self {
         Self {
+            id: Uuid::new_v4(),
             mediator,
             current_map: None,
-            towers,
+            towers: towers.into(),
             waves: Some(VecDeque::new()),
             current_wave: None,
             wave_index: 0,
@@ -61,6 +110,24 @@ impl Game {
             logs: Vec::new(),
             log_limit: 100,
             money: 100000000,
+            rules: RunRules::default(),
+            mode: GameMode::Standard,
+            locale: Locale::default(),
+            score: RunScore::default(),
+            replay: Replay::default(),
+            subscribers: Vec::new(),
+            wave_limit: None,
+            outcome: None,
+            monster_grid: SpatialGrid::default(),
+        }
+    }
+
+    /// État de la partie joint aux notifications
+    pub fn notification_context(&self) -> NotificationContext {
+        NotificationContext {
+            game_id: self.id,
+            wave: self.wave_index,
+            life: self.player_life,
         }
     }
 
@@ -77,31 +144,136 @@ impl Game {
         if self.logs.len() > self.log_limit {
             self.logs.remove(0);
         }
+    }
+
+    /// Ajoute un abonné aux événements de la partie
+    pub fn subscribe(&mut self, subscriber: Box<dyn GameEventSubscriber>) {
+        self.subscribers.push(subscriber);
+    }
+
+    /// Diffuse un événement : journal, statistiques, enregistrement puis abonnés supplémentaires
+    pub fn emit(&mut self, event: GameEvent) {
+        let time = self.elapsed_time;
+
+        if let Some(message) = event.message(self.locale) {
+            self.add_log(message);
+        }
+        self.score.on_event(&event, time);
+        self.replay.on_event(&event, time);
 
-        // Envoyer une notification pour les événements importants
-        if message.contains("Game Over") || message.contains("VICTOIRE") {
-            self.mediator
-                .notifier
-                .send_notification("Tower Defense", &message);
+        for subscriber in &mut self.subscribers {
+            subscriber.on_event(&event, time);
         }
     }
 
-    pub fn remove_tower(&mut self, position: Position) {
-        if let Some(index) = self
-            .towers
-            .iter()
-            .position(|t| t.position.x == position.x && t.position.y == position.y)
+    /// Termine la partie et diffuse son issue
+    fn finish(&mut self, outcome: GameOutcome) {
+        if self.outcome.is_some() {
+            return;
+        }
+        self.outcome = Some(outcome);
+
+        let waves = self.wave_index;
+        self.emit(match outcome {
+            GameOutcome::Victory => GameEvent::Victory { waves },
+            GameOutcome::Defeat => GameEvent::GameOver { waves },
+        });
+    }
+
+    /// Vend la tour à cette position et rembourse une partie de son coût
+    pub fn sell_tower(&mut self, position: Position) -> Result<u32, GameError> {
+        if self.rules.has(Mutator::NoSelling) {
+            return Err(GameError::SellingForbidden);
+        }
+
+        let id = self
+            .tower_at(position)
+            .ok_or(GameError::NoTowerHere(position))?
+            .id;
+        let tower = self.towers.remove(id).ok_or(GameError::TowerNotFound(id))?;
+        let refund = (tower.cost as f32 * SELL_REFUND_RATIO).round() as u32;
+        self.emit(GameEvent::TowerSold {
+            name: tower.name,
+            refund,
+        });
+        self.add_money(refund);
+
+        Ok(refund)
+    }
+
+    /// Vérifie que les règles de la partie autorisent la construction de cette tour
+    pub fn can_build(&self, tower: &Tower) -> Result<(), GameError> {
+        if self.rules.has(Mutator::SingleTowerType)
+            && let Some(first) = self.towers.first()
+            && first.name != tower.name
         {
-            self.towers.remove(index);
+            return Err(GameError::SingleTowerType {
+                allowed: first.name.clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Indique si une tour peut être construite sur cette case : dans la carte, hors du chemin
+    /// et sans tour existante
+    pub fn is_position_valid(&self, position: &Position) -> bool {
+        let Some(map) = &self.current_map else {
+            return false;
+        };
+
+        map.contains(position)
+            && !map.is_position_on_path(position)
+            && !self.towers.iter().any(|t| t.position == *position)
+    }
+
+    /// Construit une tour à la position donnée si la case, les règles et le solde le permettent
+    pub fn place_tower(&mut self, mut tower: Tower, position: Position) -> Result<(), GameError> {
+        if self.current_map.is_none() {
+            return Err(GameError::MissingMap);
+        }
+        if !self.is_position_valid(&position) {
+            return Err(GameError::InvalidPosition(position));
+        }
+        if !self.has_enough_money(tower.cost) {
+            return Err(GameError::InsufficientFunds {
+                needed: tower.cost,
+                available: self.money,
+            });
+        }
+        self.can_build(&tower)?;
+
+        // La tour est copiée du catalogue : chaque construction a son propre identifiant
+        tower.id = Uuid::new_v4();
+        tower.position = position;
+        self.emit(GameEvent::TowerPlaced {
+            name: tower.name.clone(),
+            position,
+            cost: tower.cost,
+        });
+        self.spend_money(tower.cost);
+        self.towers.insert(tower);
+
+        Ok(())
+    }
+
+    /// Tour construite à cette position
+    pub fn tower_at(&self, position: Position) -> Option<&Tower> {
+        self.towers.iter().find(|tower| tower.position == position)
+    }
+
+    pub fn remove_tower(&mut self, position: Position) {
+        if let Some(id) = self.tower_at(position).map(|tower| tower.id) {
+            self.towers.remove(id);
         }
     }
 
     pub fn add_money(&mut self, amount: u32) {
         self.money += amount;
-        self.add_log(format!(
-            "💰 Gain de {} pièces! Total: {}",
-            amount, self.money
-        ));
+        self.emit(GameEvent::MoneyChanged {
+            delta: amount as i64,
+            total: self.money,
+        });
     }
 
     pub fn has_enough_money(&self, amount: u32) -> bool {
@@ -111,13 +283,14 @@ impl Game {
     pub fn spend_money(&mut self, amount: u32) -> bool {
         if self.has_enough_money(amount) {
             self.money -= amount;
-            self.add_log(format!(
-                "💸 Dépense de {} pièces. Reste: {}",
-                amount, self.money
-            ));
+            self.emit(GameEvent::MoneyChanged {
+                delta: -(amount as i64),
+                total: self.money,
+            });
             true
         } else {
-            self.add_log(format!("❌ Pas assez de pièces! ({})", self.money));
+            let message = self.locale.plural("log.missing_money", self.money, &[]);
+            self.add_log(message);
             false
         }
     }
@@ -126,7 +299,7 @@ impl Game {
         self.spawn_interval = interval;
     }
 
-    fn gen_random_wave(&self) -> Wave {
+    fn gen_random_wave(&self) -> Result<Wave, GameError> {
         if let Some(map) = &self.current_map {
             let mut rng = rng();
             let count = rng.random_range(1..=10 + self.wave_index);
@@ -141,10 +314,10 @@ impl Game {
 
             for _ in 0..count as usize {
                 let mut monster = map.monsters[rng.random_range(0..map.monsters.len())].clone();
-                monster.hp = monster.hp * (1.0 + self.wave_index as f32 * self.wave_multiplier);
-                monster.waypoint_idx = 1;
+                monster.hp *= 1.0 + self.wave_index as f32 * self.wave_multiplier;
+                self.rules.apply_to_monster(&mut monster);
                 // Set the position to the first waypoint
-                monster.position = start_position;
+                monster.position = start_position.into();
 
                 monsters.push(monster);
             }
@@ -155,38 +328,172 @@ impl Game {
                 Wave::with_staggered_spawn(monsters, self.spawn_interval)
             };
 
-            return wave;
+            return Ok(wave);
+        }
+
+        Err(GameError::MissingMap)
+    }
+
+    /// Vague du mode sans fin : effectif et points de vie croissent selon la progression
+    /// de la carte, avec de plus en plus d'élites
+    fn gen_endless_wave(&self) -> Result<Wave, GameError> {
+        let Some(map) = &self.current_map else {
+            return Err(GameError::MissingMap);
+        };
+
+        let mut rng = rng();
+        let scaling = map.scaling;
+        let count = scaling
+            .monster_count(ENDLESS_BASE_COUNT, self.wave_index)
+            .min(ENDLESS_MAX_COUNT);
+        // La difficulté accélère ou ralentit la croissance des points de vie
+        let hp_factor = scaling
+            .hp_factor(self.wave_index)
+            .powf(self.wave_multiplier);
+        let start_position = map
+            .waypoints
+            .first()
+            .copied()
+            .unwrap_or(Position::initial());
+
+        let monsters = (0..count)
+            .map(|_| {
+                let mut monster = map.monsters[rng.random_range(0..map.monsters.len())].clone();
+                monster.hp *= hp_factor;
+                monster.position = start_position.into();
+
+                if rng.random::<f32>() < scaling.elite_ratio(self.wave_index) {
+                    make_elite(&mut monster, self.wave_index, self.locale, &mut rng);
+                }
+                self.rules.apply_to_monster(&mut monster);
+
+                monster
+            })
+            .collect();
+
+        let wave = if self.spawn_interval <= 0.0 {
+            Wave::new(Some(monsters))
+        } else {
+            Wave::with_staggered_spawn(monsters, self.spawn_interval)
+        };
+
+        Ok(wave)
+    }
+
+    /// Prochaine vague prévue, ou une vague générée si aucune n'est prévue
+    ///
+    /// En campagne, seules les vagues scriptées du niveau sont jouées.
+    fn next_wave(&mut self) -> Result<Option<Wave>, GameError> {
+        if self.wave_limit.is_some_and(|limit| self.wave_index > limit) {
+            return Ok(None);
+        }
+
+        match self.waves.as_mut().and_then(|waves| waves.pop_front()) {
+            Some(mut wave) => {
+                for monster in wave.monsters.iter_mut() {
+                    self.rules.apply_to_monster(monster);
+                }
+                Ok(Some(wave))
+            }
+            None => match self.mode {
+                GameMode::Campaign => Ok(None),
+                GameMode::Endless => self.gen_endless_wave().map(Some),
+                GameMode::Standard => self.gen_random_wave().map(Some),
+            },
+        }
+    }
+
+    /// Indique s'il reste au moins une vague à lancer après celles déjà démarrées
+    pub fn has_next_wave(&self) -> bool {
+        if self
+            .wave_limit
+            .is_some_and(|limit| self.wave_index >= limit)
+        {
+            return false;
         }
 
-        panic!("No map selected");
+        // Seule la campagne se limite aux vagues prévues, les autres modes en génèrent
+        self.mode != GameMode::Campaign
+            || self.waves.as_ref().is_some_and(|waves| !waves.is_empty())
+    }
+
+    /// Nombre total de vagues de la partie, `None` si elles sont illimitées
+    pub fn total_waves(&self) -> Option<u32> {
+        match self.mode {
+            GameMode::Campaign => {
+                Some(self.wave_index + self.waves.as_ref().map_or(0, |waves| waves.len() as u32))
+            }
+            _ => self.wave_limit,
+        }
     }
 
     fn start_next_wave(&mut self) {
-        if let Some(map) = &self.current_map {
-            if self.current_wave.is_none() {
-                self.wave_index += 1;
-
-                let wave = self
-                    .waves
-                    .as_mut()
-                    .unwrap()
-                    .pop_front()
-                    .unwrap_or_else(|| self.gen_random_wave());
-
-                let log_message = format!(
-                    "🚩 Démarrage vague {}: {} monstres sur carte '{}'",
-                    self.wave_index,
-                    wave.monsters.len(),
-                    map.name
-                );
-
-                self.add_log(log_message);
-                self.current_wave = Some(wave);
+        if self.current_map.is_some() && self.current_wave.is_none() {
+            self.wave_index += 1;
+
+            // La carte est présente, seule la fin des vagues prévues peut arrêter la partie
+            let Ok(Some(wave)) = self.next_wave() else {
+                self.wave_index -= 1;
+                return;
+            };
+
+            self.emit(GameEvent::WaveStarted {
+                index: self.wave_index,
+                monsters: wave.monsters.len(),
+                early: false,
+                last: !self.has_next_wave(),
+            });
+            self.current_wave = Some(wave);
+        }
+    }
+
+    /// Lance la vague suivante, sans attendre la fin de la vague en cours
+    pub fn start_wave(&mut self) -> Result<(), GameError> {
+        if self.current_map.is_none() {
+            return Err(GameError::MissingMap);
+        }
+
+        if self.current_wave.is_none() {
+            self.start_next_wave();
+            return match self.current_wave {
+                Some(_) => Ok(()),
+                None => Err(GameError::NoMoreWaves),
+            };
+        }
+
+        self.wave_index += 1;
+
+        let wave = match self.next_wave() {
+            Ok(Some(wave)) => wave,
+            Ok(None) => {
+                self.wave_index -= 1;
+                return Err(GameError::NoMoreWaves);
+            }
+            Err(error) => {
+                self.wave_index -= 1;
+                return Err(error);
             }
+        };
+
+        self.emit(GameEvent::WaveStarted {
+            index: self.wave_index,
+            monsters: wave.monsters.len(),
+            early: true,
+            last: !self.has_next_wave(),
+        });
+
+        if let Some(current_wave) = &mut self.current_wave {
+            current_wave.monsters.extend(wave.monsters);
         }
+
+        Ok(())
     }
 
     pub fn update(&mut self, delta_time: f32) {
+        if self.outcome.is_some() {
+            return;
+        }
+
         if self.current_wave.is_none() {
             self.start_next_wave();
             return;
@@ -196,16 +503,23 @@ impl Game {
 
         let sub_frames = 1;
         let sub_delta = delta_time / sub_frames as f32;
-        let start_time = self.elapsed_time.clone();
+        let start_time = self.elapsed_time;
+
+        // Les tours sont sorties de la partie le temps de tirer, sans copie, pour que leurs
+        // capacités puissent la modifier
+        let mut towers = std::mem::take(&mut self.towers);
 
-        let mut towers = Vec::new();
-        for i in 0..self.towers.len() {
-            towers.push(self.towers[i].clone());
+        // La grille n'est utile qu'aux images où au moins une tour tire
+        match &self.current_wave {
+            Some(wave) if towers.iter().any(|tower| tower.can_shoot(start_time)) => {
+                self.monster_grid.rebuild(&wave.monsters)
+            }
+            _ => self.monster_grid.clear(),
         }
 
         for i in 0..sub_frames {
             let sub_frame_time = start_time + sub_delta * i as f32;
-            for tower in &mut towers {
+            for tower in towers.iter_mut() {
                 if tower.can_shoot(sub_frame_time) {
                     let tower_logs = tower.shoot(self.mediator.clone(), self, sub_frame_time);
                     logs_to_add.extend(tower_logs);
@@ -213,19 +527,22 @@ impl Game {
             }
         }
 
-        self.towers = towers;
+        // Une tour construite par une capacité pendant les tirs est conservée
+        let built = std::mem::replace(&mut self.towers, towers);
+        self.towers.extend(built);
         self.elapsed_time += delta_time;
 
         if let Some(wave) = &mut self.current_wave {
             let newly_spawned = wave.update_spawns(delta_time);
 
-            for monster in newly_spawned {
-                let log_message = format!(
-                    "👾 Apparition d'un monstre {}! HP: {:.1}",
-                    monster.name, monster.hp
-                );
-                logs_to_add.push(log_message);
-            }
+            let mut events: Vec<GameEvent> = newly_spawned
+                .into_iter()
+                .map(|monster| GameEvent::MonsterSpawned {
+                    name: monster.name.clone(),
+                    hp: monster.hp,
+                })
+                .collect();
+            let mut rewards = 0;
 
             for monster in wave.monsters.iter_mut() {
                 if let Some(map) = &self.current_map
@@ -235,65 +552,68 @@ impl Game {
                 }
             }
 
-            let mut rem = Vec::new();
             let wave_is_empty = wave.monsters.is_empty();
 
             if let Some(map) = &self.current_map {
-                for monster in wave.monsters.drain(..) {
+                let path_length = map.path_length();
+                wave.monsters.retain(|monster| {
                     if monster.is_alive() {
-                        if monster.reached_goal(map) {
-                            self.player_life -= monster.damage_to_player as i32;
-                            let log_message = format!(
-                                "⚠️ Monstre {} arrivé, -{} vie(s). Vie joueur: {}",
-                                monster.name, monster.damage_to_player, self.player_life
-                            );
-                            logs_to_add.push(log_message);
-                        } else {
-                            rem.push(monster);
+                        if !monster.reached_goal(path_length) {
+                            return true;
                         }
+
+                        self.player_life -= monster.damage_to_player as i32;
+                        events.push(GameEvent::MonsterLeaked {
+                            name: monster.name.clone(),
+                            damage: monster.damage_to_player,
+                            life: self.player_life,
+                        });
+                        false
                     } else if monster.hp <= 0.0 && monster.active {
-                        let reward = 10 + (self.wave_index as u32);
-                        logs_to_add.push(format!(
-                            "💀 Monstre {} éliminé! +{} pièces",
-                            monster.name, reward
-                        ));
-
-                        logs_to_add.push(format!(
-                            "💰 Gain de {} pièces! Total: {}",
+                        let reward = self.rules.reward(10 + self.wave_index);
+                        rewards += reward;
+                        events.push(GameEvent::MonsterKilled {
+                            name: monster.name.clone(),
                             reward,
-                            self.money + reward
-                        ));
-                        self.money += reward;
+                        });
+                        false
                     } else {
-                        rem.push(monster);
+                        true
                     }
-                }
+                });
             }
 
-            wave.monsters = rem;
-
             for log in logs_to_add {
                 self.add_log(log);
             }
+            for event in events {
+                self.emit(event);
+            }
+            if rewards > 0 {
+                self.add_money(rewards);
+            }
+
+            if self.player_life <= 0 {
+                self.finish(GameOutcome::Defeat);
+                return;
+            }
 
             if wave_is_empty {
-                let wave_bonus = 20 * self.wave_index as u32;
-                self.money += wave_bonus;
-                let log_message = format!(
-                    "🏆 Vague {} terminée! Bonus de +{} pièces",
-                    self.wave_index, wave_bonus
-                );
-                self.add_log(log_message);
+                let wave_bonus = self.rules.reward(20 * self.wave_index);
                 self.current_wave = None;
+                self.emit(GameEvent::WaveCleared {
+                    index: self.wave_index,
+                    bonus: wave_bonus,
+                });
+                self.add_money(wave_bonus);
 
-                if self.player_life > 0 {
-                    let log_message = format!("✅ Préparation de la prochaine vague...");
+                if self.has_next_wave() {
+                    let log_message = self.locale.text("log.preparing_wave").to_string();
 
                     self.add_log(log_message);
                     self.start_next_wave();
                 } else {
-                    let log_message = "☠️ Game Over! Vous avez perdu!".to_string();
-                    self.add_log(log_message);
+                    self.finish(GameOutcome::Victory);
                 }
             }
         }
@@ -308,14 +628,14 @@ impl Game {
 
             self.update(seconds_per_frame);
 
-            if self.player_life <= 0 || (self.waves.is_none() && self.current_wave.is_none()) {
+            if self.outcome.is_some() {
                 break;
             }
 
-            if let Some(total) = total_seconds {
-                if start_time.elapsed().as_secs_f32() >= total {
-                    break;
-                }
+            if let Some(total) = total_seconds
+                && start_time.elapsed().as_secs_f32() >= total
+            {
+                break;
             }
 
             let elapsed = frame_start.elapsed();
@@ -325,44 +645,69 @@ impl Game {
         }
     }
 
-    pub fn upgrade_tower(
+    /// Choisit la stratégie de ciblage de la tour
+    pub fn set_targeting(
         &mut self,
-        tower_index: usize,
-        upgrade_type: TowerStatType,
-    ) -> Result<String, String> {
-        if tower_index >= self.towers.len() {
-            let message = "❌ Invalid tower index".to_string();
-            self.add_log(message.clone());
-            return Err(message);
-        }
+        tower: EntityId,
+        target: TargetSelection,
+    ) -> Result<(), GameError> {
+        self.change_targeting(tower, |tower| tower.set_target_selection(target))
+            .map(|_| ())
+    }
 
-        let tower_name = self.towers[tower_index].name.clone();
-        let upgrade_cost =
-            self.towers[tower_index].upgrade_cost_for_attribute(upgrade_type.clone());
+    /// Passe la tour à la stratégie de ciblage suivante et renvoie celle retenue
+    pub fn cycle_targeting(&mut self, tower: EntityId) -> Result<TargetSelection, GameError> {
+        self.change_targeting(tower, Tower::cycle_target_selection)
+    }
 
-        if let Some(cost) = upgrade_cost {
-            if !self.has_enough_money(cost) {
-                let message = format!("❌ Missing money ({})", self.money);
-                self.add_log(message.clone());
-                return Err(message);
-            }
+    fn change_targeting(
+        &mut self,
+        id: EntityId,
+        change: impl FnOnce(&mut Tower) -> Option<TargetSelection>,
+    ) -> Result<TargetSelection, GameError> {
+        let tower = self
+            .towers
+            .entity_mut(id)
+            .ok_or(GameError::TowerNotFound(id))?;
+        let target = change(tower).ok_or_else(|| GameError::FixedTargeting {
+            name: tower.name.clone(),
+        })?;
 
-            match self.towers[tower_index].upgrade(upgrade_type) {
-                Ok(_) => {
-                    self.spend_money(cost);
-                    self.add_log(format!("🔧 Tour {} améliorée", tower_name));
+        let name = tower.name.clone();
+        self.emit(GameEvent::TargetingChanged { name, target });
 
-                    Ok("Tower upgraded".to_string())
-                }
-                Err(error) => {
-                    self.add_log(error.clone());
-                    Err(error.to_string())
-                }
-            }
-        } else {
-            let message = "Upgrade not available".to_string();
-            self.add_log(message.clone());
-            return Err(message);
+        Ok(target)
+    }
+
+    /// Améliore une caractéristique de la tour et renvoie le prix payé
+    pub fn upgrade_tower(
+        &mut self,
+        id: EntityId,
+        upgrade_type: TowerStatType,
+    ) -> Result<u32, GameError> {
+        let tower = self.towers.entity(id).ok_or(GameError::TowerNotFound(id))?;
+        let tower_name = tower.name.clone();
+        let cost = tower
+            .upgrade_cost_for_attribute(upgrade_type.clone())
+            .ok_or_else(|| GameError::MaxLevel(upgrade_type.clone()))?;
+
+        if !self.has_enough_money(cost) {
+            return Err(GameError::InsufficientFunds {
+                needed: cost,
+                available: self.money,
+            });
+        }
+
+        if let Some(tower) = self.towers.entity_mut(id) {
+            tower.upgrade(upgrade_type.clone())?;
         }
+        self.emit(GameEvent::TowerUpgraded {
+            name: tower_name,
+            stat: upgrade_type,
+            cost,
+        });
+        self.spend_money(cost);
+
+        Ok(cost)
     }
 }
@@ -1,8 +1,82 @@
-use super::{monster::Monster, position::Position};
-use std::rc::Rc;
+use crate::domain::i18n::Locale;
 
-pub trait TerrainModifier: Fn() -> f32 + Clone {}
-impl<T: Fn() -> f32 + Clone> TerrainModifier for T {}
+use super::{
+    monster::Monster,
+    position::{Point, Position},
+};
+use std::sync::Arc;
+
+/// Terrain modifiant la vitesse des monstres, identifié par une clé du registre des terrains
+pub trait TerrainModifier: Send + Sync {
+    /// Clé du terrain dans le registre, utilisée dans les fichiers de carte
+    fn key(&self) -> &'static str;
+
+    /// Multiplicateur de vitesse d'un monstre à la position donnée
+    fn speed_factor(&self, position: Position) -> f32;
+}
+
+/// Élément de décor purement visuel affiché sur la carte
+#[derive(Debug, Clone)]
+pub struct Decoration {
+    pub position: Position,
+    pub symbol: String,
+}
+
+impl Decoration {
+    pub fn new(position: Position, symbol: String) -> Self {
+        Self { position, symbol }
+    }
+}
+
+/// Courbes de progression des vagues du mode sans fin
+///
+/// Les points de vie et le nombre de monstres sont multipliés à chaque vague,
+/// la part de monstres d'élite (élémentaires ou cuirassés) augmente linéairement.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WaveScaling {
+    pub hp_growth: f32,
+    pub count_growth: f32,
+    pub elite_growth: f32,
+}
+
+impl Default for WaveScaling {
+    fn default() -> Self {
+        Self {
+            hp_growth: 1.15,
+            count_growth: 1.1,
+            elite_growth: 0.05,
+        }
+    }
+}
+
+impl WaveScaling {
+    pub fn new(hp_growth: f32, count_growth: f32, elite_growth: f32) -> Self {
+        Self {
+            hp_growth,
+            count_growth,
+            elite_growth,
+        }
+    }
+
+    /// Multiplicateur de points de vie à la vague donnée (la première vague vaut 1)
+    pub fn hp_factor(&self, wave: u32) -> f32 {
+        self.hp_growth.powi(wave.saturating_sub(1) as i32)
+    }
+
+    /// Nombre de monstres à la vague donnée, à partir du nombre de la première vague
+    pub fn monster_count(&self, base: u32, wave: u32) -> u32 {
+        (base as f32 * self.count_growth.powi(wave.saturating_sub(1) as i32)).round() as u32
+    }
+
+    /// Part des monstres d'élite à la vague donnée, plafonnée à 90%
+    pub fn elite_ratio(&self, wave: u32) -> f32 {
+        (self.elite_growth * wave.saturating_sub(1) as f32).min(0.9)
+    }
+}
+
+/// Dimensions autorisées pour une carte
+pub const MIN_MAP_SIZE: u32 = 5;
+pub const MAX_MAP_SIZE: u32 = 60;
 
 #[derive(Clone)]
 pub struct Map {
@@ -14,10 +88,15 @@ pub struct Map {
     pub width: u32,
     pub height: u32,
     pub monsters: Vec<Monster>,
-    pub apply_modifier: Option<Rc<dyn Fn() -> f32>>,
+    pub terrain: Option<Arc<dyn TerrainModifier>>,
+    pub decorations: Vec<Decoration>,
+    pub scaling: WaveScaling,
+    /// Identifiant des nom et description traduits, pour les cartes fournies avec le jeu
+    pub label_id: Option<&'static str>,
 }
 
 impl Map {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: String,
         description: String,
@@ -27,7 +106,7 @@ impl Map {
         width: u32,
         height: u32,
         monsters: Vec<Monster>,
-        apply_modifier: Option<Rc<dyn Fn() -> f32>>,
+        terrain: Option<Arc<dyn TerrainModifier>>,
     ) -> Self {
         Self {
             name,
@@ -38,52 +117,177 @@ impl Map {
             width,
             height,
             monsters,
-            apply_modifier,
+            terrain,
+            decorations: Vec::new(),
+            scaling: WaveScaling::default(),
+            label_id: None,
         }
     }
 
-    /// Vérifie si une position est sur le chemin des monstres
-    pub fn is_position_on_path(&self, position: &Position) -> bool {
-        let is_waypoint_position = self
+    pub fn with_decorations(mut self, decorations: Vec<Decoration>) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    pub fn with_terrain(mut self, terrain: Arc<dyn TerrainModifier>) -> Self {
+        self.terrain = Some(terrain);
+        self
+    }
+
+    pub fn with_scaling(mut self, scaling: WaveScaling) -> Self {
+        self.scaling = scaling;
+        self
+    }
+
+    pub fn with_label_id(mut self, label_id: &'static str) -> Self {
+        self.label_id = Some(label_id);
+        self
+    }
+
+    /// Nom affiché, traduit si la carte en a un identifiant
+    pub fn title(&self, locale: Locale) -> &str {
+        match self.label_id {
+            Some(id) => locale.text(id),
+            None => &self.name,
+        }
+    }
+
+    /// Description affichée, traduite si la carte en a un identifiant
+    pub fn summary(&self, locale: Locale) -> String {
+        match self.label_id {
+            Some(id) => locale.text(&format!("{}.description", id)).to_string(),
+            None => self.description.clone(),
+        }
+    }
+
+    /// Indique si une position est dans les limites de la carte, de `0` à `width - 1`
+    /// et de `0` à `height - 1`
+    pub fn contains(&self, position: &Position) -> bool {
+        position.x >= 0
+            && position.y >= 0
+            && position.x < self.width as i32
+            && position.y < self.height as i32
+    }
+
+    /// Tronçons du trajet, tous horizontaux ou verticaux : entre deux waypoints, le chemin
+    /// suit l'horizontale puis la verticale
+    ///
+    /// Seule géométrie du chemin : le déplacement des monstres, l'affichage et la pose des
+    /// tours en dérivent.
+    fn legs(&self) -> impl Iterator<Item = (Position, Position)> + '_ {
+        self.waypoints.windows(2).flat_map(|segment| {
+            let (start, end) = (segment[0], segment[1]);
+            let corner = Position::new(end.x, start.y);
+            [(start, corner), (corner, end)]
+                .into_iter()
+                .filter(|(from, to)| from != to)
+        })
+    }
+
+    /// Liste les cases parcourues par le chemin, dans l'ordre du trajet
+    pub fn path_cells(&self) -> Vec<Position> {
+        let mut cells: Vec<Position> = self.waypoints.first().copied().into_iter().collect();
+
+        for (start, end) in self.legs() {
+            let dx = (end.x - start.x).signum();
+            let dy = (end.y - start.y).signum();
+            let mut cell = start;
+            while cell != end {
+                cell = Position::new(cell.x + dx, cell.y + dy);
+                cells.push(cell);
+            }
+        }
+
+        cells
+    }
+
+    /// Vérifie qu'une carte est jouable : dimensions, chemin et monstres
+    pub fn validate(&self, locale: Locale) -> Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err(locale.text("map.error.missing_name").to_string());
+        }
+
+        let sizes = MIN_MAP_SIZE..=MAX_MAP_SIZE;
+        if !sizes.contains(&self.width) || !sizes.contains(&self.height) {
+            return Err(locale.format(
+                "map.error.invalid_size",
+                &[
+                    ("width", &self.width),
+                    ("height", &self.height),
+                    ("min", &MIN_MAP_SIZE),
+                    ("max", &MAX_MAP_SIZE),
+                ],
+            ));
+        }
+
+        if self.waypoints.len() < 2 {
+            return Err(locale.text("map.error.too_few_waypoints").to_string());
+        }
+
+        if let Some(waypoint) = self.waypoints.iter().find(|wp| !self.contains(wp)) {
+            return Err(locale.format(
+                "map.error.waypoint_outside",
+                &[("x", &waypoint.x), ("y", &waypoint.y)],
+            ));
+        }
+
+        if self
             .waypoints
-            .iter()
-            .any(|wp| wp.x == position.x && wp.y == position.y);
+            .windows(2)
+            .any(|segment| segment[0].x == segment[1].x && segment[0].y == segment[1].y)
+        {
+            return Err(locale.text("map.error.duplicate_waypoint").to_string());
+        }
 
-        if is_waypoint_position {
-            return true;
+        let cells = self.path_cells();
+        for (i, cell) in cells.iter().enumerate() {
+            if cells[..i].iter().any(|c| c.x == cell.x && c.y == cell.y) {
+                return Err(
+                    locale.format("map.error.path_crossing", &[("x", &cell.x), ("y", &cell.y)])
+                );
+            }
+        }
+
+        if self.monsters.is_empty() {
+            return Err(locale.text("map.error.no_monster").to_string());
         }
 
-        for i in 0..self.waypoints.len() - 1 {
-            let start = &self.waypoints[i];
-            let end = &self.waypoints[i + 1];
-
-            let location = position.x >= start.x.min(end.x)
-                && position.x <= start.x.max(end.x)
-                && position.y >= start.y.min(end.y)
-                && position.y <= start.y.max(end.y);
-
-            if location {
-                let dx = end.x - start.x;
-                let dy = end.y - start.y;
-
-                if dx == 0 {
-                    if position.x == start.x {
-                        return true;
-                    }
-                } else if dy == 0 {
-                    if position.y == start.y {
-                        return true;
-                    }
-                } else {
-                    let slope = dy as f32 / dx as f32;
-                    let expected_y = start.y as f32 + slope * (position.x - start.x) as f32;
-                    if (expected_y - position.y as f32).abs() < 0.1 {
-                        return true;
-                    }
-                }
+        Ok(())
+    }
+
+    /// Longueur du trajet des monstres, en cases
+    pub fn path_length(&self) -> f32 {
+        self.legs()
+            .map(|(start, end)| Point::from(start).distance_to(&end.into()))
+            .sum()
+    }
+
+    /// Point du trajet atteint après avoir parcouru `progress` cases depuis le départ,
+    /// l'arrivée au-delà de la longueur du trajet
+    pub fn point_at(&self, progress: f32) -> Option<Point> {
+        let mut remaining = progress.max(0.0);
+
+        for (start, end) in self.legs() {
+            let (start, end) = (Point::from(start), Point::from(end));
+            let length = start.distance_to(&end);
+
+            if remaining <= length && length > 0.0 {
+                return Some(start.lerp(&end, remaining / length));
             }
+            remaining -= length;
         }
 
-        false
+        self.waypoints.last().map(|end| Point::from(*end))
+    }
+
+    /// Vérifie si une position est sur le chemin des monstres
+    pub fn is_position_on_path(&self, position: &Position) -> bool {
+        self.waypoints.first() == Some(position)
+            || self.legs().any(|(start, end)| {
+                position.x >= start.x.min(end.x)
+                    && position.x <= start.x.max(end.x)
+                    && position.y >= start.y.min(end.y)
+                    && position.y <= start.y.max(end.y)
+            })
     }
 }
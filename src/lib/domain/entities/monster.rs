@@ -1,8 +1,13 @@
 use uuid::Uuid;
 
-use super::{element::Element, map::Map, position::Position};
-
-#[derive(Debug, Clone)]
+use super::{
+    components::{Entity, EntityId},
+    element::Element,
+    map::Map,
+    position::{Point, Position},
+};
+
+#[derive(Debug, Clone, Default)]
 pub struct Resistances {
     pub fire: f32,
     pub water: f32,
@@ -34,16 +39,6 @@ impl Resistances {
         }
     }
 
-    // Défini une résistance par défaut pour les monstres
-    pub fn default() -> Self {
-        Self {
-            fire: 0.0,
-            water: 0.0,
-            earth: 0.0,
-            air: 0.0,
-        }
-    }
-
     // Calcule le facteur final de dégâts en tenant compte des résistances et vulnérabilités
     pub fn damage_factor(&self, attack_element: &Element) -> f32 {
         let resistance = self.get_resistance(attack_element);
@@ -57,16 +52,18 @@ pub struct Monster {
     pub name: String,
     pub symbol: String,
     pub hp: f32,
-    pub position: Position,
+    /// Position exacte sur le trajet, utilisée pour la portée des tours
+    pub position: Point,
     pub movement_speed: f32, // Cases par seconde (larger = faster)
-    pub waypoint_idx: usize,
+    /// Distance parcourue depuis le départ le long du trajet, en cases
+    pub progress: f32,
     pub resistances: Resistances,
     pub damage_to_player: u32,
-    pub distance_moved: f32, // Distance accumulée pour les mouvements partiels
-    pub spawn_delay: f32,    // Délai avant apparition (en secondes)
-    pub active: bool,        // Indique si le monstre est actif dans la vague
-    pub armor: f32, // Réduction des dégâts (0.0 = aucune réduction, 0.5 = 50% de réduction)
-    pub detected: Vec<Uuid>,
+    pub spawn_delay: f32, // Délai avant apparition (en secondes)
+    pub active: bool,     // Indique si le monstre est actif dans la vague
+    pub armor: f32,       // Réduction des dégâts (0.0 = aucune réduction, 0.5 = 50% de réduction)
+    /// Tours qui ont déjà signalé ce monstre
+    pub detected: Vec<EntityId>,
 }
 
 impl Monster {
@@ -74,6 +71,11 @@ impl Monster {
         self.hp > 0.0 && self.active
     }
 
+    /// Dégâts réellement subis après l'armure et la résistance à l'élément de l'attaque
+    pub fn mitigate_damage(&self, damage: f32, element: &Element) -> f32 {
+        damage * self.resistances.damage_factor(element) * (1.0 - self.armor)
+    }
+
     pub fn update_spawn_status(&mut self, delta_time: f32) -> bool {
         if !self.active {
             self.spawn_delay -= delta_time;
@@ -85,67 +87,32 @@ impl Monster {
         false
     }
 
-    // Déplacer le monstre en fonction de sa vitesse en cases/seconde et du temps écoulé
+    /// Case affichée pour ce monstre
+    pub fn cell(&self) -> Position {
+        self.position.cell()
+    }
+
+    /// Avance le long du trajet selon la vitesse en cases par seconde et le terrain traversé
     pub fn advance(&mut self, map: &Map, delta_time: f32) {
         // Ne rien faire si le monstre n'est pas encore actif
         if !self.active {
             return;
         }
 
-        if self.waypoint_idx >= map.waypoints.len() {
-            return;
-        }
-
-        let target = map.waypoints[self.waypoint_idx];
-        let dx = target.x - self.position.x;
-        let dy = target.y - self.position.y;
+        let terrain_modifier = map
+            .terrain
+            .as_ref()
+            .map_or(1.0, |terrain| terrain.speed_factor(self.cell()));
 
-        // Si le monstre a atteint le waypoint cible
-        if dx == 0 && dy == 0 {
-            self.waypoint_idx += 1;
-            return;
-        }
-
-        let dist = ((dx * dx + dy * dy) as f32).sqrt();
-        let terrain_modifier = if let Some(modifier) = &map.apply_modifier {
-            modifier()
-        } else {
-            1.0
-        };
-
-        let cases_per_second = self.movement_speed * terrain_modifier;
-        let distance_this_frame = cases_per_second * delta_time;
-
-        // Ajouter à la distance accumulée
-        self.distance_moved += distance_this_frame;
-
-        // Calculer combien de cases entières à déplacer
-        let steps = self.distance_moved.floor() as i32;
-        self.distance_moved -= steps as f32;
-
-        if steps > 0 {
-            // Calculer le déplacement en x et y
-            let step_x = ((dx as f32) / dist * steps as f32).round() as i32;
-            let step_y = ((dy as f32) / dist * steps as f32).round() as i32;
-
-            // Appliquer le déplacement
-            self.position.x += step_x;
-            self.position.y += step_y;
-
-            // Vérifier si on a atteint ou dépassé le waypoint
-            let new_dx = target.x - self.position.x;
-            let new_dy = target.y - self.position.y;
-
-            // Si on a dépassé le waypoint ou changé de direction, aller directement au waypoint
-            if new_dx * dx <= 0 && new_dy * dy <= 0 {
-                self.position = target;
-                self.waypoint_idx += 1;
-            }
+        self.progress += self.movement_speed * terrain_modifier * delta_time;
+        if let Some(position) = map.point_at(self.progress) {
+            self.position = position;
         }
     }
 
-    pub fn reached_goal(&self, map: &Map) -> bool {
-        self.active && self.waypoint_idx >= map.waypoints.len()
+    /// Indique si le monstre a parcouru tout le trajet, de longueur `Map::path_length`
+    pub fn reached_goal(&self, path_length: f32) -> bool {
+        self.active && self.progress >= path_length
     }
 
     pub fn set_movement_speed(&mut self, cases_per_second: f32) {
@@ -167,12 +134,11 @@ impl Monster {
             name,
             symbol,
             hp,
-            position,
+            position: position.into(),
             movement_speed,
-            waypoint_idx: 0,
+            progress: 0.0,
             resistances,
             damage_to_player,
-            distance_moved: 0.0,
             spawn_delay: 0.0, // Par défaut, pas de délai
             active: true,     // Par défaut, actif immédiatement
             armor: 0.0,       // Par défaut, pas d'armure
@@ -186,3 +152,9 @@ impl Monster {
         self
     }
 }
+
+impl Entity for Monster {
+    fn id(&self) -> EntityId {
+        self.id
+    }
+}
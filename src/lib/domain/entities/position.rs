@@ -1,4 +1,4 @@
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Position {
     pub x: i32,
     pub y: i32,
@@ -20,3 +20,42 @@ impl Position {
         (dx * dx + dy * dy).sqrt()
     }
 }
+
+/// Position continue sur la carte, en cases : les coordonnées entières désignent le centre
+/// d'une case
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Point {
+    pub x: f32,
+    pub y: f32,
+}
+
+impl Point {
+    pub fn new(x: f32, y: f32) -> Self {
+        Self { x, y }
+    }
+
+    /// Case dans laquelle se trouve ce point, utilisée pour l'affichage
+    pub fn cell(&self) -> Position {
+        Position::new(self.x.round() as i32, self.y.round() as i32)
+    }
+
+    pub fn distance_to(&self, other: &Point) -> f32 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    /// Point situé à la fraction `t` du segment allant de `self` à `other`
+    pub fn lerp(&self, other: &Point, t: f32) -> Point {
+        Point::new(
+            self.x + (other.x - self.x) * t,
+            self.y + (other.y - self.y) * t,
+        )
+    }
+}
+
+impl From<Position> for Point {
+    fn from(position: Position) -> Self {
+        Self::new(position.x as f32, position.y as f32)
+    }
+}
@@ -0,0 +1,265 @@
+use rand::Rng;
+
+use crate::domain::i18n::Locale;
+
+use super::monster::Monster;
+
+/// Bonus d'armure minimal du modificateur `DoubleArmor`, qui touche aussi les monstres sans armure
+pub const DOUBLE_ARMOR_MIN_BONUS: f32 = 0.25;
+/// Armure maximale après modificateurs : un monstre reste toujours vulnérable
+pub const MAX_ARMOR: f32 = 0.9;
+
+/// Niveaux de difficulté proposés au joueur
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+    Nightmare,
+}
+
+/// Valeurs de départ et de progression associées à une difficulté
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DifficultyPreset {
+    pub starting_money: u32,
+    pub starting_life: i32,
+    pub wave_multiplier: f32,
+    /// Multiplicateur appliqué aux pièces gagnées (monstres éliminés et bonus de vague)
+    pub reward_multiplier: f32,
+    /// Intervalle entre l'apparition de deux monstres, en secondes
+    pub spawn_interval: f32,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 4] = [
+        Difficulty::Easy,
+        Difficulty::Normal,
+        Difficulty::Hard,
+        Difficulty::Nightmare,
+    ];
+
+    pub fn label(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            Difficulty::Easy => "difficulty.easy",
+            Difficulty::Normal => "difficulty.normal",
+            Difficulty::Hard => "difficulty.hard",
+            Difficulty::Nightmare => "difficulty.nightmare",
+        })
+    }
+
+    /// Clé utilisée dans les fichiers de configuration
+    pub fn name(&self) -> &str {
+        match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Normal => "normal",
+            Difficulty::Hard => "hard",
+            Difficulty::Nightmare => "nightmare",
+        }
+    }
+
+    pub fn preset(&self) -> DifficultyPreset {
+        match self {
+            Difficulty::Easy => DifficultyPreset {
+                starting_money: 400,
+                starting_life: 20,
+                wave_multiplier: 0.5,
+                reward_multiplier: 1.5,
+                spawn_interval: 1.5,
+            },
+            Difficulty::Normal => DifficultyPreset {
+                starting_money: 250,
+                starting_life: 10,
+                wave_multiplier: 1.0,
+                reward_multiplier: 1.0,
+                spawn_interval: 1.0,
+            },
+            Difficulty::Hard => DifficultyPreset {
+                starting_money: 150,
+                starting_life: 5,
+                wave_multiplier: 1.5,
+                reward_multiplier: 0.8,
+                spawn_interval: 0.7,
+            },
+            Difficulty::Nightmare => DifficultyPreset {
+                starting_money: 100,
+                starting_life: 1,
+                wave_multiplier: 2.5,
+                reward_multiplier: 0.6,
+                spawn_interval: 0.4,
+            },
+        }
+    }
+
+    /// Difficulté suivante (`step > 0`) ou précédente, en boucle
+    pub fn cycle(&self, step: i32) -> Self {
+        let index = Difficulty::ALL
+            .iter()
+            .position(|difficulty| difficulty == self)
+            .unwrap_or(0) as i32;
+
+        Difficulty::ALL[(index + step).rem_euclid(Difficulty::ALL.len() as i32) as usize]
+    }
+}
+
+impl Difficulty {
+    /// Difficulté correspondant à un nom du fichier de paramètres
+    pub fn from_name(name: &str) -> Option<Self> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.name() == name.trim().to_lowercase())
+    }
+}
+
+/// Modificateurs optionnels choisis avant de lancer une partie
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Mutator {
+    /// Les monstres se déplacent 50% plus vite
+    FastMonsters,
+    /// Les tours ne peuvent plus être vendues
+    NoSelling,
+    /// L'armure des monstres est doublée, avec un bonus minimal pour les monstres sans armure
+    DoubleArmor,
+    /// Chaque monstre résiste à un élément tiré au hasard
+    RandomElements,
+    /// Seul le premier type de tour construit peut être utilisé
+    SingleTowerType,
+}
+
+impl Mutator {
+    pub const ALL: [Mutator; 5] = [
+        Mutator::FastMonsters,
+        Mutator::NoSelling,
+        Mutator::DoubleArmor,
+        Mutator::RandomElements,
+        Mutator::SingleTowerType,
+    ];
+
+    pub fn label(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            Mutator::FastMonsters => "mutator.fast_monsters",
+            Mutator::NoSelling => "mutator.no_selling",
+            Mutator::DoubleArmor => "mutator.double_armor",
+            Mutator::RandomElements => "mutator.random_elements",
+            Mutator::SingleTowerType => "mutator.single_tower_type",
+        })
+    }
+
+    pub fn description(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            Mutator::FastMonsters => "mutator.fast_monsters.description",
+            Mutator::NoSelling => "mutator.no_selling.description",
+            Mutator::DoubleArmor => "mutator.double_armor.description",
+            Mutator::RandomElements => "mutator.random_elements.description",
+            Mutator::SingleTowerType => "mutator.single_tower_type.description",
+        })
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Mutator::FastMonsters => "fast_monsters",
+            Mutator::NoSelling => "no_selling",
+            Mutator::DoubleArmor => "double_armor",
+            Mutator::RandomElements => "random_elements",
+            Mutator::SingleTowerType => "single_tower_type",
+        }
+    }
+}
+
+/// Règles d'une partie, enregistrées avec elle pour ne comparer que des scores
+/// obtenus dans les mêmes conditions
+#[derive(Debug, Clone, PartialEq)]
+pub struct RunRules {
+    pub difficulty: Difficulty,
+    /// Les valeurs de départ ont été modifiées par rapport à la difficulté choisie
+    pub custom: bool,
+    pub reward_multiplier: f32,
+    /// Modificateurs actifs, triés et sans doublon
+    pub mutators: Vec<Mutator>,
+}
+
+impl Default for RunRules {
+    fn default() -> Self {
+        Self::new(Difficulty::Normal, vec![])
+    }
+}
+
+impl RunRules {
+    pub fn new(difficulty: Difficulty, mut mutators: Vec<Mutator>) -> Self {
+        mutators.sort();
+        mutators.dedup();
+
+        Self {
+            difficulty,
+            custom: false,
+            reward_multiplier: difficulty.preset().reward_multiplier,
+            mutators,
+        }
+    }
+
+    pub fn with_custom(mut self, custom: bool) -> Self {
+        self.custom = custom;
+        self
+    }
+
+    pub fn has(&self, mutator: Mutator) -> bool {
+        self.mutators.contains(&mutator)
+    }
+
+    /// Identifiant stable des règles, par exemple `hard+fast_monsters+no_selling`
+    pub fn key(&self) -> String {
+        let mut parts = vec![match self.custom {
+            true => format!("{}-custom", self.difficulty.name()),
+            false => self.difficulty.name().to_string(),
+        }];
+        parts.extend(
+            self.mutators
+                .iter()
+                .map(|mutator| mutator.name().to_string()),
+        );
+        parts.join("+")
+    }
+
+    /// Résumé lisible des règles, par exemple « Difficile + Vente interdite »
+    pub fn label(&self, locale: Locale) -> String {
+        let difficulty = self.difficulty.label(locale);
+        let mut parts = vec![match self.custom {
+            true => locale.format("difficulty.custom", &[("difficulty", &difficulty)]),
+            false => difficulty.to_string(),
+        }];
+        parts.extend(
+            self.mutators
+                .iter()
+                .map(|mutator| mutator.label(locale).to_string()),
+        );
+        parts.join(" + ")
+    }
+
+    /// Pièces gagnées pour une récompense de base
+    pub fn reward(&self, base: u32) -> u32 {
+        (base as f32 * self.reward_multiplier).round() as u32
+    }
+
+    /// Applique les modificateurs à un monstre avant son entrée dans une vague
+    pub fn apply_to_monster(&self, monster: &mut Monster) {
+        if self.has(Mutator::FastMonsters) {
+            monster.movement_speed *= 1.5;
+        }
+
+        if self.has(Mutator::DoubleArmor) {
+            monster.armor = (monster.armor * 2.0)
+                .max(monster.armor + DOUBLE_ARMOR_MIN_BONUS)
+                .min(MAX_ARMOR);
+        }
+
+        if self.has(Mutator::RandomElements) {
+            let resistances = &mut monster.resistances;
+            let resistance = match rand::rng().random_range(0..4) {
+                0 => &mut resistances.fire,
+                1 => &mut resistances.water,
+                2 => &mut resistances.earth,
+                _ => &mut resistances.air,
+            };
+            *resistance = resistance.max(0.5);
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use std::collections::HashMap;
+
+use super::{
+    monster::Monster,
+    position::{Point, Position},
+};
+
+/// Côté d'une cellule de la grille, proche de la portée des tours
+pub const DEFAULT_CELL_SIZE: i32 = 4;
+
+/// Grille uniforme des monstres actifs, reconstruite à chaque image pour trouver les cibles
+/// proches d'un point sans parcourir toute la vague
+///
+/// Les monstres y sont repérés par leur place dans la vague au moment de la reconstruction.
+/// Les requêtes reçoivent la vague courante pour ignorer les monstres tués depuis par
+/// les tours ayant déjà tiré pendant l'image.
+#[derive(Debug, Clone)]
+pub struct SpatialGrid {
+    cell_size: i32,
+    cells: HashMap<(i32, i32), Vec<(usize, Point)>>,
+}
+
+impl Default for SpatialGrid {
+    fn default() -> Self {
+        Self::new(DEFAULT_CELL_SIZE)
+    }
+}
+
+impl SpatialGrid {
+    pub fn new(cell_size: i32) -> Self {
+        Self {
+            cell_size: cell_size.max(1),
+            cells: HashMap::new(),
+        }
+    }
+
+    fn cell(&self, position: Position) -> (i32, i32) {
+        (
+            position.x.div_euclid(self.cell_size),
+            position.y.div_euclid(self.cell_size),
+        )
+    }
+
+    /// Vide la grille en conservant les cellules déjà allouées
+    pub fn clear(&mut self) {
+        for cell in self.cells.values_mut() {
+            cell.clear();
+        }
+    }
+
+    /// Range les monstres actifs de la vague selon leur position actuelle
+    pub fn rebuild(&mut self, monsters: &[Monster]) {
+        self.clear();
+
+        for (slot, monster) in monsters.iter().enumerate() {
+            if monster.active {
+                let cell = self.cell(monster.cell());
+                self.cells
+                    .entry(cell)
+                    .or_default()
+                    .push((slot, monster.position));
+            }
+        }
+    }
+
+    /// Appelle `visit` pour chaque monstre vivant à moins de `radius` cases de `center`
+    fn visit(
+        &self,
+        monsters: &[Monster],
+        center: Point,
+        radius: f32,
+        mut visit: impl FnMut(usize, f32),
+    ) {
+        // Un point est rangé dans la case arrondie : une case de marge suffit autour du rayon
+        let reach = radius.max(0.0).ceil() as i32 + 1;
+        let origin = center.cell();
+        let (min_x, min_y) = self.cell(Position::new(
+            origin.x.saturating_sub(reach),
+            origin.y.saturating_sub(reach),
+        ));
+        let (max_x, max_y) = self.cell(Position::new(
+            origin.x.saturating_add(reach),
+            origin.y.saturating_add(reach),
+        ));
+
+        let mut check = |entries: &Vec<(usize, Point)>| {
+            for (slot, position) in entries {
+                if !monsters.get(*slot).is_some_and(Monster::is_alive) {
+                    continue;
+                }
+                let distance = center.distance_to(position);
+                if distance <= radius {
+                    visit(*slot, distance);
+                }
+            }
+        };
+
+        // Une très grande portée couvre plus de cellules que la grille n'en contient
+        let span =
+            (max_x as i64 - min_x as i64 + 1).saturating_mul(max_y as i64 - min_y as i64 + 1);
+        if span > self.cells.len() as i64 {
+            self.cells.values().for_each(&mut check);
+            return;
+        }
+
+        for x in min_x..=max_x {
+            for y in min_y..=max_y {
+                if let Some(entries) = self.cells.get(&(x, y)) {
+                    check(entries);
+                }
+            }
+        }
+    }
+
+    /// Places des monstres vivants à portée, dans l'ordre de la vague
+    pub fn within(&self, monsters: &[Monster], center: Point, radius: f32) -> Vec<usize> {
+        let mut slots = Vec::new();
+        self.visit(monsters, center, radius, |slot, _| slots.push(slot));
+        slots.sort_unstable();
+        slots
+    }
+
+    /// Place du monstre vivant à portée le plus proche, le premier de la vague en cas d'égalité
+    pub fn nearest(&self, monsters: &[Monster], center: Point, radius: f32) -> Option<usize> {
+        let mut nearest: Option<(f32, usize)> = None;
+        self.visit(monsters, center, radius, |slot, distance| {
+            if nearest.is_none_or(|best| (distance, slot) < best) {
+                nearest = Some((distance, slot));
+            }
+        });
+        nearest.map(|(_, slot)| slot)
+    }
+}
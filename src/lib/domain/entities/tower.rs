@@ -1,24 +1,29 @@
 use ratatui::style::Color;
 use uuid::Uuid;
 
-use crate::domain::mediator::MediatorService;
+use crate::domain::{error::GameError, i18n::Locale, mediator::MediatorService};
 
+use super::components::{Entity, EntityId};
 use super::game::Game;
 use super::{
-    behavior::TowerBehavior, element::Element, monster::Monster, position::Position, wave::Wave,
+    behavior::TowerBehavior,
+    element::Element,
+    position::{Point, Position},
 };
 use std::f32;
 use std::fmt::Debug;
-use std::rc::Rc;
 use std::sync::Arc;
 
 /// Stratégie de sélection de cible pour les tourelles
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TargetSelection {
     /// Cible les monstres volants
     Flying,
     /// Cible le monstre le plus proche
+    #[default]
     Nearest,
+    /// Cible le monstre le plus avancé sur le chemin
+    First,
     /// Cible le monstre le plus éloigné
     Farthest,
     /// Cible le monstre avec le plus de points de vie
@@ -29,9 +34,25 @@ pub enum TargetSelection {
     All,
 }
 
-impl Default for TargetSelection {
-    fn default() -> Self {
-        Self::Nearest
+impl TargetSelection {
+    /// Stratégies prises en charge par le tir des tourelles, dans l'ordre du changement de ciblage
+    pub const CYCLE: [TargetSelection; 4] = [
+        TargetSelection::Nearest,
+        TargetSelection::First,
+        TargetSelection::Strongest,
+        TargetSelection::All,
+    ];
+
+    pub fn label(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            TargetSelection::Flying => "target.flying",
+            TargetSelection::Nearest => "target.nearest",
+            TargetSelection::First => "target.first",
+            TargetSelection::Farthest => "target.farthest",
+            TargetSelection::Strongest => "target.strongest",
+            TargetSelection::Weakest => "target.weakest",
+            TargetSelection::All => "target.all",
+        })
     }
 }
 
@@ -52,9 +73,10 @@ pub struct TowerStatUpgrade {
 }
 
 impl TowerStatUpgrade {
-    pub fn format(&self, stat: &TowerStats) -> Result<String, ()> {
+    pub fn format(&self, stat: &TowerStats, locale: Locale) -> String {
+        let label = stat.label(locale);
         if self.max_level == stat.level {
-            return Ok(format!("{} {:.2} {}", stat.icon, stat.base, stat.label));
+            return format!("{} {:.2} {}", stat.icon, stat.base, label);
         }
 
         let unit = match &self.value_multiplier_unit {
@@ -67,17 +89,18 @@ impl TowerStatUpgrade {
             TowerUpgradeElementUnit::Unit => "+",
         };
 
-        Ok(format!(
+        format!(
             "{} {:.2} {} ({}{:.2}{})",
-            stat.icon, stat.base, stat.label, symbol, self.value_multiplier, unit
-        ))
+            stat.icon, stat.base, label, symbol, self.value_multiplier, unit
+        )
     }
 }
 
 #[derive(Clone)]
 pub struct TowerStats {
     pub stat_type: TowerStatType,
-    pub label: String,
+    /// Identifiant du nom de la caractéristique dans les traductions
+    pub label_id: String,
     pub icon: String,
     pub base: f32,
     pub level: u32,
@@ -85,6 +108,10 @@ pub struct TowerStats {
 }
 
 impl TowerStats {
+    pub fn label(&self, locale: Locale) -> String {
+        locale.text(&self.label_id).to_string()
+    }
+
     pub fn get_next_price(&self) -> Option<u32> {
         if let Some(upgrade) = &self.upgrade {
             let base = (self.base * 1.3_f32.powi(self.level as i32)).round() as u32;
@@ -95,6 +122,41 @@ impl TowerStats {
     }
 }
 
+/// Valeurs courantes des caractéristiques d'une tour, lues à chaque tir sans parcourir
+/// la liste des caractéristiques
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct StatBlock {
+    pub range: Option<f32>,
+    pub damage: Option<f32>,
+    pub attack_speed: Option<f32>,
+    pub money: Option<f32>,
+}
+
+impl StatBlock {
+    pub fn from_stats(stats: &[TowerStats]) -> Self {
+        let mut block = Self::default();
+        for stat in stats {
+            let value = Some(stat.base);
+            match stat.stat_type {
+                TowerStatType::Range => block.range = value,
+                TowerStatType::Damage => block.damage = value,
+                TowerStatType::AttackSpeed => block.attack_speed = value,
+                TowerStatType::Money => block.money = value,
+            }
+        }
+        block
+    }
+
+    pub fn get(&self, stat: TowerStatType) -> Option<f32> {
+        match stat {
+            TowerStatType::Range => self.range,
+            TowerStatType::Damage => self.damage,
+            TowerStatType::AttackSpeed => self.attack_speed,
+            TowerStatType::Money => self.money,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 pub enum TowerUpgradeElementUnit {
     Percent,
@@ -203,6 +265,18 @@ pub enum TowerKind {
     Sentinel,
 }
 
+/// Capacité spécifique exécutée par une tourelle à chaque tir
+///
+/// Partagée entre threads avec la partie, qui peut ainsi être simulée hors du thread de l'interface.
+pub trait TowerAbility: Send + Sync {
+    fn on_shoot(
+        &self,
+        mediator: &MediatorService,
+        game: &mut Game,
+        tower: &mut Tower,
+    ) -> Result<(), GameError>;
+}
+
 /// Structure uniforme pour toutes les tourelles
 #[derive(Clone)]
 pub struct Tower {
@@ -212,15 +286,17 @@ pub struct Tower {
     pub level: u32,
     pub cost: u32,
     pub stats: Vec<TowerStats>,
+    /// Valeurs de `stats`, tenues à jour par `upgrade`
+    pub stat_block: StatBlock,
     pub meta: Option<Vec<TowerMeta>>,
     pub position: Position,
     pub last_attack: f32,
-    pub on_action:
-        Option<Rc<dyn Fn(Arc<MediatorService>, &mut Game, &mut Tower) -> Result<(), String>>>,
+    pub ability: Option<Arc<dyn TowerAbility>>,
     pub highlight: Option<Color>,
 }
 
 impl Tower {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         name: String,
         symbol: String,
@@ -229,9 +305,7 @@ impl Tower {
         position: Position,
         stats: Vec<TowerStats>,
         meta: Option<Vec<TowerMeta>>,
-        on_action: Option<
-            Rc<dyn Fn(Arc<MediatorService>, &mut Game, &mut Tower) -> Result<(), String>>,
-        >,
+        ability: Option<Arc<dyn TowerAbility>>,
     ) -> Self {
         Self {
             id: Uuid::new_v4(),
@@ -239,35 +313,58 @@ impl Tower {
             symbol,
             level,
             cost,
+            stat_block: StatBlock::from_stats(&stats),
             stats,
             meta,
             position,
             last_attack: 0.0,
-            on_action,
+            ability,
             highlight: None,
         }
     }
 
     pub fn can_shoot(&self, current_time: f32) -> bool {
-        let attack_speed = self
-            .stats
-            .iter()
-            .find(|stat| stat.stat_type == TowerStatType::AttackSpeed);
-
-        if let Some(attack_speed) = attack_speed {
+        if let Some(attack_speed) = self.stat_block.attack_speed {
             let time_since_last_attack = current_time - self.last_attack;
-            time_since_last_attack >= 1.0 / attack_speed.base
+            time_since_last_attack >= 1.0 / attack_speed
         } else {
             false
         }
     }
 
+    fn target_selection_mut(&mut self) -> Option<&mut TargetSelection> {
+        self.meta.as_mut()?.iter_mut().find_map(|meta| match meta {
+            TowerMeta::TargetSelection(target) => Some(target),
+            _ => None,
+        })
+    }
+
+    /// Choisit la stratégie de ciblage, si la tourelle en possède une
+    pub fn set_target_selection(&mut self, target: TargetSelection) -> Option<TargetSelection> {
+        let current = self.target_selection_mut()?;
+        *current = target;
+        Some(target)
+    }
+
+    /// Passe à la stratégie de ciblage suivante, si la tourelle en possède une
+    pub fn cycle_target_selection(&mut self) -> Option<TargetSelection> {
+        let target = self.target_selection_mut()?;
+
+        let index = TargetSelection::CYCLE
+            .iter()
+            .position(|t| t == target)
+            .map_or(0, |i| (i + 1) % TargetSelection::CYCLE.len());
+
+        *target = TargetSelection::CYCLE[index];
+        Some(*target)
+    }
+
     pub fn upgrade_level(&mut self) -> u32 {
         self.level += 1;
         self.level
     }
 
-    pub fn upgrade(&mut self, upgrade_type: TowerStatType) -> Result<(), String> {
+    pub fn upgrade(&mut self, upgrade_type: TowerStatType) -> Result<(), GameError> {
         let element = self
             .stats
             .iter_mut()
@@ -276,7 +373,7 @@ impl Tower {
         if let Some(element) = element {
             if let Some(upgrade) = &element.upgrade {
                 if element.level >= upgrade.max_level {
-                    return Err(format!("La vitesse d'attaque est déjà au niveau maximum."));
+                    return Err(GameError::MaxLevel(upgrade_type));
                 }
 
                 element.level += 1;
@@ -284,6 +381,7 @@ impl Tower {
                     TowerUpgradeElementUnit::Percent => element.base * upgrade.value_multiplier,
                     TowerUpgradeElementUnit::Unit => element.base + upgrade.value_multiplier,
                 };
+                self.stat_block = StatBlock::from_stats(&self.stats);
             }
 
             Ok(())
@@ -295,7 +393,11 @@ impl Tower {
     pub fn upgrade_cost_for_attribute(&self, stat: TowerStatType) -> Option<u32> {
         let stat = self.stats.iter().find(|s| s.stat_type == stat);
         if let Some(stat) = stat {
-            if stat.level >= stat.upgrade.as_ref().unwrap().max_level {
+            if stat
+                .upgrade
+                .as_ref()
+                .is_none_or(|upgrade| stat.level >= upgrade.max_level)
+            {
                 return None;
             }
 
@@ -317,160 +419,111 @@ impl Tower {
         // Mettre à jour le temps du dernier tir
         self.last_attack = current_time;
 
-        if let Some(on_action) = self.on_action.take() {
-            on_action(mediator, game, self).unwrap();
-            self.on_action = Some(on_action);
+        if let Some(ability) = self.ability.clone()
+            && let Err(error) = ability.on_shoot(&mediator, game, self)
+        {
+            game.add_log(format!("❌ {}", error.message(game.locale)));
         }
 
-        // Sélectionner les cibles primaires en fonction de la stratégie
-        let current_wave = game.current_wave.as_mut().unwrap();
-        if let Some(meta) = &self.meta {
-            if let Some(_) = meta
-                .iter()
-                .find(|metadata| **metadata == TowerMeta::TargetSelection(TargetSelection::Nearest))
-            {
-                if let Some(target) = self.find_nearest_target(current_wave) {
-                    primary_targets.push(target);
-                }
-            }
-
-            if let Some(_) = meta
-                .iter()
-                .find(|metadata| **metadata == TowerMeta::TargetSelection(TargetSelection::All))
-            {
-                for (idx, monster) in current_wave.monsters.iter().enumerate() {
-                    if monster.active && self.is_in_range(monster) {
-                        primary_targets.push(idx);
-                    }
-                }
-            }
-
-            if let Some(_) = meta.iter().find(|metadata| {
-                **metadata == TowerMeta::TargetSelection(TargetSelection::Strongest)
-            }) {
-                let strongest_monster = current_wave
-                    .monsters
-                    .iter()
-                    .max_by_key(|m| m.hp as i32)
-                    .map(|m| m.id);
-
-                if let Some(strongest_monster) = strongest_monster {
-                    let index = current_wave
-                        .monsters
-                        .iter()
-                        .position(|m| m.id == strongest_monster)
-                        .unwrap();
-
-                    primary_targets.push(index);
-                }
-            }
-
-            let damage = self
-                .stats
-                .iter()
-                .find(|stat| stat.stat_type == TowerStatType::Damage);
+        // Sélectionner les cibles primaires en fonction de la stratégie, parmi les monstres
+        // à portée repérés par la grille
+        let grid = &game.monster_grid;
+        let Some(current_wave) = game.current_wave.as_mut() else {
+            return logs;
+        };
+        let (Some(meta), Some(range)) = (&self.meta, self.stat_block.range) else {
+            return logs;
+        };
+        let origin = Point::from(self.position);
+        let monsters = &current_wave.monsters;
+        let in_range = || {
+            grid.within(monsters, origin, range)
+                .into_iter()
+                .filter_map(|slot| monsters.get(slot))
+        };
 
-            let behavior = meta.iter().find_map(|metadata| {
-                if let TowerMeta::Behavior(behavior) = metadata {
-                    Some(behavior)
-                } else {
-                    None
-                }
-            });
-
-            if let Some(damage) = damage {
-                for target_idx in primary_targets {
-                    if let Some(monster) = current_wave.monsters.get_mut(target_idx) {
-                        if !monster.active {
-                            continue;
-                        }
-
-                        if let Some(behavior) = behavior {
-                            let actual_damage = behavior.apply(monster, damage.base);
-                            monster.hp -= actual_damage;
-                        }
-                    };
+        if meta.contains(&TowerMeta::TargetSelection(TargetSelection::Nearest))
+            && let Some(monster) = grid
+                .nearest(monsters, origin, range)
+                .and_then(|slot| monsters.get(slot))
+        {
+            primary_targets.push(monster.id);
+        }
 
-                    let aoe = meta.iter().find_map(|metadata| {
-                        if let TowerMeta::AoeEffect(aoe) = metadata {
-                            Some(aoe)
-                        } else {
-                            None
-                        }
-                    });
-
-                    if let Some(aoe) = aoe {
-                        let target_pos = current_wave.monsters[target_idx].position;
-                        for (idx, monster) in current_wave.monsters.iter_mut().enumerate() {
-                            if idx == target_idx || !monster.active {
-                                continue;
-                            }
-
-                            let distance = target_pos.distance_to(&monster.position);
-                            if let TowerAoe::Radius(radius, damage_multiplier) = aoe {
-                                if distance <= *radius as f32 {
-                                    let aoe_damage = damage.base * damage_multiplier; // 50% des dégâts pour l'AOE
-                                    monster.hp -= behavior
-                                        .expect("You should define a behavior when you use AOE")
-                                        .apply(monster, aoe_damage);
-                                }
-                            }
-                        }
-                    }
-                }
-            }
+        if meta.contains(&TowerMeta::TargetSelection(TargetSelection::First))
+            && let Some(monster) = in_range().max_by(|a, b| a.progress.total_cmp(&b.progress))
+        {
+            primary_targets.push(monster.id);
         }
 
-        logs
-    }
+        if meta.contains(&TowerMeta::TargetSelection(TargetSelection::All)) {
+            primary_targets.extend(in_range().map(|monster| monster.id));
+        }
 
-    fn find_nearest_target(&self, wave: &Wave) -> Option<usize> {
-        let range = self
-            .stats
-            .iter()
-            .find(|stat| stat.stat_type == TowerStatType::Range);
+        if meta.contains(&TowerMeta::TargetSelection(TargetSelection::Strongest))
+            && let Some(monster) = in_range().max_by_key(|monster| monster.hp as i32)
+        {
+            primary_targets.push(monster.id);
+        }
 
-        if let Some(range) = range {
-            let tower_pos = self.position;
-            let mut nearest_idx = None;
-            let mut min_distance = f32::MAX;
+        let Some(damage) = self.stat_block.damage else {
+            return logs;
+        };
 
-            for (idx, monster) in wave.monsters.iter().enumerate() {
-                if !monster.active {
-                    continue;
-                }
+        let behavior = meta.iter().find_map(|metadata| match metadata {
+            TowerMeta::Behavior(behavior) => Some(behavior),
+            _ => None,
+        });
+        let aoe = meta.iter().find_map(|metadata| match metadata {
+            TowerMeta::AoeEffect(aoe) => Some(aoe),
+            _ => None,
+        });
+
+        for target in primary_targets {
+            let Some(monster) = current_wave.monsters.entity_mut(target) else {
+                continue;
+            };
+            // Une tour ayant tiré avant elle pendant l'image a pu l'achever
+            if !monster.is_alive() {
+                continue;
+            }
 
-                let dx = (monster.position.x - tower_pos.x) as f32;
-                let dy = (monster.position.y - tower_pos.y) as f32;
-                let distance = (dx * dx + dy * dy).sqrt();
+            if let Some(behavior) = behavior {
+                let damage = monster.mitigate_damage(damage, &behavior.element());
+                let actual_damage = behavior.apply(monster, damage);
+                monster.hp -= actual_damage;
+            }
 
-                if distance <= range.base && distance < min_distance {
-                    min_distance = distance;
-                    nearest_idx = Some(idx);
+            // Les dégâts de zone reprennent l'élément du comportement de la tour
+            if let Some(TowerAoe::Radius(radius, damage_multiplier)) = aoe
+                && let Some(behavior) = behavior
+            {
+                let center = monster.position;
+                let splashed: Vec<_> = grid
+                    .within(&current_wave.monsters, center, *radius as f32)
+                    .into_iter()
+                    .filter_map(|slot| current_wave.monsters.get(slot))
+                    .map(|monster| monster.id)
+                    .filter(|id| *id != target)
+                    .collect();
+
+                for id in splashed {
+                    let Some(monster) = current_wave.monsters.entity_mut(id) else {
+                        continue;
+                    };
+                    let aoe_damage = damage * damage_multiplier;
+                    let aoe_damage = monster.mitigate_damage(aoe_damage, &behavior.element());
+                    monster.hp -= behavior.apply(monster, aoe_damage);
                 }
             }
-
-            nearest_idx
-        } else {
-            None
         }
-    }
 
-    fn is_in_range(&self, monster: &Monster) -> bool {
-        let range = self
-            .stats
-            .iter()
-            .find(|stat| stat.stat_type == TowerStatType::Range);
-
-        if let Some(range) = range {
-            let tower_pos = self.position;
-            let dx = (monster.position.x - tower_pos.x) as f32;
-            let dy = (monster.position.y - tower_pos.y) as f32;
-            let distance = (dx * dx + dy * dy).sqrt();
+        logs
+    }
+}
 
-            distance <= range.base
-        } else {
-            false
-        }
+impl Entity for Tower {
+    fn id(&self) -> EntityId {
+        self.id
     }
 }
@@ -1,8 +1,10 @@
-use super::monster::Monster;
+use uuid::Uuid;
+
+use super::{components::Components, monster::Monster};
 
 #[derive(Debug, Clone)]
 pub struct Wave {
-    pub monsters: Vec<Monster>,
+    pub monsters: Components<Monster>,
 }
 
 impl Wave {
@@ -10,6 +12,7 @@ impl Wave {
         let active_monsters = if let Some(monsters) = monsters {
             let mut active_monsters = Vec::new();
             for mut monster in monsters {
+                monster.id = Uuid::new_v4();
                 monster.spawn_delay = 0.0;
                 monster.active = true;
                 active_monsters.push(monster);
@@ -21,7 +24,7 @@ impl Wave {
         };
 
         Self {
-            monsters: active_monsters,
+            monsters: active_monsters.into(),
         }
     }
 
@@ -30,19 +33,23 @@ impl Wave {
 
         for (i, mut monster) in monsters.into_iter().enumerate() {
             let delay = i as f32 * interval;
+            // Les monstres sont copiés des modèles de la carte : chacun reçoit son identifiant
+            monster.id = Uuid::new_v4();
             monster.spawn_delay = delay;
             monster.active = delay <= 0.0;
             result.push(monster);
         }
 
-        Self { monsters: result }
+        Self {
+            monsters: result.into(),
+        }
     }
 
     // Met à jour les délais de spawn de tous les monstres
     pub fn update_spawns(&mut self, delta_time: f32) -> Vec<&mut Monster> {
         let mut newly_spawned = Vec::new();
 
-        for monster in &mut self.monsters {
+        for monster in self.monsters.iter_mut() {
             if monster.update_spawn_status(delta_time) {
                 newly_spawned.push(monster);
             }
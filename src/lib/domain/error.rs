@@ -0,0 +1,82 @@
+use std::fmt;
+
+use super::{
+    entities::{components::EntityId, position::Position, tower::TowerStatType},
+    i18n::Locale,
+};
+
+/// Erreur d'une action de jeu, affichée au joueur plutôt que d'interrompre la partie
+#[derive(Debug, Clone, PartialEq)]
+pub enum GameError {
+    InsufficientFunds {
+        needed: u32,
+        available: u32,
+    },
+    /// Case hors de la carte, sur le chemin ou déjà occupée
+    InvalidPosition(Position),
+    NoTowerHere(Position),
+    /// Aucune tour avec cet identifiant
+    TowerNotFound(EntityId),
+    /// La caractéristique ne peut plus être améliorée
+    MaxLevel(TowerStatType),
+    /// La tour n'a pas la caractéristique attendue par sa capacité
+    MissingStat(TowerStatType),
+    /// La tour n'a pas de stratégie de ciblage à modifier
+    FixedTargeting {
+        name: String,
+    },
+    MissingMap,
+    NoMoreWaves,
+    SellingForbidden,
+    /// Le modificateur « type de tour unique » n'autorise que ce type
+    SingleTowerType {
+        allowed: String,
+    },
+    Notification(String),
+}
+
+impl GameError {
+    /// Message affiché au joueur
+    pub fn message(&self, locale: Locale) -> String {
+        match self {
+            GameError::InsufficientFunds { needed, available } => locale.plural(
+                "error.insufficient_funds",
+                *needed,
+                &[("available", available)],
+            ),
+            GameError::InvalidPosition(position) => locale.format(
+                "error.invalid_position",
+                &[("x", &position.x), ("y", &position.y)],
+            ),
+            GameError::NoTowerHere(position) => locale.format(
+                "error.no_tower_here",
+                &[("x", &position.x), ("y", &position.y)],
+            ),
+            GameError::TowerNotFound(_) => locale.text("error.tower_not_found").to_string(),
+            GameError::MaxLevel(_) => locale.text("error.upgrade_maxed").to_string(),
+            GameError::MissingStat(stat) => {
+                locale.format("error.missing_stat", &[("stat", &format!("{:?}", stat))])
+            }
+            GameError::FixedTargeting { name } => {
+                locale.format("log.targeting_fixed", &[("name", name)])
+            }
+            GameError::MissingMap => locale.text("error.missing_map").to_string(),
+            GameError::NoMoreWaves => locale.text("log.no_more_waves").to_string(),
+            GameError::SellingForbidden => locale.text("error.selling_forbidden").to_string(),
+            GameError::SingleTowerType { allowed } => {
+                locale.format("error.single_tower_type", &[("name", allowed)])
+            }
+            GameError::Notification(error) => {
+                locale.format("error.notification", &[("error", error)])
+            }
+        }
+    }
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message(Locale::default()))
+    }
+}
+
+impl std::error::Error for GameError {}
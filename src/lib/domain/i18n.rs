@@ -0,0 +1,126 @@
+pub mod en;
+pub mod fr;
+
+use std::{collections::HashMap, env, fmt::Display, sync::OnceLock};
+
+/// Variable d'environnement imposant la langue, sans modifier les paramètres
+pub const LOCALE_ENV: &str = "TOWER_DEFENSE_LANG";
+
+/// Langues disponibles pour les textes du jeu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Locale {
+    #[default]
+    Fr,
+    En,
+}
+
+/// Valeur d'un paramètre de message, par exemple `("name", &tower.name)`
+pub type Arg<'a> = (&'a str, &'a dyn Display);
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::Fr, Locale::En];
+
+    /// Code de la langue, utilisé dans les fichiers de configuration
+    pub fn name(&self) -> &'static str {
+        match self {
+            Locale::Fr => "fr",
+            Locale::En => "en",
+        }
+    }
+
+    /// Nom de la langue dans cette langue
+    pub fn label(&self) -> &'static str {
+        match self {
+            Locale::Fr => "Français",
+            Locale::En => "English",
+        }
+    }
+
+    /// Langue suivante (`step > 0`) ou précédente, en boucle
+    pub fn cycle(&self, step: i32) -> Self {
+        let index = Locale::ALL
+            .iter()
+            .position(|locale| locale == self)
+            .unwrap_or(0) as i32;
+
+        Locale::ALL[(index + step).rem_euclid(Locale::ALL.len() as i32) as usize]
+    }
+
+    /// Langue imposée par `TOWER_DEFENSE_LANG`, si elle est définie et reconnue
+    pub fn from_env() -> Option<Self> {
+        Self::from_name(&env::var(LOCALE_ENV).ok()?)
+    }
+
+    /// Langue du système d'après `LC_ALL`, `LC_MESSAGES` puis `LANG`, le français par défaut
+    pub fn system() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| env::var(var).ok())
+            .find(|value| !value.is_empty())
+            .and_then(|value| Self::from_name(&value))
+            .unwrap_or_default()
+    }
+
+    fn messages(&self) -> &'static HashMap<&'static str, &'static str> {
+        static FR: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+        static EN: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+
+        match self {
+            Locale::Fr => FR.get_or_init(|| fr::MESSAGES.iter().copied().collect()),
+            Locale::En => EN.get_or_init(|| en::MESSAGES.iter().copied().collect()),
+        }
+    }
+
+    /// Texte d'un message, en français s'il manque dans cette langue, sinon son identifiant
+    pub fn text<'a>(&self, id: &'a str) -> &'a str {
+        self.messages()
+            .get(id)
+            .or_else(|| Locale::Fr.messages().get(id))
+            .copied()
+            .unwrap_or(id)
+    }
+
+    /// Message dont les paramètres `{nom}` sont remplacés par leur valeur
+    pub fn format(&self, id: &str, args: &[Arg]) -> String {
+        args.iter()
+            .fold(self.text(id).to_string(), |message, (name, value)| {
+                message.replace(&format!("{{{}}}", name), &value.to_string())
+            })
+    }
+
+    /// Message accordé selon `count`, disponible comme paramètre `{count}`
+    ///
+    /// Utilise la variante `<id>.one` au singulier et `<id>.other` au pluriel.
+    pub fn plural(&self, id: &str, count: u32, args: &[Arg]) -> String {
+        let form = match self.is_singular(count) {
+            true => format!("{}.one", id),
+            false => format!("{}.other", id),
+        };
+
+        let mut args = args.to_vec();
+        args.push(("count", &count));
+        self.format(&form, &args)
+    }
+
+    /// Le français accorde au singulier zéro et un, l'anglais seulement un
+    fn is_singular(&self, count: u32) -> bool {
+        match self {
+            Locale::Fr => count <= 1,
+            Locale::En => count == 1,
+        }
+    }
+}
+
+impl Locale {
+    /// Langue correspondant à un code simple (`en`) ou à une valeur de `LANG` (`en_US.UTF-8`)
+    pub fn from_name(value: &str) -> Option<Self> {
+        let code = value
+            .trim()
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        Locale::ALL.into_iter().find(|locale| locale.name() == code)
+    }
+}
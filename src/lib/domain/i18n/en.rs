@@ -0,0 +1,623 @@
+//! Textes en anglais
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Événements de la partie
+    ("event.monster_spawned", "👾 A {name} appeared! HP: {hp}"),
+    (
+        "event.monster_killed.one",
+        "💀 {name} killed! +{count} coin",
+    ),
+    (
+        "event.monster_killed.other",
+        "💀 {name} killed! +{count} coins",
+    ),
+    (
+        "event.monster_leaked.one",
+        "⚠️ {name} got through, -{count} life. Player life: {life}",
+    ),
+    (
+        "event.monster_leaked.other",
+        "⚠️ {name} got through, -{count} lives. Player life: {life}",
+    ),
+    (
+        "event.tower_placed.one",
+        "🏗️ {name} built at [{x}, {y}] for {count} coin",
+    ),
+    (
+        "event.tower_placed.other",
+        "🏗️ {name} built at [{x}, {y}] for {count} coins",
+    ),
+    (
+        "event.tower_upgraded.one",
+        "🔧 {name} upgraded for {count} coin",
+    ),
+    (
+        "event.tower_upgraded.other",
+        "🔧 {name} upgraded for {count} coins",
+    ),
+    ("event.tower_sold.one", "💸 {name} sold, +{count} coin"),
+    ("event.tower_sold.other", "💸 {name} sold, +{count} coins"),
+    (
+        "event.wave_started.one",
+        "🚩 Wave {wave} starting: {count} monster",
+    ),
+    (
+        "event.wave_started.other",
+        "🚩 Wave {wave} starting: {count} monsters",
+    ),
+    (
+        "event.wave_called.one",
+        "⏭️ Wave {wave} called early: {count} monster",
+    ),
+    (
+        "event.wave_called.other",
+        "⏭️ Wave {wave} called early: {count} monsters",
+    ),
+    (
+        "event.wave_cleared.one",
+        "🏆 Wave {wave} cleared! +{count} coin bonus",
+    ),
+    (
+        "event.wave_cleared.other",
+        "🏆 Wave {wave} cleared! +{count} coins bonus",
+    ),
+    ("event.game_over", "☠️ Game Over! You lost!"),
+    ("event.victory.one", "🎉 VICTORY! The wave was repelled!"),
+    (
+        "event.victory.other",
+        "🎉 VICTORY! All {count} waves were repelled!",
+    ),
+    // Erreurs
+    (
+        "error.selling_forbidden",
+        "Selling is forbidden in this game.",
+    ),
+    ("error.no_tower_here", "No tower at ({x}, {y})."),
+    ("error.single_tower_type", "Only {name} towers are allowed"),
+    ("error.tower_not_found", "Tower to upgrade not found."),
+    (
+        "error.upgrade_maxed",
+        "This upgrade is already at max level.",
+    ),
+    ("error.invalid_position", "Cannot build at ({x}, {y})"),
+    ("error.missing_map", "No map selected"),
+    ("error.missing_stat", "Missing stat: {stat}"),
+    ("error.notification", "Notification failed: {error}"),
+    (
+        "error.insufficient_funds.one",
+        "Not enough money: {count} coin needed, {available} available",
+    ),
+    (
+        "error.insufficient_funds.other",
+        "Not enough money: {count} coins needed, {available} available",
+    ),
+    ("error.title", "Action not possible"),
+    // Journal
+    (
+        "log.missing_money.one",
+        "❌ Not enough coins! ({count} coin)",
+    ),
+    (
+        "log.missing_money.other",
+        "❌ Not enough coins! ({count} coins)",
+    ),
+    ("log.no_more_waves", "No more waves to call."),
+    ("log.preparing_wave", "✅ Preparing the next wave..."),
+    ("log.tower_upgraded", "Tower upgraded"),
+    (
+        "log.tutorial_finished",
+        "🎓 Tutorial complete, the game begins!",
+    ),
+    ("log.no_tower_to_select", "No tower to select on the map."),
+    ("log.no_tower_selected", "No tower selected."),
+    (
+        "log.no_tower_to_upgrade",
+        "No tower to upgrade at this position.",
+    ),
+    ("log.game_speed", "⏩ Game speed: x{speed}"),
+    ("log.targeting_changed", "🎯 {name} targeting: {target}"),
+    ("log.targeting_fixed", "{name} cannot change its targeting."),
+    ("log.tower_inspected", "🔍 {name} (Level {level})"),
+    ("log.tower_selected", "🔍 {name} (Level {level}) selected"),
+    // Règles de la partie
+    ("difficulty.easy", "Easy"),
+    ("difficulty.normal", "Normal"),
+    ("difficulty.hard", "Hard"),
+    ("difficulty.nightmare", "Nightmare"),
+    ("difficulty.custom", "{difficulty} (custom)"),
+    ("mutator.fast_monsters", "Fast monsters"),
+    (
+        "mutator.fast_monsters.description",
+        "Monsters move 50% faster",
+    ),
+    ("mutator.no_selling", "No selling"),
+    (
+        "mutator.no_selling.description",
+        "Placed towers can no longer be sold",
+    ),
+    ("mutator.double_armor", "Double armor"),
+    (
+        "mutator.double_armor.description",
+        "Monster armor is doubled, at least +25% (90% maximum)",
+    ),
+    ("mutator.random_elements", "Random elements"),
+    (
+        "mutator.random_elements.description",
+        "Each monster resists a random element",
+    ),
+    ("mutator.single_tower_type", "Single tower type"),
+    (
+        "mutator.single_tower_type.description",
+        "Only the first tower type built is allowed",
+    ),
+    ("mode.standard", "Standard"),
+    ("mode.endless", "Endless"),
+    ("mode.campaign", "Campaign"),
+    ("elite.fire", "Fire {name}"),
+    ("elite.water", "Water {name}"),
+    ("elite.earth", "Earth {name}"),
+    ("elite.air", "Air {name}"),
+    ("elite.armored", "Armored {name}"),
+    // Tours
+    ("stat.range", "Range"),
+    ("stat.damage", "Damage"),
+    ("stat.attack_speed", "Attack speed"),
+    ("stat.money", "Money"),
+    ("stat.generation_speed", "Generation speed"),
+    ("target.flying", "Flying"),
+    ("target.nearest", "Nearest"),
+    ("target.first", "First on path"),
+    ("target.farthest", "Farthest"),
+    ("target.strongest", "Strongest"),
+    ("target.weakest", "Weakest"),
+    ("target.all", "All"),
+    ("sentinel.title", "Monster detected"),
+    (
+        "sentinel.message",
+        "A monster is approaching the Sentinel tower at ({x}, {y})",
+    ),
+    ("element.neutral", "neutral"),
+    ("element.fire", "fire"),
+    ("element.water", "water"),
+    ("element.earth", "earth"),
+    ("element.air", "air"),
+    ("element.lightning", "lightning"),
+    ("element.ice", "ice"),
+    ("element.poison", "poison"),
+    // Cartes
+    ("map.forest", "Enchanted Forest"),
+    ("map.forest.description", "A dense and mysterious forest"),
+    ("map.desert", "Arid Desert"),
+    ("map.desert.description", "A dry and hostile desert"),
+    ("map.cave", "Deep Cave"),
+    ("map.cave.description", "A deep and dark cave"),
+    ("map.labyrinth", "Labyrinth"),
+    (
+        "map.labyrinth.description",
+        "A maze generated once and for all",
+    ),
+    ("map_difficulty.easy", "Easy"),
+    ("map_difficulty.normal", "Normal"),
+    ("map_difficulty.hard", "Hard"),
+    ("random_map.forest", "Forest"),
+    ("random_map.desert", "Desert"),
+    ("random_map.cave", "Cave"),
+    ("random_map.name", "Random map #{seed}"),
+    (
+        "random_map.description",
+        "{theme} {width}x{height}, {difficulty} difficulty",
+    ),
+    ("map.error.missing_name", "The map must have a name"),
+    (
+        "map.error.invalid_size",
+        "Invalid size {width}x{height} (between {min} and {max})",
+    ),
+    (
+        "map.error.too_few_waypoints",
+        "The path must have at least two waypoints",
+    ),
+    (
+        "map.error.waypoint_outside",
+        "Waypoint ({x}, {y}) is outside the map",
+    ),
+    (
+        "map.error.duplicate_waypoint",
+        "Two consecutive waypoints are identical",
+    ),
+    (
+        "map.error.path_crossing",
+        "The path crosses itself at ({x}, {y})",
+    ),
+    (
+        "map.error.no_monster",
+        "The map must define at least one monster",
+    ),
+    ("map_file.invalid_line", "Invalid line {number}: {line}"),
+    ("map_file.unknown_terrain", "Unknown terrain: {terrain}"),
+    ("map_file.unknown_monster", "Unknown monster: {monster}"),
+    ("map_file.invalid_position", "Invalid position: {value}"),
+    ("map_file.invalid_scaling", "Invalid scaling: {value}"),
+    ("map_file.invalid_decoration", "Invalid decoration: {value}"),
+    // Campagne
+    ("campaign.forest", "The Edge of the Woods"),
+    (
+        "campaign.forest.description",
+        "A few goblins venture out of the forest",
+    ),
+    ("campaign.desert", "Crossing the Desert"),
+    (
+        "campaign.desert.description",
+        "Orcs join the goblins under the sun",
+    ),
+    ("campaign.cave", "The Depths"),
+    (
+        "campaign.cave.description",
+        "A long tunnel where monsters arrive in hordes",
+    ),
+    ("campaign.labyrinth", "The Labyrinth"),
+    (
+        "campaign.labyrinth.description",
+        "A short, direct path: every tower counts",
+    ),
+    ("campaign.title", "Campaign"),
+    ("campaign.locked", "locked"),
+    ("campaign.levels", "Levels"),
+    ("campaign.details", "Details"),
+    ("campaign.objective.one", "Goal: survive {count} wave"),
+    ("campaign.objective.other", "Goal: survive {count} waves"),
+    (
+        "campaign.stars_rule",
+        "Stars: 3 without losing a life, 2 with half of your lives",
+    ),
+    ("campaign.unlocks", "Unlocks: {towers}"),
+    (
+        "campaign.locked_hint",
+        "Clear the previous level to unlock it",
+    ),
+    ("campaign.available_towers", "Available towers: {towers}"),
+    (
+        "campaign.hint",
+        "↑↓: choose a level | Enter: play | Esc: back",
+    ),
+    ("campaign.level_cleared", "LEVEL CLEARED"),
+    ("campaign.level_failed", "LEVEL FAILED"),
+    (
+        "campaign.result",
+        "Waves repelled: {waves} | Lives left: {life}",
+    ),
+    ("campaign.unlocked_level", "New level unlocked: {level}"),
+    ("campaign.unlocked_towers", "New towers unlocked: {towers}"),
+    ("campaign.next_level", "Next level"),
+    ("campaign.back", "Back to the campaign"),
+    ("campaign.retry", "Retry"),
+    ("campaign.map", "Campaign map"),
+    // Menus
+    ("menu.title", "Main Menu"),
+    ("menu.subtitle", "Defend your land against waves of enemies"),
+    ("menu.hint", "Use ↑↓ to navigate and Enter to select"),
+    ("menu.new_game", "Start a new game"),
+    ("menu.campaign", "Campaign"),
+    ("menu.tutorial", "Tutorial"),
+    ("menu.map_editor", "Map editor"),
+    ("menu.settings", "Settings"),
+    ("menu.help", "Help"),
+    ("menu.quit", "Quit"),
+    ("menu.main_menu", "Main menu"),
+    ("pause.title", "Pause"),
+    ("pause.resume", "Resume"),
+    ("game_over.title", "GAME OVER"),
+    ("game_over.block", "Game over"),
+    ("game_over.new_game", "New game"),
+    ("game_over.waves", "Waves completed: {waves}"),
+    ("victory.title", "VICTORY!"),
+    ("summary.map", "Map: {map}"),
+    ("summary.rules", "Rules: {rules}"),
+    ("summary.waves_repelled", "Waves repelled: {waves}"),
+    ("summary.waves_survived", "Waves survived: {waves}"),
+    ("summary.lives_left", "Lives left: {life}/{total}"),
+    ("summary.kills", "Monsters killed: {kills}"),
+    ("summary.leaks", "Monsters through: {leaks}"),
+    ("summary.money_earned", "Coins earned: {money}"),
+    ("summary.towers", "Towers in play: {towers}"),
+    ("summary.duration", "Duration: {duration}"),
+    ("endless.title", "ENDLESS RUN OVER"),
+    ("endless.block", "Summary"),
+    ("endless.new_record", "New record!"),
+    (
+        "endless.previous_best.one",
+        "Previous best: {score} ({count} wave)",
+    ),
+    (
+        "endless.previous_best.other",
+        "Previous best: {score} ({count} waves)",
+    ),
+    ("endless.replay", "Play again"),
+    ("endless.other_map", "Choose another map"),
+    ("map_selection.title", "Map selection"),
+    ("map_selection.maps", "Available maps"),
+    ("map_selection.random", "Random map"),
+    (
+        "map_selection.random_details",
+        "Difficulty: {difficulty} | Size: {width}x{height} | Seed: {seed}",
+    ),
+    (
+        "map_selection.random_hint",
+        "←→: difficulty, a/d: width, w/s: height, r: new seed, 0-9: type the seed",
+    ),
+    ("run.title", "Game"),
+    ("run.mode", "Mode"),
+    ("run.resources", "{money} coins, {life} lives"),
+    ("run.rewards", "Rewards x{multiplier}"),
+    // Vue de jeu
+    ("terminal.too_small", "Terminal too small"),
+    ("terminal.current_size", "Current size: {width}x{height}"),
+    ("terminal.required_size", "Required size: {width}x{height}"),
+    ("terminal.paused", "Game paused"),
+    ("hud.life", "Life: {life}"),
+    ("hud.wave", "Wave: {wave}"),
+    ("hud.wave_of", "Wave: {wave}/{total}"),
+    ("hud.score", "Score: {score}"),
+    ("hud.money", "Money: {money}"),
+    ("monsters.title", "Monsters"),
+    ("monsters.hp", "{name}: HP {hp}"),
+    ("logs.title", "Logs"),
+    ("controls.title", "Controls"),
+    ("controls.quit", "Quit"),
+    ("controls.pause", "Pause"),
+    ("controls.help", "Help"),
+    ("controls.cancel", "Cancel"),
+    ("controls.select_action", "Select action"),
+    ("controls.confirm_action", "Confirm action"),
+    ("controls.browse_towers", "Browse towers"),
+    ("controls.select", "Select"),
+    ("controls.select_type", "Select type"),
+    ("controls.choose", "Choose"),
+    ("controls.move_cursor_map", "Move the cursor on the map"),
+    ("controls.select_tower", "Select the tower"),
+    ("controls.move_cursor", "Move cursor"),
+    ("controls.confirm", "Confirm"),
+    ("controls.select_upgrade", "Select upgrade"),
+    ("controls.upgrade", "Upgrade"),
+    ("action.title", "Actions"),
+    ("action.build", "Build tower"),
+    ("action.remove", "Remove tower"),
+    ("action.upgrade", "Upgrade existing tower"),
+    ("action.tower_types", "Types of towers"),
+    ("placement.title", "Placement"),
+    (
+        "placement.move_cursor",
+        "Use the arrow keys to position the cursor",
+    ),
+    (
+        "placement.move_cursor_map",
+        "Use the arrow keys to move the cursor on the map",
+    ),
+    ("placement.cancel", "Press Escape to cancel"),
+    ("placement.build.title", "Placement mode - {name}"),
+    ("placement.build.confirm", "Press Enter to place the tower"),
+    ("placement.upgrade.title", "Tower upgrade mode"),
+    ("placement.upgrade.target", "Move onto an existing tower"),
+    (
+        "placement.upgrade.confirm",
+        "Press Enter to select the tower to upgrade",
+    ),
+    ("placement.remove.title", "Tower removal mode"),
+    (
+        "placement.remove.confirm",
+        "Press Enter to remove the tower",
+    ),
+    ("upgrade.title", "Upgrade a tower"),
+    (
+        "upgrade.tower_info",
+        "{name} (x:{x}, y:{y}) - Level {level}",
+    ),
+    ("upgrade.choose", "Choose an upgrade:"),
+    ("upgrade.maxed", "Maxed"),
+    // Tutoriel
+    ("tutorial.title", "Tutorial"),
+    ("tutorial.place.title", "Step 1/3: place a tower"),
+    (
+        "tutorial.place.hint",
+        "Choose \"{build}\" with {confirm} (or press 1), move the cursor off the path and confirm with {confirm}.",
+    ),
+    ("tutorial.upgrade.title", "Step 2/3: upgrade a tower"),
+    (
+        "tutorial.upgrade.hint",
+        "Select your tower with {next_tower}, confirm with {confirm} then choose an upgrade.",
+    ),
+    ("tutorial.sell.title", "Step 3/3: sell a tower"),
+    (
+        "tutorial.sell.hint",
+        "Select a tower with {next_tower} then press {sell}: half of its cost is refunded.",
+    ),
+    (
+        "tutorial.paused",
+        "The game is paused during the tutorial ({skip}: skip)",
+    ),
+    // Éditeur de cartes
+    ("editor.new_map", "New map"),
+    ("editor.map_description", "Custom map {width}x{height}"),
+    ("editor.cannot_paint_path", "Cannot paint on the path"),
+    ("editor.map_valid", "Map is valid"),
+    ("editor.saved", "Map saved to {path}"),
+    ("editor.name", "Name"),
+    ("editor.size", "Size: {width}x{height}"),
+    ("editor.cursor", "Cursor: ({x}, {y})"),
+    ("editor.tool", "Tool"),
+    ("editor.tool.path", "Path"),
+    ("editor.tool.terrain", "Terrain"),
+    ("editor.tool.erase", "Eraser"),
+    (
+        "editor.symbols",
+        "Start: {start}  End: {end}  Terrain: {terrain}",
+    ),
+    ("editor.waypoints", "Waypoints: {count}"),
+    ("editor.hint.move", "{move}: Move | {apply}: Apply tool"),
+    (
+        "editor.hint.tools",
+        "{tool}: Change tool | {undo}: Remove last waypoint",
+    ),
+    (
+        "editor.hint.size",
+        "{width}: Width | {height}: Height | {rename}: Rename",
+    ),
+    (
+        "editor.hint.symbols",
+        "{symbols}: Start/end symbol | {terrain}: Terrain symbol",
+    ),
+    (
+        "editor.hint.actions",
+        "1-9: Toggle a monster | {validate}: Validate | {save}: Save",
+    ),
+    ("editor.hint.back", "{back}: Back to menu"),
+    // Paramètres
+    ("settings.difficulty", "Difficulty"),
+    ("settings.wave_multiplier", "Wave progression"),
+    ("settings.starting_money", "Starting money"),
+    ("settings.starting_life", "Starting lives"),
+    ("settings.tick_rate", "Refresh rate"),
+    ("settings.display_mode", "Display"),
+    ("settings.language", "Language"),
+    ("settings.notifications", "Notifications"),
+    ("settings.back", "Back"),
+    ("settings.enabled", "On"),
+    ("settings.disabled", "Off"),
+    ("settings.hint", "←→ or Enter to change, Esc to go back"),
+    ("settings.next_game", "Changes apply to the next game"),
+    ("config.invalid_line", "Invalid line: {line}"),
+    ("config.unknown_key", "Unknown key: {key}"),
+    ("config.missing_key", "Missing key: {key}"),
+    ("config.invalid_value", "Invalid value for {key}: {value}"),
+    (
+        "config.invalid_webhook",
+        "Only http:// addresses are supported: {url}",
+    ),
+    ("file.read_failed", "Cannot read {path}: {error}"),
+    ("file.create_dir_failed", "Cannot create {path}: {error}"),
+    ("file.write_failed", "Cannot write {path}: {error}"),
+    (
+        "campaign_file.invalid_stars",
+        "Invalid stars for {level}: {line}",
+    ),
+    ("scores_file.invalid_entry", "Invalid score: {line}"),
+    ("glyphs.missing_glyph", "Missing character: {line}"),
+    ("glyphs.invalid_color", "Invalid color: {color}"),
+    // Aide
+    ("help.controls", "Controls"),
+    ("help.elements", "Elements"),
+    ("help.towers", "Towers"),
+    ("help.monsters", "Monsters"),
+    ("help.keys", "Keys"),
+    ("help.quick_build", "Build tower #1 to 9 directly"),
+    ("help.close", "{help} or {cancel} to close"),
+    ("help.hint", "{pages}: change page | {back}: back"),
+    ("help.elements.header", "Att./Target"),
+    (
+        "help.elements.legend",
+        "Green: increased damage, red: reduced damage",
+    ),
+    (
+        "help.towers.stat",
+        "{stat}: {value} (max level {max_level})",
+    ),
+    (
+        "help.monsters.stats",
+        "HP: {hp} | Speed: {speed} tiles/s | Armor: {armor}% | Base damage: {damage}",
+    ),
+    ("key.move_up", "Move up"),
+    ("key.move_down", "Move down"),
+    ("key.move_left", "Move left"),
+    ("key.move_right", "Move right"),
+    ("key.confirm", "Confirm"),
+    ("key.cancel", "Cancel"),
+    ("key.pause", "Pause"),
+    ("key.quit", "Quit"),
+    ("key.build", "Build a tower"),
+    ("key.sell", "Sell a tower"),
+    ("key.upgrade", "Upgrade a tower"),
+    ("key.speed", "Change game speed"),
+    ("key.next_wave", "Call the next wave"),
+    ("key.targeting", "Change tower targeting"),
+    ("key.next_tower", "Next tower"),
+    ("key.previous_tower", "Previous tower"),
+    ("key.upgrade_range", "Upgrade range"),
+    ("key.upgrade_damage", "Upgrade damage"),
+    ("key.upgrade_speed", "Upgrade attack speed"),
+    ("key.upgrade_money", "Upgrade income"),
+    ("key.scroll_up", "Scroll up"),
+    ("key.scroll_down", "Scroll down"),
+    ("key.scroll_left", "Scroll left"),
+    ("key.scroll_right", "Scroll right"),
+    ("key.center_camera", "Center view on cursor"),
+    ("key.reroll_seed", "New random seed"),
+    ("key.help", "Show help"),
+    ("key_name.enter", "Enter"),
+    ("key_name.backtab", "Shift+Tab"),
+    ("key_name.backspace", "Backspace"),
+    ("key_name.space", "Space"),
+    ("keymap.unknown_action", "Unknown action: {action}"),
+    ("keymap.unknown_preset", "Unknown key preset: {preset}"),
+    ("keymap.unknown_key", "Unknown key: {key}"),
+    (
+        "keymap.conflict",
+        "Key {key} is bound to both {first} and {second}",
+    ),
+    // Notifications
+    ("notification.category.monster_detected", "Monster detected"),
+    ("notification.category.low_life", "Low life"),
+    ("notification.category.wave_cleared", "Wave cleared"),
+    ("notification.category.boss", "Final wave"),
+    ("notification.category.game_over", "Game over"),
+    (
+        "notification.monsters_detected.one",
+        "{count} monster detected",
+    ),
+    (
+        "notification.monsters_detected.other",
+        "{count} monsters detected",
+    ),
+    ("notification.waves_cleared.one", "{count} wave cleared"),
+    ("notification.waves_cleared.other", "{count} waves cleared"),
+    ("notification.low_life.one", "❤️ Only one life left!"),
+    ("notification.low_life.other", "❤️ Only {count} lives left!"),
+    (
+        "notification.boss",
+        "👑 Wave {wave}: the final wave is coming!",
+    ),
+    (
+        "notification.send_failed",
+        "Cannot show notification: {error}",
+    ),
+    ("dnd.unavailable", "{program} unavailable: {error}"),
+    ("dnd.failed", "{program} failed"),
+    (
+        "dnd.unexpected",
+        "Unexpected answer from {program}: {output}",
+    ),
+    ("dnd.no_probe", "No \"do not disturb\" probe"),
+    (
+        "webhook.unsupported_scheme",
+        "Only http:// addresses are supported: {url}",
+    ),
+    ("webhook.invalid_port", "Invalid port in address: {url}"),
+    ("webhook.missing_host", "Missing host in address: {url}"),
+    (
+        "webhook.worker_stopped",
+        "The webhook sender thread stopped",
+    ),
+    (
+        "webhook.address_not_found",
+        "Webhook address not found: {error}",
+    ),
+    (
+        "webhook.connect_failed",
+        "Cannot connect to webhook: {error}",
+    ),
+    ("webhook.send_failed", "Cannot send to webhook: {error}"),
+    (
+        "webhook.unreadable_response",
+        "Unreadable webhook response: {error}",
+    ),
+    ("webhook.invalid_response", "Invalid webhook response"),
+    ("webhook.status", "The webhook answered {status}"),
+];
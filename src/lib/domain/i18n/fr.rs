@@ -0,0 +1,722 @@
+//! Textes en français, langue de référence : tout identifiant doit y figurer
+
+pub const MESSAGES: &[(&str, &str)] = &[
+    // Événements de la partie
+    (
+        "event.monster_spawned",
+        "👾 Apparition d'un monstre {name}! HP: {hp}",
+    ),
+    (
+        "event.monster_killed.one",
+        "💀 Monstre {name} éliminé! +{count} pièce",
+    ),
+    (
+        "event.monster_killed.other",
+        "💀 Monstre {name} éliminé! +{count} pièces",
+    ),
+    (
+        "event.monster_leaked.one",
+        "⚠️ Monstre {name} arrivé, -{count} vie. Vie joueur: {life}",
+    ),
+    (
+        "event.monster_leaked.other",
+        "⚠️ Monstre {name} arrivé, -{count} vies. Vie joueur: {life}",
+    ),
+    (
+        "event.tower_placed.one",
+        "🏗️ Tour {name} construite en [{x}, {y}] pour {count} pièce",
+    ),
+    (
+        "event.tower_placed.other",
+        "🏗️ Tour {name} construite en [{x}, {y}] pour {count} pièces",
+    ),
+    (
+        "event.tower_upgraded.one",
+        "🔧 Tour {name} améliorée pour {count} pièce",
+    ),
+    (
+        "event.tower_upgraded.other",
+        "🔧 Tour {name} améliorée pour {count} pièces",
+    ),
+    (
+        "event.tower_sold.one",
+        "💸 Tour {name} vendue, +{count} pièce",
+    ),
+    (
+        "event.tower_sold.other",
+        "💸 Tour {name} vendue, +{count} pièces",
+    ),
+    (
+        "event.wave_started.one",
+        "🚩 Démarrage vague {wave}: {count} monstre",
+    ),
+    (
+        "event.wave_started.other",
+        "🚩 Démarrage vague {wave}: {count} monstres",
+    ),
+    (
+        "event.wave_called.one",
+        "⏭️ Vague {wave} appelée en avance: {count} monstre",
+    ),
+    (
+        "event.wave_called.other",
+        "⏭️ Vague {wave} appelée en avance: {count} monstres",
+    ),
+    (
+        "event.wave_cleared.one",
+        "🏆 Vague {wave} terminée! Bonus de +{count} pièce",
+    ),
+    (
+        "event.wave_cleared.other",
+        "🏆 Vague {wave} terminée! Bonus de +{count} pièces",
+    ),
+    ("event.game_over", "☠️ Game Over! Vous avez perdu!"),
+    (
+        "event.victory.one",
+        "🎉 VICTOIRE! La vague a été repoussée!",
+    ),
+    (
+        "event.victory.other",
+        "🎉 VICTOIRE! Les {count} vagues ont été repoussées!",
+    ),
+    // Erreurs
+    (
+        "error.selling_forbidden",
+        "La vente est interdite pour cette partie.",
+    ),
+    ("error.no_tower_here", "Aucune tour en ({x}, {y})."),
+    (
+        "error.single_tower_type",
+        "Seules les tours {name} sont autorisées",
+    ),
+    (
+        "error.tower_not_found",
+        "Tour non trouvée pour l'amélioration.",
+    ),
+    (
+        "error.upgrade_maxed",
+        "Cette amélioration est déjà au niveau maximum.",
+    ),
+    (
+        "error.invalid_position",
+        "Impossible de construire en ({x}, {y})",
+    ),
+    ("error.missing_map", "Aucune carte sélectionnée"),
+    ("error.missing_stat", "Caractéristique manquante: {stat}"),
+    ("error.notification", "Notification impossible: {error}"),
+    (
+        "error.insufficient_funds.one",
+        "Pas assez d'argent: {count} pièce nécessaire, {available} disponibles",
+    ),
+    (
+        "error.insufficient_funds.other",
+        "Pas assez d'argent: {count} pièces nécessaires, {available} disponibles",
+    ),
+    ("error.title", "Action impossible"),
+    // Journal
+    (
+        "log.missing_money.one",
+        "❌ Pas assez de pièces! ({count} pièce)",
+    ),
+    (
+        "log.missing_money.other",
+        "❌ Pas assez de pièces! ({count} pièces)",
+    ),
+    ("log.no_more_waves", "Aucune autre vague à appeler."),
+    (
+        "log.preparing_wave",
+        "✅ Préparation de la prochaine vague...",
+    ),
+    ("log.tower_upgraded", "Tour améliorée"),
+    (
+        "log.tutorial_finished",
+        "🎓 Tutoriel terminé, la partie commence !",
+    ),
+    (
+        "log.no_tower_to_select",
+        "Aucune tour à sélectionner sur la carte.",
+    ),
+    ("log.no_tower_selected", "Aucune tour sélectionnée."),
+    (
+        "log.no_tower_to_upgrade",
+        "Aucune tour à cette position pour amélioration.",
+    ),
+    ("log.game_speed", "⏩ Vitesse du jeu: x{speed}"),
+    ("log.targeting_changed", "🎯 Ciblage de {name}: {target}"),
+    (
+        "log.targeting_fixed",
+        "{name} ne peut pas changer de ciblage.",
+    ),
+    ("log.tower_inspected", "🔍 Tour {name} (Niveau {level})"),
+    (
+        "log.tower_selected",
+        "🔍 Tour {name} (Niveau {level}) sélectionnée",
+    ),
+    // Règles de la partie
+    ("difficulty.easy", "Facile"),
+    ("difficulty.normal", "Normal"),
+    ("difficulty.hard", "Difficile"),
+    ("difficulty.nightmare", "Cauchemar"),
+    ("difficulty.custom", "{difficulty} (personnalisé)"),
+    ("mutator.fast_monsters", "Monstres rapides"),
+    (
+        "mutator.fast_monsters.description",
+        "Les monstres avancent 50% plus vite",
+    ),
+    ("mutator.no_selling", "Vente interdite"),
+    (
+        "mutator.no_selling.description",
+        "Les tours posées ne peuvent plus être vendues",
+    ),
+    ("mutator.double_armor", "Armure doublée"),
+    (
+        "mutator.double_armor.description",
+        "L'armure des monstres est doublée, +25% au minimum (90% maximum)",
+    ),
+    ("mutator.random_elements", "Éléments aléatoires"),
+    (
+        "mutator.random_elements.description",
+        "Chaque monstre résiste à un élément au hasard",
+    ),
+    ("mutator.single_tower_type", "Un seul type de tour"),
+    (
+        "mutator.single_tower_type.description",
+        "Seul le premier type de tour construit est autorisé",
+    ),
+    ("mode.standard", "Standard"),
+    ("mode.endless", "Sans fin"),
+    ("mode.campaign", "Campagne"),
+    ("elite.fire", "{name} de feu"),
+    ("elite.water", "{name} d'eau"),
+    ("elite.earth", "{name} de terre"),
+    ("elite.air", "{name} d'air"),
+    ("elite.armored", "{name} cuirassé"),
+    // Tours
+    ("stat.range", "Portée"),
+    ("stat.damage", "Dégâts"),
+    ("stat.attack_speed", "Cadence"),
+    ("stat.money", "Gains"),
+    ("stat.generation_speed", "Vitesse de production"),
+    ("target.flying", "Volants"),
+    ("target.nearest", "Plus proche"),
+    ("target.first", "Premier sur le chemin"),
+    ("target.farthest", "Plus éloigné"),
+    ("target.strongest", "Plus fort"),
+    ("target.weakest", "Plus faible"),
+    ("target.all", "Tous"),
+    ("sentinel.title", "Monstre détecté"),
+    (
+        "sentinel.message",
+        "Un monstre approche de la tour Sentinelle en ({x}, {y})",
+    ),
+    ("element.neutral", "neutre"),
+    ("element.fire", "feu"),
+    ("element.water", "eau"),
+    ("element.earth", "terre"),
+    ("element.air", "air"),
+    ("element.lightning", "foudre"),
+    ("element.ice", "glace"),
+    ("element.poison", "poison"),
+    // Cartes
+    ("map.forest", "Forêt Enchantée"),
+    ("map.forest.description", "Une forêt dense et mystérieuse"),
+    ("map.desert", "Désert Aride"),
+    ("map.desert.description", "Un désert aride et hostile"),
+    ("map.cave", "Caverne Profonde"),
+    ("map.cave.description", "Une caverne profonde et sombre"),
+    ("map.labyrinth", "Labyrinthe"),
+    (
+        "map.labyrinth.description",
+        "Un dédale généré une fois pour toutes",
+    ),
+    ("map_difficulty.easy", "Facile"),
+    ("map_difficulty.normal", "Normale"),
+    ("map_difficulty.hard", "Difficile"),
+    ("random_map.forest", "Forêt"),
+    ("random_map.desert", "Désert"),
+    ("random_map.cave", "Caverne"),
+    ("random_map.name", "Carte aléatoire #{seed}"),
+    (
+        "random_map.description",
+        "{theme} {width}x{height}, difficulté {difficulty}",
+    ),
+    ("map.error.missing_name", "La carte doit avoir un nom"),
+    (
+        "map.error.invalid_size",
+        "Dimensions {width}x{height} invalides (entre {min} et {max})",
+    ),
+    (
+        "map.error.too_few_waypoints",
+        "Le chemin doit contenir au moins deux waypoints",
+    ),
+    (
+        "map.error.waypoint_outside",
+        "Le waypoint ({x}, {y}) est hors de la carte",
+    ),
+    (
+        "map.error.duplicate_waypoint",
+        "Deux waypoints consécutifs sont identiques",
+    ),
+    (
+        "map.error.path_crossing",
+        "Le chemin se croise lui-même en ({x}, {y})",
+    ),
+    (
+        "map.error.no_monster",
+        "La carte doit définir au moins un monstre",
+    ),
+    ("map_file.invalid_line", "Ligne {number} invalide: {line}"),
+    ("map_file.unknown_terrain", "Terrain inconnu: {terrain}"),
+    ("map_file.unknown_monster", "Monstre inconnu: {monster}"),
+    ("map_file.invalid_position", "Position invalide: {value}"),
+    ("map_file.invalid_scaling", "Progression invalide: {value}"),
+    ("map_file.invalid_decoration", "Décor invalide: {value}"),
+    // Campagne
+    ("campaign.forest", "La lisière"),
+    (
+        "campaign.forest.description",
+        "Quelques gobelins s'aventurent hors de la forêt",
+    ),
+    ("campaign.desert", "La traversée du désert"),
+    (
+        "campaign.desert.description",
+        "Les orcs rejoignent les gobelins sous le soleil",
+    ),
+    ("campaign.cave", "Les profondeurs"),
+    (
+        "campaign.cave.description",
+        "Un long tunnel où les monstres arrivent en masse",
+    ),
+    ("campaign.labyrinth", "Le labyrinthe"),
+    (
+        "campaign.labyrinth.description",
+        "Un chemin court et direct : chaque tour compte",
+    ),
+    ("campaign.title", "Campagne"),
+    ("campaign.locked", "verrouillé"),
+    ("campaign.levels", "Niveaux"),
+    ("campaign.details", "Détails"),
+    (
+        "campaign.objective.one",
+        "Objectif: survivre à {count} vague",
+    ),
+    (
+        "campaign.objective.other",
+        "Objectif: survivre à {count} vagues",
+    ),
+    (
+        "campaign.stars_rule",
+        "Étoiles: 3 sans perte de vie, 2 avec la moitié des vies",
+    ),
+    ("campaign.unlocks", "Débloque: {towers}"),
+    (
+        "campaign.locked_hint",
+        "Réussissez le niveau précédent pour le débloquer",
+    ),
+    ("campaign.available_towers", "Tours disponibles: {towers}"),
+    (
+        "campaign.hint",
+        "↑↓: choisir un niveau | Entrée: jouer | Échap: retour",
+    ),
+    ("campaign.level_cleared", "NIVEAU RÉUSSI"),
+    ("campaign.level_failed", "NIVEAU ÉCHOUÉ"),
+    (
+        "campaign.result",
+        "Vagues repoussées: {waves} | Vies restantes: {life}",
+    ),
+    (
+        "campaign.unlocked_level",
+        "Nouveau niveau débloqué: {level}",
+    ),
+    (
+        "campaign.unlocked_towers",
+        "Nouvelles tours débloquées: {towers}",
+    ),
+    ("campaign.next_level", "Niveau suivant"),
+    ("campaign.back", "Retour à la campagne"),
+    ("campaign.retry", "Réessayer"),
+    ("campaign.map", "Carte de la campagne"),
+    // Menus
+    ("menu.title", "Menu Principal"),
+    (
+        "menu.subtitle",
+        "Défendez votre territoire contre des vagues d'ennemis",
+    ),
+    (
+        "menu.hint",
+        "Utilisez ↑↓ pour naviguer et Entrée pour sélectionner",
+    ),
+    ("menu.new_game", "Démarrer une nouvelle partie"),
+    ("menu.campaign", "Campagne"),
+    ("menu.tutorial", "Tutoriel"),
+    ("menu.map_editor", "Éditeur de cartes"),
+    ("menu.settings", "Paramètres"),
+    ("menu.help", "Aide"),
+    ("menu.quit", "Quitter"),
+    ("menu.main_menu", "Menu principal"),
+    ("pause.title", "Pause"),
+    ("pause.resume", "Reprendre"),
+    ("game_over.title", "GAME OVER"),
+    ("game_over.block", "Fin de partie"),
+    ("game_over.new_game", "Nouvelle partie"),
+    ("game_over.waves", "Vagues complétées: {waves}"),
+    ("victory.title", "VICTOIRE!"),
+    ("summary.map", "Carte: {map}"),
+    ("summary.rules", "Règles: {rules}"),
+    ("summary.waves_repelled", "Vagues repoussées: {waves}"),
+    ("summary.waves_survived", "Vagues survécues: {waves}"),
+    ("summary.lives_left", "Vies restantes: {life}/{total}"),
+    ("summary.kills", "Monstres éliminés: {kills}"),
+    ("summary.leaks", "Monstres passés: {leaks}"),
+    ("summary.money_earned", "Pièces gagnées: {money}"),
+    ("summary.towers", "Tours en jeu: {towers}"),
+    ("summary.duration", "Durée: {duration}"),
+    ("endless.title", "FIN DE LA PARTIE SANS FIN"),
+    ("endless.block", "Bilan"),
+    ("endless.new_record", "Nouveau record !"),
+    (
+        "endless.previous_best.one",
+        "Meilleur score précédent: {score} ({count} vague)",
+    ),
+    (
+        "endless.previous_best.other",
+        "Meilleur score précédent: {score} ({count} vagues)",
+    ),
+    ("endless.replay", "Rejouer"),
+    ("endless.other_map", "Choisir une autre carte"),
+    ("map_selection.title", "Sélection de carte"),
+    ("map_selection.maps", "Cartes disponibles"),
+    ("map_selection.random", "Carte aléatoire"),
+    (
+        "map_selection.random_details",
+        "Difficulté: {difficulty} | Taille: {width}x{height} | Graine: {seed}",
+    ),
+    (
+        "map_selection.random_hint",
+        "←→: difficulté, a/d: largeur, w/s: hauteur, r: nouvelle graine, 0-9: saisir la graine",
+    ),
+    ("run.title", "Partie"),
+    ("run.mode", "Mode"),
+    ("run.resources", "{money} pièces, {life} vies"),
+    ("run.rewards", "Récompenses x{multiplier}"),
+    // Vue de jeu
+    ("terminal.too_small", "Terminal trop petit"),
+    ("terminal.current_size", "Taille actuelle: {width}x{height}"),
+    ("terminal.required_size", "Taille requise: {width}x{height}"),
+    ("terminal.paused", "Jeu en pause"),
+    ("hud.life", "Vie: {life}"),
+    ("hud.wave", "Vague: {wave}"),
+    ("hud.wave_of", "Vague: {wave}/{total}"),
+    ("hud.score", "Score: {score}"),
+    ("hud.money", "Pièces: {money}"),
+    ("monsters.title", "Monstres"),
+    ("monsters.hp", "{name}: PV {hp}"),
+    ("logs.title", "Journal"),
+    ("controls.title", "Commandes"),
+    ("controls.quit", "Quitter"),
+    ("controls.pause", "Pause"),
+    ("controls.help", "Aide"),
+    ("controls.cancel", "Annuler"),
+    ("controls.select_action", "Sélectionner action"),
+    ("controls.confirm_action", "Confirmer action"),
+    ("controls.browse_towers", "Naviguer entre les tours"),
+    ("controls.select", "Sélectionner"),
+    ("controls.select_type", "Sélectionner type"),
+    ("controls.choose", "Choisir"),
+    (
+        "controls.move_cursor_map",
+        "Déplacer le curseur sur la carte",
+    ),
+    ("controls.select_tower", "Sélectionner la tour"),
+    ("controls.move_cursor", "Déplacer curseur"),
+    ("controls.confirm", "Confirmer"),
+    ("controls.select_upgrade", "Sélectionner amélioration"),
+    ("controls.upgrade", "Améliorer"),
+    ("action.title", "Actions"),
+    ("action.build", "Construire une tour"),
+    ("action.remove", "Supprimer une tour"),
+    ("action.upgrade", "Améliorer une tour existante"),
+    ("action.tower_types", "Types de tours"),
+    ("placement.title", "Placement"),
+    (
+        "placement.move_cursor",
+        "Utilisez les flèches pour positionner le curseur",
+    ),
+    (
+        "placement.move_cursor_map",
+        "Utilisez les flèches pour déplacer le curseur sur la carte",
+    ),
+    ("placement.cancel", "Appuyez sur Escape pour annuler"),
+    ("placement.build.title", "Mode placement - Tour {name}"),
+    (
+        "placement.build.confirm",
+        "Appuyez sur Enter pour placer la tour",
+    ),
+    ("placement.upgrade.title", "Mode amélioration de tour"),
+    (
+        "placement.upgrade.target",
+        "Positionnez-vous sur une tour existante",
+    ),
+    (
+        "placement.upgrade.confirm",
+        "Appuyez sur Enter pour sélectionner la tour à améliorer",
+    ),
+    ("placement.remove.title", "Mode suppression de tour"),
+    (
+        "placement.remove.confirm",
+        "Appuyez sur Enter pour supprimer la tour",
+    ),
+    ("upgrade.title", "Améliorer une tour"),
+    (
+        "upgrade.tower_info",
+        "Tour {name} (x:{x}, y:{y}) - Niveau {level}",
+    ),
+    ("upgrade.choose", "Choisissez une amélioration:"),
+    ("upgrade.maxed", "Max"),
+    // Tutoriel
+    ("tutorial.title", "Tutoriel"),
+    ("tutorial.place.title", "Étape 1/3 : placez une tour"),
+    (
+        "tutorial.place.hint",
+        "Choisissez « {build} » avec {confirm} (ou appuyez sur 1), déplacez le curseur hors du chemin et validez avec {confirm}.",
+    ),
+    ("tutorial.upgrade.title", "Étape 2/3 : améliorez une tour"),
+    (
+        "tutorial.upgrade.hint",
+        "Sélectionnez votre tour avec {next_tower}, validez avec {confirm} puis choisissez une amélioration.",
+    ),
+    ("tutorial.sell.title", "Étape 3/3 : vendez une tour"),
+    (
+        "tutorial.sell.hint",
+        "Sélectionnez une tour avec {next_tower} puis appuyez sur {sell} : la moitié de son coût vous est rendue.",
+    ),
+    (
+        "tutorial.paused",
+        "Le jeu est en pause pendant le tutoriel ({skip} : passer)",
+    ),
+    // Éditeur de cartes
+    ("editor.new_map", "Nouvelle carte"),
+    (
+        "editor.map_description",
+        "Carte personnalisée {width}x{height}",
+    ),
+    (
+        "editor.cannot_paint_path",
+        "Impossible de peindre sur le chemin",
+    ),
+    ("editor.map_valid", "Carte valide"),
+    ("editor.saved", "Carte enregistrée dans {path}"),
+    ("editor.name", "Nom"),
+    ("editor.size", "Dimensions: {width}x{height}"),
+    ("editor.cursor", "Curseur: ({x}, {y})"),
+    ("editor.tool", "Outil"),
+    ("editor.tool.path", "Chemin"),
+    ("editor.tool.terrain", "Terrain"),
+    ("editor.tool.erase", "Gomme"),
+    (
+        "editor.symbols",
+        "Départ: {start}  Arrivée: {end}  Terrain: {terrain}",
+    ),
+    ("editor.waypoints", "Waypoints: {count}"),
+    (
+        "editor.hint.move",
+        "{move}: Déplacer | {apply}: Appliquer l'outil",
+    ),
+    (
+        "editor.hint.tools",
+        "{tool}: Changer d'outil | {undo}: Retirer le dernier waypoint",
+    ),
+    (
+        "editor.hint.size",
+        "{width}: Largeur | {height}: Hauteur | {rename}: Renommer",
+    ),
+    (
+        "editor.hint.symbols",
+        "{symbols}: Symbole départ/arrivée | {terrain}: Symbole terrain",
+    ),
+    (
+        "editor.hint.actions",
+        "1-9: Activer un monstre | {validate}: Valider | {save}: Enregistrer",
+    ),
+    ("editor.hint.back", "{back}: Retour au menu"),
+    // Paramètres
+    ("settings.difficulty", "Difficulté"),
+    ("settings.wave_multiplier", "Progression des vagues"),
+    ("settings.starting_money", "Argent de départ"),
+    ("settings.starting_life", "Vies de départ"),
+    ("settings.tick_rate", "Rafraîchissement"),
+    ("settings.display_mode", "Affichage"),
+    ("settings.language", "Langue"),
+    ("settings.notifications", "Notifications"),
+    ("settings.back", "Retour"),
+    ("settings.enabled", "Activées"),
+    ("settings.disabled", "Désactivées"),
+    (
+        "settings.hint",
+        "←→ ou Entrée pour modifier, Esc pour revenir",
+    ),
+    (
+        "settings.next_game",
+        "Les changements s'appliquent à la prochaine partie",
+    ),
+    ("config.invalid_line", "Ligne invalide: {line}"),
+    ("config.unknown_key", "Clé inconnue: {key}"),
+    ("config.missing_key", "Clé manquante: {key}"),
+    (
+        "config.invalid_value",
+        "Valeur invalide pour {key}: {value}",
+    ),
+    (
+        "config.invalid_webhook",
+        "Seules les adresses http:// sont prises en charge: {url}",
+    ),
+    ("file.read_failed", "Lecture de {path} impossible: {error}"),
+    (
+        "file.create_dir_failed",
+        "Création de {path} impossible: {error}",
+    ),
+    (
+        "file.write_failed",
+        "Écriture de {path} impossible: {error}",
+    ),
+    (
+        "campaign_file.invalid_stars",
+        "Étoiles invalides pour {level}: {line}",
+    ),
+    ("scores_file.invalid_entry", "Score invalide: {line}"),
+    ("glyphs.missing_glyph", "Caractère manquant: {line}"),
+    ("glyphs.invalid_color", "Couleur invalide: {color}"),
+    // Aide
+    ("help.controls", "Commandes"),
+    ("help.elements", "Éléments"),
+    ("help.towers", "Tours"),
+    ("help.monsters", "Monstres"),
+    ("help.keys", "Touches"),
+    ("help.quick_build", "Construire directement la tour n°1 à 9"),
+    ("help.close", "{help} ou {cancel} pour fermer"),
+    ("help.hint", "{pages}: changer de page | {back}: retour"),
+    ("help.elements.header", "Att./Cible"),
+    (
+        "help.elements.legend",
+        "Vert : dégâts augmentés, rouge : dégâts réduits",
+    ),
+    (
+        "help.towers.stat",
+        "{stat}: {value} (niveau max {max_level})",
+    ),
+    (
+        "help.monsters.stats",
+        "PV: {hp} | Vitesse: {speed} cases/s | Armure: {armor}% | Dégâts à la base: {damage}",
+    ),
+    ("key.move_up", "Monter"),
+    ("key.move_down", "Descendre"),
+    ("key.move_left", "Aller à gauche"),
+    ("key.move_right", "Aller à droite"),
+    ("key.confirm", "Confirmer"),
+    ("key.cancel", "Annuler"),
+    ("key.pause", "Pause"),
+    ("key.quit", "Quitter"),
+    ("key.build", "Construire une tour"),
+    ("key.sell", "Vendre une tour"),
+    ("key.upgrade", "Améliorer une tour"),
+    ("key.speed", "Changer la vitesse du jeu"),
+    ("key.next_wave", "Appeler la vague suivante"),
+    ("key.targeting", "Changer le ciblage de la tour"),
+    ("key.next_tower", "Tour suivante"),
+    ("key.previous_tower", "Tour précédente"),
+    ("key.upgrade_range", "Améliorer la portée"),
+    ("key.upgrade_damage", "Améliorer les dégâts"),
+    ("key.upgrade_speed", "Améliorer la cadence"),
+    ("key.upgrade_money", "Améliorer les gains"),
+    ("key.scroll_up", "Défiler vers le haut"),
+    ("key.scroll_down", "Défiler vers le bas"),
+    ("key.scroll_left", "Défiler vers la gauche"),
+    ("key.scroll_right", "Défiler vers la droite"),
+    ("key.center_camera", "Centrer la vue sur le curseur"),
+    ("key.reroll_seed", "Nouvelle graine aléatoire"),
+    ("key.help", "Afficher l'aide"),
+    ("key_name.enter", "Entrée"),
+    ("key_name.backtab", "Maj+Tab"),
+    ("key_name.backspace", "Retour"),
+    ("key_name.space", "Espace"),
+    ("keymap.unknown_action", "Action inconnue: {action}"),
+    (
+        "keymap.unknown_preset",
+        "Préréglage de touches inconnu: {preset}",
+    ),
+    ("keymap.unknown_key", "Touche inconnue: {key}"),
+    (
+        "keymap.conflict",
+        "La touche {key} est associée à la fois à {first} et à {second}",
+    ),
+    // Notifications
+    ("notification.category.monster_detected", "Monstre détecté"),
+    ("notification.category.low_life", "Vies faibles"),
+    ("notification.category.wave_cleared", "Vague terminée"),
+    ("notification.category.boss", "Dernière vague"),
+    ("notification.category.game_over", "Fin de partie"),
+    (
+        "notification.monsters_detected.one",
+        "{count} monstre détecté",
+    ),
+    (
+        "notification.monsters_detected.other",
+        "{count} monstres détectés",
+    ),
+    ("notification.waves_cleared.one", "{count} vague terminée"),
+    (
+        "notification.waves_cleared.other",
+        "{count} vagues terminées",
+    ),
+    ("notification.low_life.one", "❤️ Plus qu'une vie !"),
+    ("notification.low_life.other", "❤️ Plus que {count} vies !"),
+    (
+        "notification.boss",
+        "👑 Vague {wave}: la dernière vague arrive !",
+    ),
+    (
+        "notification.send_failed",
+        "Notification impossible: {error}",
+    ),
+    ("dnd.unavailable", "{program} indisponible: {error}"),
+    ("dnd.failed", "{program} a échoué"),
+    (
+        "dnd.unexpected",
+        "Réponse inattendue de {program}: {output}",
+    ),
+    ("dnd.no_probe", "Aucune sonde « ne pas déranger »"),
+    (
+        "webhook.unsupported_scheme",
+        "Seules les adresses http:// sont prises en charge: {url}",
+    ),
+    (
+        "webhook.invalid_port",
+        "Port invalide dans l'adresse: {url}",
+    ),
+    (
+        "webhook.missing_host",
+        "Hôte manquant dans l'adresse: {url}",
+    ),
+    (
+        "webhook.worker_stopped",
+        "Le thread d'envoi du webhook s'est arrêté",
+    ),
+    (
+        "webhook.address_not_found",
+        "Adresse du webhook introuvable: {error}",
+    ),
+    (
+        "webhook.connect_failed",
+        "Connexion au webhook impossible: {error}",
+    ),
+    (
+        "webhook.send_failed",
+        "Envoi au webhook impossible: {error}",
+    ),
+    (
+        "webhook.unreadable_response",
+        "Réponse du webhook illisible: {error}",
+    ),
+    ("webhook.invalid_response", "Réponse du webhook invalide"),
+    ("webhook.status", "Le webhook a répondu {status}"),
+];
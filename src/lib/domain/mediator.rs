@@ -1,13 +1,96 @@
-use super::{ports::notifier::Notifier, services::notifications::NotifierAdapter};
+use std::{sync::Mutex, time::Instant};
 
-pub type MediatorService = Mediator<NotifierAdapter>;
+use super::{
+    ports::notifier::Notifier,
+    services::notifications::{
+        policy::{Notification, NotificationPolicy},
+        throttle::{Delivery, NotificationThrottle},
+    },
+};
+
+/// Médiateur partagé par la partie, quel que soit le moyen de notification choisi
+pub type MediatorService = Mediator<Box<dyn Notifier>>;
 
 pub struct Mediator<T: Notifier> {
     pub notifier: T,
+    /// Filtres et espacement appliqués aux notifications de la partie
+    throttle: Mutex<NotificationThrottle>,
 }
 
 impl<T: Notifier> Mediator<T> {
     pub fn new(notifier: T) -> Self {
-        Self { notifier }
+        Self {
+            notifier,
+            throttle: Mutex::new(NotificationThrottle::default()),
+        }
+    }
+
+    pub fn with_policy(self, policy: NotificationPolicy) -> Self {
+        self.set_policy(policy);
+        self
+    }
+
+    pub fn set_policy(&self, policy: NotificationPolicy) {
+        if let Ok(mut throttle) = self.throttle.lock() {
+            throttle.policy = policy;
+        }
+    }
+
+    /// Le terminal a gagné ou perdu le focus
+    pub fn set_focused(&self, focused: bool) {
+        if let Ok(mut throttle) = self.throttle.lock() {
+            throttle.focused = focused;
+        }
+    }
+
+    /// Envoie une notification selon les préférences du joueur
+    ///
+    /// Une notification trop rapprochée de la précédente de sa catégorie est retenue,
+    /// puis regroupée avec les suivantes.
+    pub fn notify(&self, notification: Notification) -> Result<(), String> {
+        let admitted = self
+            .throttle
+            .lock()
+            .map_err(|e| e.to_string())?
+            .admit(notification, Instant::now());
+
+        match admitted {
+            Some((notification, delivery)) => self.deliver(&notification, delivery),
+            None => Ok(()),
+        }
+    }
+
+    /// Envoie les notifications retenues dont le délai est écoulé
+    pub fn flush(&self) -> Result<(), String> {
+        let ready = self
+            .throttle
+            .lock()
+            .map_err(|e| e.to_string())?
+            .flush(Instant::now());
+
+        let mut result = Ok(());
+        for (notification, delivery) in &ready {
+            result = result.and(self.deliver(notification, *delivery));
+        }
+        result
+    }
+
+    fn deliver(&self, notification: &Notification, delivery: Delivery) -> Result<(), String> {
+        let (title, message) = (&notification.title, &notification.message);
+
+        match (delivery, &notification.context) {
+            (Delivery::Everywhere, Some(context)) => {
+                self.notifier.send_with_context(title, message, context)
+            }
+            (Delivery::Everywhere, None) => self.notifier.send_notification(title, message),
+            (Delivery::InTerminal, _) => self.notifier.send_in_terminal(title, message),
+        }
+    }
+}
+
+impl MediatorService {
+    /// Crée un médiateur à partir de n'importe quel moyen de notification
+    pub fn boxed(notifier: impl Notifier + 'static) -> Self {
+        Self::new(Box::new(notifier))
     }
 }
@@ -1,6 +1,109 @@
-pub trait Notifier {
-    fn can_send_message(&self) -> bool;
-    fn is_dnd_enabled(&self) -> Result<bool, String>;
-    fn request_permission(&self) -> bool;
-    fn send_notification(&self, title: &str, message: &str);
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+/// État de la partie au moment d'une notification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotificationContext {
+    pub game_id: Uuid,
+    pub wave: u32,
+    pub life: i32,
+}
+
+/// Moyen de notifier le joueur, partagé par la partie entre plusieurs threads
+pub trait Notifier: Send + Sync {
+    /// Indique si une notification peut être envoyée maintenant
+    fn can_send_message(&self) -> bool {
+        !self.is_dnd_enabled().unwrap_or(false) && self.request_permission()
+    }
+
+    /// Mode « ne pas déranger » du système, désactivé par défaut
+    fn is_dnd_enabled(&self) -> Result<bool, String> {
+        Ok(false)
+    }
+
+    fn request_permission(&self) -> bool {
+        true
+    }
+
+    fn send_notification(&self, title: &str, message: &str) -> Result<(), String>;
+
+    /// Notification destinée au joueur qui regarde déjà le jeu
+    ///
+    /// Seuls les moyens affichés dans le terminal la délivrent, les autres l'ignorent.
+    fn send_in_terminal(&self, _title: &str, _message: &str) -> Result<(), String> {
+        Ok(())
+    }
+
+    /// Notification accompagnée de l'état de la partie, ignoré par défaut
+    fn send_with_context(
+        &self,
+        title: &str,
+        message: &str,
+        _context: &NotificationContext,
+    ) -> Result<(), String> {
+        self.send_notification(title, message)
+    }
+}
+
+impl<T: Notifier + ?Sized> Notifier for Box<T> {
+    fn can_send_message(&self) -> bool {
+        (**self).can_send_message()
+    }
+
+    fn is_dnd_enabled(&self) -> Result<bool, String> {
+        (**self).is_dnd_enabled()
+    }
+
+    fn request_permission(&self) -> bool {
+        (**self).request_permission()
+    }
+
+    fn send_notification(&self, title: &str, message: &str) -> Result<(), String> {
+        (**self).send_notification(title, message)
+    }
+
+    fn send_in_terminal(&self, title: &str, message: &str) -> Result<(), String> {
+        (**self).send_in_terminal(title, message)
+    }
+
+    fn send_with_context(
+        &self,
+        title: &str,
+        message: &str,
+        context: &NotificationContext,
+    ) -> Result<(), String> {
+        (**self).send_with_context(title, message, context)
+    }
+}
+
+impl<T: Notifier + ?Sized> Notifier for Arc<T> {
+    fn can_send_message(&self) -> bool {
+        (**self).can_send_message()
+    }
+
+    fn is_dnd_enabled(&self) -> Result<bool, String> {
+        (**self).is_dnd_enabled()
+    }
+
+    fn request_permission(&self) -> bool {
+        (**self).request_permission()
+    }
+
+    fn send_notification(&self, title: &str, message: &str) -> Result<(), String> {
+        (**self).send_notification(title, message)
+    }
+
+    fn send_in_terminal(&self, title: &str, message: &str) -> Result<(), String> {
+        (**self).send_in_terminal(title, message)
+    }
+
+    fn send_with_context(
+        &self,
+        title: &str,
+        message: &str,
+        context: &NotificationContext,
+    ) -> Result<(), String> {
+        (**self).send_with_context(title, message, context)
+    }
 }
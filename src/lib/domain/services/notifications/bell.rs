@@ -0,0 +1,22 @@
+use std::io::{Write, stdout};
+
+use crate::domain::ports::notifier::Notifier;
+
+/// Fait sonner la cloche du terminal, sans afficher le message
+#[derive(Debug, Default)]
+pub struct BellNotifier;
+
+impl BellNotifier {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl Notifier for BellNotifier {
+    fn send_notification(&self, _title: &str, _message: &str) -> Result<(), String> {
+        let mut out = stdout();
+        out.write_all(b"\x07")
+            .and_then(|_| out.flush())
+            .map_err(|e| e.to_string())
+    }
+}
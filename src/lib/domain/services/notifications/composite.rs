@@ -0,0 +1,81 @@
+use crate::domain::ports::notifier::{NotificationContext, Notifier};
+
+/// Transmet chaque notification à plusieurs moyens de notification
+#[derive(Default)]
+pub struct CompositeNotifier {
+    notifiers: Vec<Box<dyn Notifier>>,
+}
+
+impl CompositeNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_notifier(mut self, notifier: impl Notifier + 'static) -> Self {
+        self.notifiers.push(Box::new(notifier));
+        self
+    }
+
+    pub fn len(&self) -> usize {
+        self.notifiers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notifiers.is_empty()
+    }
+
+    fn fan_out(&self, send: impl Fn(&dyn Notifier) -> Result<(), String>) -> Result<(), String> {
+        let mut result = Ok(());
+        for notifier in &self.notifiers {
+            if notifier.can_send_message() {
+                result = result.and(send(notifier.as_ref()));
+            }
+        }
+        result
+    }
+}
+
+impl Notifier for CompositeNotifier {
+    /// Au moins un des moyens de notification est disponible
+    fn can_send_message(&self) -> bool {
+        self.notifiers
+            .iter()
+            .any(|notifier| notifier.can_send_message())
+    }
+
+    fn is_dnd_enabled(&self) -> Result<bool, String> {
+        for notifier in &self.notifiers {
+            if !notifier.is_dnd_enabled()? {
+                return Ok(false);
+            }
+        }
+
+        Ok(!self.notifiers.is_empty())
+    }
+
+    fn request_permission(&self) -> bool {
+        self.notifiers
+            .iter()
+            .any(|notifier| notifier.request_permission())
+    }
+
+    /// Chaque moyen de notification décide seul s'il peut envoyer le message
+    ///
+    /// Un échec n'empêche pas les suivants de recevoir la notification, seul le premier est renvoyé.
+    fn send_notification(&self, title: &str, message: &str) -> Result<(), String> {
+        self.fan_out(|notifier| notifier.send_notification(title, message))
+    }
+
+    fn send_in_terminal(&self, title: &str, message: &str) -> Result<(), String> {
+        self.fan_out(|notifier| notifier.send_in_terminal(title, message))
+    }
+
+    fn send_with_context(
+        &self,
+        title: &str,
+        message: &str,
+        context: &NotificationContext,
+    ) -> Result<(), String> {
+        self.fan_out(|notifier| notifier.send_with_context(title, message, context))
+    }
+}
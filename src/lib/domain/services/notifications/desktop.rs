@@ -0,0 +1,97 @@
+use notify_rust::Notification;
+use std::process::Command;
+
+use crate::domain::{i18n::Locale, ports::notifier::Notifier};
+
+use super::dnd::{DndProbe, system_probe};
+
+/// Notifications du bureau, muettes lorsque le système est en mode « ne pas déranger »
+pub struct DesktopNotifier {
+    dnd_probe: Box<dyn DndProbe>,
+    locale: Locale,
+}
+
+impl Default for DesktopNotifier {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DesktopNotifier {
+    pub fn new() -> Self {
+        Self {
+            dnd_probe: system_probe(),
+            locale: Locale::default(),
+        }
+    }
+
+    /// Langue des erreurs remontées par le notifieur
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    /// Remplace la détection du mode « ne pas déranger » du système
+    pub fn with_dnd_probe(mut self, probe: impl DndProbe + 'static) -> Self {
+        self.dnd_probe = Box::new(probe);
+        self
+    }
+}
+
+impl Notifier for DesktopNotifier {
+    fn can_send_message(&self) -> bool {
+        // First check if DND is enabled
+        if self.is_dnd_enabled().unwrap_or(false) {
+            return false;
+        }
+
+        // Then check if notifications are enabled
+        self.request_permission()
+    }
+
+    fn is_dnd_enabled(&self) -> Result<bool, String> {
+        self.dnd_probe.is_dnd_enabled(self.locale)
+    }
+
+    fn request_permission(&self) -> bool {
+        let nc_output = if cfg!(target_os = "macos") {
+            Command::new("defaults")
+                .arg("read")
+                .arg("com.apple.notificationcenterui")
+                .arg("enabled")
+                .output()
+        } else if cfg!(target_os = "windows") {
+            Command::new("powershell")
+                .arg("Get-ItemProperty -Path HKCU:\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Notifications\\Settings\\")
+                .arg("enabled")
+                .output()
+        } else {
+            return true; // On Linux, assume notifications are enabled by default
+        };
+
+        if let Ok(output) = nc_output {
+            if output.stdout.starts_with(b"0") {
+                return false;
+            }
+            return true;
+        }
+
+        true // If we can't check, assume notifications are enabled
+    }
+
+    fn send_notification(&self, title: &str, message: &str) -> Result<(), String> {
+        if !self.can_send_message() {
+            return Ok(());
+        }
+
+        Notification::new()
+            .summary(title)
+            .body(message)
+            .show()
+            .map(|_| ())
+            .map_err(|e| {
+                self.locale
+                    .format("notification.send_failed", &[("error", &e)])
+            })
+    }
+}
@@ -0,0 +1,192 @@
+use std::process::Command;
+
+use crate::domain::i18n::Locale;
+
+/// Sonde indiquant si le système est en mode « ne pas déranger »
+///
+/// Le notifieur du bureau s'en remet à elle, ce qui permet de la remplacer dans les tests.
+/// Les erreurs sont rédigées dans la langue donnée.
+pub trait DndProbe: Send + Sync {
+    fn is_dnd_enabled(&self, locale: Locale) -> Result<bool, String>;
+}
+
+/// Réponse fixe, pour les tests ou pour forcer un comportement
+#[derive(Debug, Clone, Copy)]
+pub struct FixedDndProbe(pub bool);
+
+impl DndProbe for FixedDndProbe {
+    fn is_dnd_enabled(&self, _locale: Locale) -> Result<bool, String> {
+        Ok(self.0)
+    }
+}
+
+/// Lit l'état du mode « ne pas déranger » dans la sortie d'une commande
+pub struct CommandDndProbe {
+    program: &'static str,
+    args: &'static [&'static str],
+    /// Interprète la sortie de la commande, `None` si elle est inattendue
+    parse: fn(&str) -> Option<bool>,
+}
+
+impl CommandDndProbe {
+    pub const fn new(
+        program: &'static str,
+        args: &'static [&'static str],
+        parse: fn(&str) -> Option<bool>,
+    ) -> Self {
+        Self {
+            program,
+            args,
+            parse,
+        }
+    }
+
+    /// GNOME masque les bannières lorsque « Ne pas déranger » est actif
+    pub const fn gnome() -> Self {
+        Self::new(
+            "gsettings",
+            &["get", "org.gnome.desktop.notifications", "show-banners"],
+            parse_gnome_show_banners,
+        )
+    }
+
+    /// KDE Plasma expose le mode « Ne pas déranger » via la propriété `Inhibited` du service de notifications
+    pub const fn kde() -> Self {
+        Self::new(
+            "busctl",
+            &[
+                "--user",
+                "get-property",
+                "org.freedesktop.Notifications",
+                "/org/freedesktop/Notifications",
+                "org.freedesktop.Notifications",
+                "Inhibited",
+            ],
+            parse_dbus_boolean,
+        )
+    }
+
+    /// Dunst, courant sur les gestionnaires de fenêtres légers, met les notifications en pause
+    pub const fn dunst() -> Self {
+        Self::new("dunstctl", &["is-paused"], parse_boolean)
+    }
+
+    pub const fn macos() -> Self {
+        Self::new(
+            "defaults",
+            &["read", "com.apple.notificationcenterui", "doNotDisturb"],
+            parse_flag,
+        )
+    }
+
+    pub const fn windows() -> Self {
+        Self::new(
+            "powershell",
+            &[
+                "Get-ItemProperty -Path HKCU:\\SOFTWARE\\Microsoft\\Windows\\CurrentVersion\\Notifications\\Settings\\",
+                "doNotDisturb",
+            ],
+            parse_flag,
+        )
+    }
+}
+
+impl DndProbe for CommandDndProbe {
+    fn is_dnd_enabled(&self, locale: Locale) -> Result<bool, String> {
+        let output = Command::new(self.program)
+            .args(self.args)
+            .output()
+            .map_err(|e| {
+                locale.format(
+                    "dnd.unavailable",
+                    &[("program", &self.program), ("error", &e)],
+                )
+            })?;
+
+        if !output.status.success() {
+            return Err(locale.format("dnd.failed", &[("program", &self.program)]));
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        (self.parse)(&stdout).ok_or_else(|| {
+            locale.format(
+                "dnd.unexpected",
+                &[("program", &self.program), ("output", &stdout.trim())],
+            )
+        })
+    }
+}
+
+/// Le mode est actif dès qu'une des sondes le signale, en erreur si aucune ne répond
+///
+/// Plusieurs bureaux peuvent cohabiter : les réglages GNOME existent souvent sous KDE
+/// sans être utilisés, d'où l'interrogation de toutes les sondes.
+pub struct AnyDndProbe {
+    probes: Vec<Box<dyn DndProbe>>,
+}
+
+impl AnyDndProbe {
+    pub fn new(probes: Vec<Box<dyn DndProbe>>) -> Self {
+        Self { probes }
+    }
+
+    /// Bureaux Linux pris en charge : GNOME, KDE Plasma et Dunst
+    pub fn linux() -> Self {
+        Self::new(vec![
+            Box::new(CommandDndProbe::gnome()),
+            Box::new(CommandDndProbe::kde()),
+            Box::new(CommandDndProbe::dunst()),
+        ])
+    }
+}
+
+impl DndProbe for AnyDndProbe {
+    fn is_dnd_enabled(&self, locale: Locale) -> Result<bool, String> {
+        let mut last_error = Err(locale.text("dnd.no_probe").to_string());
+
+        for probe in &self.probes {
+            match probe.is_dnd_enabled(locale) {
+                Ok(true) => return Ok(true),
+                Ok(false) => last_error = Ok(false),
+                Err(error) if last_error.is_err() => last_error = Err(error),
+                Err(_) => {}
+            }
+        }
+
+        last_error
+    }
+}
+
+/// Sonde adaptée au système courant
+pub fn system_probe() -> Box<dyn DndProbe> {
+    if cfg!(target_os = "macos") {
+        Box::new(CommandDndProbe::macos())
+    } else if cfg!(target_os = "windows") {
+        Box::new(CommandDndProbe::windows())
+    } else {
+        Box::new(AnyDndProbe::linux())
+    }
+}
+
+/// `gsettings` répond `true` ou `false`
+fn parse_gnome_show_banners(output: &str) -> Option<bool> {
+    parse_boolean(output).map(|show_banners| !show_banners)
+}
+
+/// `busctl` répond par exemple `b true`
+fn parse_dbus_boolean(output: &str) -> Option<bool> {
+    parse_boolean(output.trim().strip_prefix("b ")?)
+}
+
+fn parse_boolean(output: &str) -> Option<bool> {
+    match output.trim() {
+        "true" => Some(true),
+        "false" => Some(false),
+        _ => None,
+    }
+}
+
+/// `defaults` et le registre répondent `1` ou `0`
+fn parse_flag(output: &str) -> Option<bool> {
+    Some(output.trim_start().starts_with('1'))
+}
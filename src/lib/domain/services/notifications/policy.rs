@@ -0,0 +1,166 @@
+use std::time::Duration;
+
+use crate::domain::{i18n::Locale, ports::notifier::NotificationContext};
+
+/// Délai minimal par défaut entre deux notifications d'une même catégorie
+pub const DEFAULT_NOTIFICATION_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Nature d'une notification, que le joueur peut activer ou désactiver
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotificationCategory {
+    /// Une sentinelle a repéré un monstre
+    MonsterDetected,
+    /// Il ne reste presque plus de vies
+    LowLife,
+    WaveCleared,
+    /// Dernière vague de la partie
+    Boss,
+    /// Défaite ou victoire
+    GameOver,
+}
+
+/// Importance d'une notification, qui détermine à quel point elle peut être retenue
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum NotificationPriority {
+    /// Espacée deux fois plus que les autres
+    Low,
+    Normal,
+    /// Jamais retardée ni rendue silencieuse
+    High,
+}
+
+impl NotificationCategory {
+    pub const ALL: [NotificationCategory; 5] = [
+        NotificationCategory::MonsterDetected,
+        NotificationCategory::LowLife,
+        NotificationCategory::WaveCleared,
+        NotificationCategory::Boss,
+        NotificationCategory::GameOver,
+    ];
+
+    /// Nom utilisé dans le fichier de paramètres
+    pub fn name(&self) -> &'static str {
+        match self {
+            NotificationCategory::MonsterDetected => "monster_detected",
+            NotificationCategory::LowLife => "low_life",
+            NotificationCategory::WaveCleared => "wave_cleared",
+            NotificationCategory::Boss => "boss",
+            NotificationCategory::GameOver => "game_over",
+        }
+    }
+
+    pub fn label(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            NotificationCategory::MonsterDetected => "notification.category.monster_detected",
+            NotificationCategory::LowLife => "notification.category.low_life",
+            NotificationCategory::WaveCleared => "notification.category.wave_cleared",
+            NotificationCategory::Boss => "notification.category.boss",
+            NotificationCategory::GameOver => "notification.category.game_over",
+        })
+    }
+
+    pub fn priority(&self) -> NotificationPriority {
+        match self {
+            NotificationCategory::MonsterDetected => NotificationPriority::Low,
+            NotificationCategory::LowLife
+            | NotificationCategory::WaveCleared
+            | NotificationCategory::Boss => NotificationPriority::Normal,
+            NotificationCategory::GameOver => NotificationPriority::High,
+        }
+    }
+
+    /// Message regroupant `count` notifications retenues, la dernière étant `message`
+    pub fn coalesced(&self, locale: Locale, count: u32, message: &str) -> String {
+        match self {
+            NotificationCategory::MonsterDetected => {
+                locale.plural("notification.monsters_detected", count, &[])
+            }
+            NotificationCategory::WaveCleared => {
+                locale.plural("notification.waves_cleared", count, &[])
+            }
+            // Seule la plus récente compte, par exemple le nombre de vies restantes
+            _ => message.to_string(),
+        }
+    }
+}
+
+impl NotificationCategory {
+    /// Catégorie correspondant à un nom du fichier de paramètres
+    pub fn from_name(name: &str) -> Option<Self> {
+        NotificationCategory::ALL
+            .into_iter()
+            .find(|category| category.name() == name)
+    }
+}
+
+/// Notification à envoyer au joueur
+#[derive(Debug, Clone)]
+pub struct Notification {
+    pub category: NotificationCategory,
+    pub title: String,
+    pub message: String,
+    /// Langue des messages regroupés
+    pub locale: Locale,
+    /// Partie à l'origine de la notification
+    pub context: Option<NotificationContext>,
+}
+
+impl Notification {
+    pub fn new(
+        category: NotificationCategory,
+        title: impl Into<String>,
+        message: impl Into<String>,
+        locale: Locale,
+    ) -> Self {
+        Self {
+            category,
+            title: title.into(),
+            message: message.into(),
+            locale,
+            context: None,
+        }
+    }
+
+    pub fn with_context(mut self, context: NotificationContext) -> Self {
+        self.context = Some(context);
+        self
+    }
+}
+
+/// Préférences du joueur sur les notifications
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationPolicy {
+    /// Interrupteur général
+    pub enabled: bool,
+    pub disabled_categories: Vec<NotificationCategory>,
+    /// Délai minimal entre deux notifications d'une même catégorie
+    pub min_interval: Duration,
+    /// Ne notifie que dans le jeu tant que le terminal a le focus
+    pub quiet_when_focused: bool,
+}
+
+impl Default for NotificationPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            disabled_categories: Vec::new(),
+            min_interval: DEFAULT_NOTIFICATION_INTERVAL,
+            quiet_when_focused: true,
+        }
+    }
+}
+
+impl NotificationPolicy {
+    pub fn allows(&self, category: NotificationCategory) -> bool {
+        self.enabled && !self.disabled_categories.contains(&category)
+    }
+
+    /// Délai minimal entre deux notifications de la catégorie
+    pub fn interval(&self, category: NotificationCategory) -> Duration {
+        match category.priority() {
+            NotificationPriority::Low => self.min_interval * 2,
+            NotificationPriority::Normal => self.min_interval,
+            NotificationPriority::High => Duration::ZERO,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::{Arc, Mutex};
+
+use crate::domain::ports::notifier::Notifier;
+
+/// Notification reçue par un `RecordingNotifier`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SentNotification {
+    pub title: String,
+    pub message: String,
+}
+
+/// Conserve les notifications au lieu de les afficher, pour les tests
+///
+/// Les clones partagent le même enregistrement : on peut en confier un au jeu
+/// et consulter l'autre ensuite.
+#[derive(Debug, Clone, Default)]
+pub struct RecordingNotifier {
+    sent: Arc<Mutex<Vec<SentNotification>>>,
+}
+
+impl RecordingNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Notifications reçues, dans l'ordre
+    pub fn sent(&self) -> Vec<SentNotification> {
+        self.sent
+            .lock()
+            .map(|sent| sent.clone())
+            .unwrap_or_default()
+    }
+
+    pub fn clear(&self) {
+        if let Ok(mut sent) = self.sent.lock() {
+            sent.clear();
+        }
+    }
+}
+
+impl Notifier for RecordingNotifier {
+    fn send_notification(&self, title: &str, message: &str) -> Result<(), String> {
+        let mut sent = self.sent.lock().map_err(|e| e.to_string())?;
+        sent.push(SentNotification {
+            title: title.to_string(),
+            message: message.to_string(),
+        });
+        Ok(())
+    }
+}
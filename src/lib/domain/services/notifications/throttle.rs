@@ -0,0 +1,125 @@
+use std::{collections::HashMap, time::Instant};
+
+use super::policy::{Notification, NotificationCategory, NotificationPolicy, NotificationPriority};
+
+/// Notifications retenues d'une catégorie, en attente d'être regroupées
+#[derive(Debug, Clone)]
+struct Pending {
+    count: u32,
+    last: Notification,
+}
+
+/// Moyen par lequel une notification autorisée doit être délivrée
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delivery {
+    /// Tous les moyens de notification
+    Everywhere,
+    /// Uniquement dans le terminal, le joueur regarde déjà le jeu
+    InTerminal,
+}
+
+/// Applique les préférences du joueur : filtres, espacement et regroupement des notifications
+#[derive(Debug, Clone, Default)]
+pub struct NotificationThrottle {
+    pub policy: NotificationPolicy,
+    /// Le terminal a le focus, d'après les événements du terminal
+    pub focused: bool,
+    last_sent: HashMap<NotificationCategory, Instant>,
+    pending: HashMap<NotificationCategory, Pending>,
+}
+
+impl NotificationThrottle {
+    pub fn new(policy: NotificationPolicy) -> Self {
+        Self {
+            policy,
+            ..Self::default()
+        }
+    }
+
+    fn delivery(&self, category: NotificationCategory) -> Delivery {
+        let quiet = self.policy.quiet_when_focused && self.focused;
+        match category.priority() {
+            NotificationPriority::High => Delivery::Everywhere,
+            _ if quiet => Delivery::InTerminal,
+            _ => Delivery::Everywhere,
+        }
+    }
+
+    fn is_ready(&self, category: NotificationCategory, now: Instant) -> bool {
+        let interval = self.policy.interval(category);
+        self.last_sent
+            .get(&category)
+            .is_none_or(|sent| now.duration_since(*sent) >= interval)
+    }
+
+    /// Notification à délivrer maintenant, `None` si elle est filtrée ou retenue
+    ///
+    /// Une notification retenue est regroupée avec les suivantes de la même catégorie.
+    pub fn admit(
+        &mut self,
+        notification: Notification,
+        now: Instant,
+    ) -> Option<(Notification, Delivery)> {
+        let category = notification.category;
+        if !self.policy.allows(category) {
+            return None;
+        }
+
+        let count = self
+            .pending
+            .remove(&category)
+            .map_or(0, |pending| pending.count)
+            + 1;
+
+        if !self.is_ready(category, now) {
+            self.pending.insert(
+                category,
+                Pending {
+                    count,
+                    last: notification,
+                },
+            );
+            return None;
+        }
+
+        self.last_sent.insert(category, now);
+        let delivery = self.delivery(category);
+        Some((coalesce(notification, count), delivery))
+    }
+
+    /// Notifications retenues dont le délai est écoulé, regroupées par catégorie
+    pub fn flush(&mut self, now: Instant) -> Vec<(Notification, Delivery)> {
+        let ready: Vec<NotificationCategory> = self
+            .pending
+            .keys()
+            .copied()
+            .filter(|category| self.is_ready(*category, now))
+            .collect();
+
+        ready
+            .into_iter()
+            .filter_map(|category| {
+                let pending = self.pending.remove(&category)?;
+                if !self.policy.allows(category) {
+                    return None;
+                }
+
+                self.last_sent.insert(category, now);
+                Some((
+                    coalesce(pending.last, pending.count),
+                    self.delivery(category),
+                ))
+            })
+            .collect()
+    }
+}
+
+fn coalesce(mut notification: Notification, count: u32) -> Notification {
+    if count > 1 {
+        notification.message =
+            notification
+                .category
+                .coalesced(notification.locale, count, &notification.message);
+    }
+    notification
+}
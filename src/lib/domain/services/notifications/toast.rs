@@ -0,0 +1,67 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use crate::domain::ports::notifier::Notifier;
+
+/// Durée d'affichage d'un toast
+pub const TOAST_DURATION: Duration = Duration::from_secs(4);
+/// Nombre maximal de toasts affichés en même temps
+pub const MAX_TOASTS: usize = 3;
+
+/// Message affiché brièvement par-dessus l'interface
+#[derive(Debug, Clone)]
+pub struct Toast {
+    pub title: String,
+    pub message: String,
+    pub created_at: Instant,
+}
+
+/// Affiche les notifications dans le jeu, sous forme de toasts
+///
+/// Les clones partagent la même file : l'interface garde un clone pour
+/// afficher ce que reçoit celui confié au médiateur.
+#[derive(Debug, Clone, Default)]
+pub struct ToastNotifier {
+    toasts: Arc<Mutex<VecDeque<Toast>>>,
+}
+
+impl ToastNotifier {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Toasts encore visibles, du plus ancien au plus récent
+    pub fn active(&self) -> Vec<Toast> {
+        let Ok(mut toasts) = self.toasts.lock() else {
+            return Vec::new();
+        };
+
+        toasts.retain(|toast| toast.created_at.elapsed() < TOAST_DURATION);
+        toasts.iter().cloned().collect()
+    }
+}
+
+impl Notifier for ToastNotifier {
+    fn send_notification(&self, title: &str, message: &str) -> Result<(), String> {
+        let mut toasts = self.toasts.lock().map_err(|e| e.to_string())?;
+
+        toasts.push_back(Toast {
+            title: title.to_string(),
+            message: message.to_string(),
+            created_at: Instant::now(),
+        });
+
+        while toasts.len() > MAX_TOASTS {
+            toasts.pop_front();
+        }
+
+        Ok(())
+    }
+
+    fn send_in_terminal(&self, title: &str, message: &str) -> Result<(), String> {
+        self.send_notification(title, message)
+    }
+}
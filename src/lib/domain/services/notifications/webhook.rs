@@ -0,0 +1,240 @@
+use std::{
+    io::{Read, Write},
+    net::{TcpStream, ToSocketAddrs},
+    sync::{
+        Arc, Mutex, OnceLock,
+        mpsc::{self, Sender},
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::domain::{
+    i18n::Locale,
+    ports::notifier::{NotificationContext, Notifier},
+};
+
+/// Délai par défaut pour se connecter, envoyer et recevoir la réponse
+pub const DEFAULT_WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+/// Nouvelles tentatives par défaut après un échec
+pub const DEFAULT_WEBHOOK_RETRIES: u32 = 2;
+
+/// Adresse d'un webhook `http://hôte[:port]/chemin`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WebhookUrl {
+    pub host: String,
+    pub port: u16,
+    pub path: String,
+}
+
+impl WebhookUrl {
+    /// Seul HTTP est pris en charge : un relais local peut faire suivre vers un service HTTPS
+    pub fn parse(url: &str, locale: Locale) -> Result<Self, String> {
+        let rest = url
+            .strip_prefix("http://")
+            .ok_or_else(|| locale.format("webhook.unsupported_scheme", &[("url", &url)]))?;
+
+        let (authority, path) = match rest.find('/') {
+            Some(index) => rest.split_at(index),
+            None => (rest, "/"),
+        };
+
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (
+                host,
+                port.parse()
+                    .map_err(|_| locale.format("webhook.invalid_port", &[("url", &url)]))?,
+            ),
+            None => (authority, 80),
+        };
+
+        if host.is_empty() {
+            return Err(locale.format("webhook.missing_host", &[("url", &url)]));
+        }
+
+        Ok(Self {
+            host: host.to_string(),
+            port,
+            path: path.to_string(),
+        })
+    }
+}
+
+/// Envoie les notifications en JSON à une adresse HTTP, par exemple pour prévenir une équipe
+///
+/// Les requêtes partent d'un thread dédié : la partie n'attend jamais le réseau.
+/// Les échecs sont retentés puis conservés dans `last_error`.
+pub struct WebhookNotifier {
+    url: WebhookUrl,
+    timeout: Duration,
+    retries: u32,
+    locale: Locale,
+    /// File des corps JSON à envoyer, créée au premier envoi
+    sender: OnceLock<Mutex<Sender<String>>>,
+    last_error: Arc<Mutex<Option<String>>>,
+}
+
+impl WebhookNotifier {
+    /// Les erreurs, y compris celles des envois, sont rédigées dans la langue donnée
+    pub fn new(url: &str, locale: Locale) -> Result<Self, String> {
+        Ok(Self {
+            url: WebhookUrl::parse(url, locale)?,
+            timeout: DEFAULT_WEBHOOK_TIMEOUT,
+            retries: DEFAULT_WEBHOOK_RETRIES,
+            locale,
+            sender: OnceLock::new(),
+            last_error: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn with_retries(mut self, retries: u32) -> Self {
+        self.retries = retries;
+        self
+    }
+
+    /// Dernière erreur d'envoi, effacée au prochain envoi réussi
+    pub fn last_error(&self) -> Option<String> {
+        self.last_error.lock().ok()?.clone()
+    }
+
+    /// Démarre le thread d'envoi, qui s'arrête avec le notifieur
+    fn spawn_worker(&self) -> Mutex<Sender<String>> {
+        let (sender, receiver) = mpsc::channel::<String>();
+        let url = self.url.clone();
+        let timeout = self.timeout;
+        let retries = self.retries;
+        let locale = self.locale;
+        let last_error = self.last_error.clone();
+
+        thread::spawn(move || {
+            for body in receiver {
+                let mut result = post(&url, &body, timeout, locale);
+                for attempt in 1..=retries {
+                    if result.is_ok() {
+                        break;
+                    }
+                    thread::sleep(Duration::from_millis(500 * attempt as u64));
+                    result = post(&url, &body, timeout, locale);
+                }
+
+                if let Ok(mut last_error) = last_error.lock() {
+                    *last_error = result.err();
+                }
+            }
+        });
+
+        Mutex::new(sender)
+    }
+
+    fn enqueue(&self, body: String) -> Result<(), String> {
+        self.sender
+            .get_or_init(|| self.spawn_worker())
+            .lock()
+            .map_err(|e| e.to_string())?
+            .send(body)
+            .map_err(|_| self.locale.text("webhook.worker_stopped").to_string())
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn send_notification(&self, title: &str, message: &str) -> Result<(), String> {
+        self.enqueue(payload(title, message, None))
+    }
+
+    fn send_with_context(
+        &self,
+        title: &str,
+        message: &str,
+        context: &NotificationContext,
+    ) -> Result<(), String> {
+        self.enqueue(payload(title, message, Some(context)))
+    }
+}
+
+/// Corps JSON : titre, message et, si connus, identifiant de partie, vague et vies
+pub fn payload(title: &str, message: &str, context: Option<&NotificationContext>) -> String {
+    let context = match context {
+        Some(context) => format!(
+            "\"game_id\":\"{}\",\"wave\":{},\"life\":{}",
+            context.game_id, context.wave, context.life
+        ),
+        None => "\"game_id\":null,\"wave\":null,\"life\":null".to_string(),
+    };
+
+    format!(
+        "{{\"title\":{},\"message\":{},{}}}",
+        json_string(title),
+        json_string(message),
+        context
+    )
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if c.is_control() => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+/// Envoie une requête `POST` et vérifie que le serveur répond par un succès
+fn post(url: &WebhookUrl, body: &str, timeout: Duration, locale: Locale) -> Result<(), String> {
+    let address = (url.host.as_str(), url.port)
+        .to_socket_addrs()
+        .map_err(|e| locale.format("webhook.address_not_found", &[("error", &e)]))?
+        .next()
+        .ok_or_else(|| locale.format("webhook.address_not_found", &[("error", &url.host)]))?;
+
+    let mut stream = TcpStream::connect_timeout(&address, timeout)
+        .map_err(|e| locale.format("webhook.connect_failed", &[("error", &e)]))?;
+    stream
+        .set_read_timeout(Some(timeout))
+        .and_then(|_| stream.set_write_timeout(Some(timeout)))
+        .map_err(|e| e.to_string())?;
+
+    let host = match url.port {
+        80 => url.host.clone(),
+        port => format!("{}:{}", url.host, port),
+    };
+    let request = format!(
+        "POST {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: tower-defense\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        url.path,
+        host,
+        body.len(),
+        body
+    );
+    stream
+        .write_all(request.as_bytes())
+        .map_err(|e| locale.format("webhook.send_failed", &[("error", &e)]))?;
+
+    // Seule la ligne de statut nous intéresse
+    let mut response = [0; 64];
+    let read = stream
+        .read(&mut response)
+        .map_err(|e| locale.format("webhook.unreadable_response", &[("error", &e)]))?;
+    let status_line = String::from_utf8_lossy(&response[..read]);
+    let status = status_line
+        .split_whitespace()
+        .nth(1)
+        .ok_or_else(|| locale.text("webhook.invalid_response").to_string())?;
+
+    match status.starts_with('2') {
+        true => Ok(()),
+        false => Err(locale.format("webhook.status", &[("status", &status)])),
+    }
+}
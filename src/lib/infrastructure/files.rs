@@ -0,0 +1,86 @@
+pub mod campaign_progress;
+pub mod map_file;
+pub mod scores;
+
+use std::{env, path::PathBuf};
+
+/// Nom du répertoire de l'application dans les dossiers de la plateforme
+const APP_DIR: &str = "tower-defense";
+
+/// Répertoire de données de l'application (cartes personnalisées, progression...)
+///
+/// Peut être surchargé avec la variable d'environnement `TOWER_DEFENSE_DATA_DIR`.
+pub fn data_dir() -> PathBuf {
+    if let Ok(dir) = env::var("TOWER_DEFENSE_DATA_DIR") {
+        return PathBuf::from(dir);
+    }
+
+    let base = if cfg!(target_os = "windows") {
+        env::var("APPDATA").map(PathBuf::from).ok()
+    } else if cfg!(target_os = "macos") {
+        env::var("HOME")
+            .map(|home| PathBuf::from(home).join("Library/Application Support"))
+            .ok()
+    } else {
+        env::var("XDG_DATA_HOME")
+            .map(PathBuf::from)
+            .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".local/share")))
+            .ok()
+    };
+
+    base.unwrap_or_else(|| PathBuf::from(".")).join(APP_DIR)
+}
+
+/// Répertoire de configuration de l'application
+///
+/// Peut être surchargé avec la variable d'environnement `TOWER_DEFENSE_CONFIG_DIR`.
+pub fn config_dir() -> PathBuf {
+    if let Ok(dir) = env::var("TOWER_DEFENSE_CONFIG_DIR") {
+        return PathBuf::from(dir);
+    }
+
+    let base = if cfg!(target_os = "windows") {
+        env::var("APPDATA").map(PathBuf::from).ok()
+    } else if cfg!(target_os = "macos") {
+        env::var("HOME")
+            .map(|home| PathBuf::from(home).join("Library/Application Support"))
+            .ok()
+    } else {
+        env::var("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|_| env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+            .ok()
+    };
+
+    base.unwrap_or_else(|| PathBuf::from(".")).join(APP_DIR)
+}
+
+/// Fichier des remplacements ASCII personnalisés
+pub fn glyphs_file() -> PathBuf {
+    config_dir().join("glyphs.conf")
+}
+
+/// Fichier des paramètres du joueur
+pub fn settings_file() -> PathBuf {
+    config_dir().join("settings.conf")
+}
+
+/// Fichier de configuration des touches
+pub fn keymap_file() -> PathBuf {
+    config_dir().join("keys.conf")
+}
+
+/// Fichier de progression de la campagne
+pub fn campaign_file() -> PathBuf {
+    data_dir().join("campaign.txt")
+}
+
+/// Fichier des meilleurs scores du mode sans fin
+pub fn scores_file() -> PathBuf {
+    data_dir().join("scores.txt")
+}
+
+/// Répertoire contenant les cartes enregistrées
+pub fn maps_dir() -> PathBuf {
+    data_dir().join("maps")
+}
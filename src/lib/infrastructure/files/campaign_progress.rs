@@ -0,0 +1,111 @@
+use std::{fs, io::ErrorKind, path::Path};
+
+use crate::domain::i18n::Locale;
+
+/// Progression de la campagne : meilleur nombre d'étoiles obtenu par niveau
+///
+/// Le fichier contient une ligne `identifiant = étoiles` par niveau réussi.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CampaignProgress {
+    pub stars: Vec<(String, u8)>,
+}
+
+impl CampaignProgress {
+    pub fn parse(content: &str, locale: Locale) -> Result<Self, String> {
+        let mut progress = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (level, stars) = line
+                .split_once('=')
+                .ok_or_else(|| locale.format("config.invalid_line", &[("line", &line)]))?;
+            let stars = stars
+                .trim()
+                .parse::<u8>()
+                .ok()
+                .filter(|stars| (1..=3).contains(stars))
+                .ok_or_else(|| {
+                    locale.format(
+                        "campaign_file.invalid_stars",
+                        &[("level", &level.trim()), ("line", &line)],
+                    )
+                })?;
+
+            progress.record(level.trim(), stars);
+        }
+
+        Ok(progress)
+    }
+
+    pub fn serialize(&self) -> String {
+        self.stars
+            .iter()
+            .map(|(level, stars)| format!("{} = {}\n", level, stars))
+            .collect()
+    }
+
+    /// Charge la progression, vide si le fichier n'existe pas encore
+    pub fn load(path: &Path, locale: Locale) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                Self::parse(&content, locale).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(locale.format(
+                "file.read_failed",
+                &[("path", &path.display()), ("error", &error)],
+            )),
+        }
+    }
+
+    pub fn save(&self, path: &Path, locale: Locale) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| {
+                locale.format(
+                    "file.create_dir_failed",
+                    &[("path", &dir.display()), ("error", &e)],
+                )
+            })?;
+        }
+
+        fs::write(path, self.serialize()).map_err(|e| {
+            locale.format(
+                "file.write_failed",
+                &[("path", &path.display()), ("error", &e)],
+            )
+        })
+    }
+
+    /// Étoiles obtenues sur un niveau, 0 s'il n'a jamais été réussi
+    pub fn stars(&self, level: &str) -> u8 {
+        self.stars
+            .iter()
+            .find(|(id, _)| id == level)
+            .map_or(0, |(_, stars)| *stars)
+    }
+
+    pub fn is_completed(&self, level: &str) -> bool {
+        self.stars(level) > 0
+    }
+
+    /// Conserve le meilleur nombre d'étoiles du niveau
+    ///
+    /// Retourne `true` si la progression a changé.
+    pub fn record(&mut self, level: &str, stars: u8) -> bool {
+        match self.stars.iter_mut().find(|(id, _)| id == level) {
+            Some((_, best)) if *best >= stars => false,
+            Some((_, best)) => {
+                *best = stars;
+                true
+            }
+            None => {
+                self.stars.push((level.to_string(), stars));
+                true
+            }
+        }
+    }
+}
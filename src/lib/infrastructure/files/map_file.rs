@@ -0,0 +1,314 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    application::engine::{
+        maps::terrain::terrain_from_key,
+        monsters::basics::{monster_from_key, monster_key},
+    },
+    domain::{
+        entities::{
+            map::{Decoration, Map, WaveScaling},
+            position::Position,
+        },
+        i18n::Locale,
+    },
+};
+
+/// Extension des fichiers de carte
+pub const MAP_FILE_EXTENSION: &str = "map";
+
+/// Lecture et écriture des cartes au format texte `clé = valeur`
+///
+/// ```text
+/// name = Forêt Enchantée
+/// description = Une forêt dense et mystérieuse
+/// width = 20
+/// height = 20
+/// start_symbol = 🌵
+/// end_symbol = 🏠
+/// roster = goblin, orc
+/// waypoints = 0,7; 5,7; 5,3
+/// decorations = 2,2 🌲; 8,9 🪨
+/// scaling = 1.15, 1.1, 0.05
+/// terrain = swamp
+/// ```
+///
+/// `scaling` (facultatif) règle la progression du mode sans fin : croissance des points de vie,
+/// du nombre de monstres et de la part d'élites à chaque vague.
+/// `terrain` (facultatif) désigne un terrain du registre qui modifie la vitesse des monstres.
+pub struct MapFile;
+
+impl MapFile {
+    /// Construit une carte à partir du contenu d'un fichier et la valide
+    ///
+    /// Les erreurs sont rédigées dans la langue demandée.
+    pub fn parse(content: &str, locale: Locale) -> Result<Map, String> {
+        let mut name = None;
+        let mut description = String::new();
+        let mut width = None;
+        let mut height = None;
+        let mut start_symbol = "🌵".to_string();
+        let mut end_symbol = "🏠".to_string();
+        let mut roster = Vec::new();
+        let mut waypoints = Vec::new();
+        let mut decorations = Vec::new();
+        let mut scaling = WaveScaling::default();
+        let mut terrain = None;
+
+        for (line_number, line) in content.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(locale.format(
+                    "map_file.invalid_line",
+                    &[("number", &(line_number + 1)), ("line", &line)],
+                ));
+            };
+            let value = value.trim();
+
+            match key.trim() {
+                "name" => name = Some(value.to_string()),
+                "description" => description = value.to_string(),
+                "width" => width = Some(parse_number(value, "width", locale)?),
+                "height" => height = Some(parse_number(value, "height", locale)?),
+                "start_symbol" => start_symbol = value.to_string(),
+                "end_symbol" => end_symbol = value.to_string(),
+                "roster" => {
+                    roster = value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|key| !key.is_empty())
+                        .map(str::to_string)
+                        .collect()
+                }
+                "waypoints" => {
+                    waypoints = split_entries(value)
+                        .map(|entry| parse_position(entry, locale))
+                        .collect::<Result<_, _>>()?
+                }
+                "decorations" => {
+                    decorations = split_entries(value)
+                        .map(|entry| parse_decoration(entry, locale))
+                        .collect::<Result<_, _>>()?
+                }
+                "scaling" => scaling = parse_scaling(value, locale)?,
+                "terrain" => {
+                    terrain = Some(terrain_from_key(value).ok_or_else(|| {
+                        locale.format("map_file.unknown_terrain", &[("terrain", &value)])
+                    })?)
+                }
+                other => return Err(locale.format("config.unknown_key", &[("key", &other)])),
+            }
+        }
+
+        let start_position = waypoints.first().copied().unwrap_or(Position::initial());
+        let monsters = roster
+            .iter()
+            .map(|key| {
+                monster_from_key(key, start_position)
+                    .ok_or_else(|| locale.format("map_file.unknown_monster", &[("monster", key)]))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let missing = |key: &str| locale.format("config.missing_key", &[("key", &key)]);
+        let map = Map::new(
+            name.ok_or_else(|| missing("name"))?,
+            description,
+            start_symbol,
+            end_symbol,
+            waypoints,
+            width.ok_or_else(|| missing("width"))?,
+            height.ok_or_else(|| missing("height"))?,
+            monsters,
+            terrain,
+        )
+        .with_decorations(decorations)
+        .with_scaling(scaling);
+
+        map.validate(locale)?;
+        Ok(map)
+    }
+
+    /// Convertit une carte au format texte
+    pub fn serialize(map: &Map) -> String {
+        let roster = map
+            .monsters
+            .iter()
+            .filter_map(monster_key)
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let waypoints = map
+            .waypoints
+            .iter()
+            .map(|wp| format!("{},{}", wp.x, wp.y))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        let decorations = map
+            .decorations
+            .iter()
+            .map(|d| format!("{},{} {}", d.position.x, d.position.y, d.symbol))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        [
+            format!("name = {}", map.name),
+            format!("description = {}", map.description),
+            format!("width = {}", map.width),
+            format!("height = {}", map.height),
+            format!("start_symbol = {}", map.start_symbol),
+            format!("end_symbol = {}", map.end_symbol),
+            format!("roster = {}", roster),
+            format!("waypoints = {}", waypoints),
+            format!("decorations = {}", decorations),
+            format!(
+                "scaling = {}, {}, {}",
+                map.scaling.hp_growth, map.scaling.count_growth, map.scaling.elite_growth
+            ),
+        ]
+        .into_iter()
+        .chain(
+            map.terrain
+                .iter()
+                .map(|terrain| format!("terrain = {}", terrain.key())),
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+            + "\n"
+    }
+
+    pub fn load(path: &Path, locale: Locale) -> Result<Map, String> {
+        let content = fs::read_to_string(path).map_err(|e| {
+            locale.format(
+                "file.read_failed",
+                &[("path", &path.display()), ("error", &e)],
+            )
+        })?;
+
+        Self::parse(&content, locale).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    /// Valide puis enregistre la carte dans le répertoire, sous un nom dérivé de son titre
+    pub fn save(map: &Map, dir: &Path, locale: Locale) -> Result<PathBuf, String> {
+        map.validate(locale)?;
+
+        fs::create_dir_all(dir).map_err(|e| {
+            locale.format(
+                "file.create_dir_failed",
+                &[("path", &dir.display()), ("error", &e)],
+            )
+        })?;
+
+        let path = dir.join(format!("{}.{}", file_stem(&map.name), MAP_FILE_EXTENSION));
+        fs::write(&path, Self::serialize(map)).map_err(|e| {
+            locale.format(
+                "file.write_failed",
+                &[("path", &path.display()), ("error", &e)],
+            )
+        })?;
+
+        Ok(path)
+    }
+
+    /// Charge toutes les cartes valides d'un répertoire, triées par nom de fichier
+    ///
+    /// Les fichiers invalides sont ignorés : une carte corrompue ne doit pas empêcher le jeu de démarrer.
+    pub fn load_dir(dir: &Path) -> Vec<Map> {
+        let Ok(entries) = fs::read_dir(dir) else {
+            return Vec::new();
+        };
+
+        let mut paths = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|ext| ext == MAP_FILE_EXTENSION)
+            })
+            .collect::<Vec<_>>();
+        paths.sort();
+
+        paths
+            .iter()
+            .filter_map(|path| Self::load(path, Locale::default()).ok())
+            .collect()
+    }
+}
+
+fn split_entries(value: &str) -> impl Iterator<Item = &str> {
+    value
+        .split(';')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+}
+
+fn parse_number(value: &str, key: &str, locale: Locale) -> Result<u32, String> {
+    value
+        .parse()
+        .map_err(|_| locale.format("config.invalid_value", &[("key", &key), ("value", &value)]))
+}
+
+fn parse_position(value: &str, locale: Locale) -> Result<Position, String> {
+    let invalid = || locale.format("map_file.invalid_position", &[("value", &value)]);
+    let (x, y) = value.split_once(',').ok_or_else(invalid)?;
+
+    let x = x.trim().parse();
+    let y = y.trim().parse();
+
+    match (x, y) {
+        (Ok(x), Ok(y)) => Ok(Position::new(x, y)),
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_scaling(value: &str, locale: Locale) -> Result<WaveScaling, String> {
+    let invalid = || locale.format("map_file.invalid_scaling", &[("value", &value)]);
+    let factors = value
+        .split(',')
+        .map(|factor| factor.trim().parse::<f32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| invalid())?;
+
+    match factors[..] {
+        [hp_growth, count_growth, elite_growth]
+            if hp_growth >= 1.0 && count_growth >= 1.0 && elite_growth >= 0.0 =>
+        {
+            Ok(WaveScaling::new(hp_growth, count_growth, elite_growth))
+        }
+        _ => Err(invalid()),
+    }
+}
+
+fn parse_decoration(value: &str, locale: Locale) -> Result<Decoration, String> {
+    let (position, symbol) = value
+        .split_once(' ')
+        .ok_or_else(|| locale.format("map_file.invalid_decoration", &[("value", &value)]))?;
+
+    Ok(Decoration::new(
+        parse_position(position, locale)?,
+        symbol.trim().to_string(),
+    ))
+}
+
+/// Nom de fichier sûr dérivé du nom de la carte
+fn file_stem(name: &str) -> String {
+    let stem = name
+        .trim()
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '-' })
+        .collect::<String>();
+
+    let stem = stem.trim_matches('-');
+    if stem.is_empty() {
+        "carte".to_string()
+    } else {
+        stem.to_string()
+    }
+}
@@ -0,0 +1,136 @@
+use std::{fs, io::ErrorKind, path::Path};
+
+use crate::domain::{entities::endless::RunScore, i18n::Locale};
+
+/// Meilleur score obtenu sur une carte avec des règles données
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScoreEntry {
+    pub map: String,
+    /// Identifiant des règles de la partie (voir `RunRules::key`)
+    pub rules: String,
+    pub score: RunScore,
+}
+
+/// Meilleurs scores du mode sans fin, un par carte et par règles
+///
+/// Chaque ligne du fichier contient `carte | règles | vagues | éliminations | gains | fuites`.
+#[derive(Debug, Clone, Default)]
+pub struct ScoreBoard {
+    pub entries: Vec<ScoreEntry>,
+}
+
+impl ScoreBoard {
+    pub fn parse(content: &str, locale: Locale) -> Result<Self, String> {
+        let entries = content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(|line| parse_entry(line, locale))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { entries })
+    }
+
+    pub fn serialize(&self) -> String {
+        self.entries
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{} | {} | {} | {} | {} | {}\n",
+                    entry.map,
+                    entry.rules,
+                    entry.score.waves_survived,
+                    entry.score.monsters_killed,
+                    entry.score.money_earned,
+                    entry.score.leaks
+                )
+            })
+            .collect()
+    }
+
+    /// Charge le tableau des scores, vide si le fichier n'existe pas encore
+    pub fn load(path: &Path, locale: Locale) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(content) => {
+                Self::parse(&content, locale).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(error) if error.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(error) => Err(locale.format(
+                "file.read_failed",
+                &[("path", &path.display()), ("error", &error)],
+            )),
+        }
+    }
+
+    pub fn save(&self, path: &Path, locale: Locale) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| {
+                locale.format(
+                    "file.create_dir_failed",
+                    &[("path", &dir.display()), ("error", &e)],
+                )
+            })?;
+        }
+
+        fs::write(path, self.serialize()).map_err(|e| {
+            locale.format(
+                "file.write_failed",
+                &[("path", &path.display()), ("error", &e)],
+            )
+        })
+    }
+
+    /// Meilleur score enregistré pour cette carte et ces règles
+    pub fn best(&self, map: &str, rules: &str) -> Option<&ScoreEntry> {
+        self.entries
+            .iter()
+            .find(|entry| entry.map == map && entry.rules == rules)
+    }
+
+    /// Enregistre un score s'il bat le précédent pour les mêmes carte et règles
+    ///
+    /// Retourne `true` lorsqu'il s'agit d'un nouveau record.
+    pub fn record(&mut self, map: &str, rules: &str, score: RunScore) -> bool {
+        match self
+            .entries
+            .iter_mut()
+            .find(|entry| entry.map == map && entry.rules == rules)
+        {
+            Some(entry) if entry.score.points() >= score.points() => false,
+            Some(entry) => {
+                entry.score = score;
+                true
+            }
+            None => {
+                self.entries.push(ScoreEntry {
+                    map: map.to_string(),
+                    rules: rules.to_string(),
+                    score,
+                });
+                true
+            }
+        }
+    }
+}
+
+fn parse_entry(line: &str, locale: Locale) -> Result<ScoreEntry, String> {
+    let invalid = || locale.format("scores_file.invalid_entry", &[("line", &line)]);
+    let fields = line.split('|').map(str::trim).collect::<Vec<_>>();
+
+    let [map, rules, waves, kills, money, leaks] = fields[..] else {
+        return Err(invalid());
+    };
+
+    let number = |value: &str| value.parse::<u32>().map_err(|_| invalid());
+
+    Ok(ScoreEntry {
+        map: map.to_string(),
+        rules: rules.to_string(),
+        score: RunScore {
+            waves_survived: number(waves)?,
+            monsters_killed: number(kills)?,
+            money_earned: number(money)?,
+            leaks: number(leaks)?,
+        },
+    })
+}
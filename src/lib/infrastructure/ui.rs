@@ -1,5 +1,12 @@
 pub mod app;
+pub mod camera;
+pub mod editor;
 pub mod events;
+pub mod glyphs;
+pub mod help;
+pub mod keymap;
+pub mod settings;
 pub mod tui;
+#[allow(clippy::module_inception)]
 pub mod ui;
 pub mod widgets;
@@ -1,26 +1,54 @@
 use std::sync::Arc;
 
+use crate::application::engine::campaign::{self, CampaignLevel, STARTING_TOWERS};
 use crate::application::engine::maps::forest::ForestMap;
-use crate::domain::entities::map::Map;
+use crate::application::engine::maps::random::{
+    DEFAULT_GENERATED_SIZE, MIN_GENERATED_SIZE, MapDifficulty, RandomMap,
+};
+use crate::domain::entities::command::{GameCommand, GameEngine};
+use crate::domain::entities::endless::{GameMode, RunScore};
+use crate::domain::entities::event::NotificationSubscriber;
+use crate::domain::entities::map::{MAX_MAP_SIZE, Map};
+use crate::domain::entities::rules::{Mutator, RunRules};
 use crate::domain::entities::tower::TowerKind;
 use crate::domain::entities::tower::{Tower, TowerStatType};
-use crate::domain::entities::{game::Game, position::Position};
+use crate::domain::entities::{
+    components::EntityId,
+    game::{Game, GameOutcome, STANDARD_WAVE_COUNT},
+    position::Position,
+};
+use crate::domain::error::GameError;
+use crate::domain::i18n::Locale;
 use crate::domain::mediator::MediatorService;
+use crate::domain::ports::notifier::Notifier;
+use crate::domain::services::notifications::toast::ToastNotifier;
+use crate::infrastructure::files::{
+    campaign_file, campaign_progress::CampaignProgress, map_file::MapFile, maps_dir,
+    scores::ScoreBoard, scores_file,
+};
 use color_eyre::Result;
 use crossterm::event::KeyCode;
-use rand::{rng, seq::IndexedRandom};
-
-use super::ui::render;
+use rand::{Rng, rng};
+use ratatui::layout::Rect;
+
+use super::camera::Camera;
+use super::editor::MapEditor;
+use super::glyphs::Glyphs;
+use super::help::{HelpPage, Tutorial};
+use super::keymap::Keymap;
+use super::settings::{Settings, SettingsItem};
+use super::ui::{is_area_too_small, map_viewport, render};
 use super::{
     events::{
         event::{Event, EventConfig, Events},
         handlers::{
-            handle_key_down, handle_key_esc, handle_key_left, handle_key_p, handle_key_q,
-            handle_key_right, handle_key_up,
+            handle_editor_key, handle_help_key, handle_key_action, handle_key_backspace,
+            handle_key_digit,
         },
     },
     tui::Tui,
 };
+use crate::infrastructure::files::settings_file;
 
 /// Actions disponibles dans le menu d'action du jeu
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +58,47 @@ pub enum GameAction {
     UpgradeTower, // Action pour améliorer une tour existante
 }
 
+/// Options de partie proposées sous la liste des cartes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunOption {
+    Mode,
+    Difficulty,
+    Mutator(Mutator),
+}
+
+impl RunOption {
+    /// Options dans l'ordre d'affichage
+    pub fn all() -> Vec<RunOption> {
+        [RunOption::Mode, RunOption::Difficulty]
+            .into_iter()
+            .chain(Mutator::ALL.into_iter().map(RunOption::Mutator))
+            .collect()
+    }
+}
+
+/// Bilan d'une partie sans fin, affiché à la place de l'écran de fin de partie
+#[derive(Debug, Clone)]
+pub struct EndlessSummary {
+    /// Meilleur score précédent avec la même carte et les mêmes règles
+    pub best: Option<RunScore>,
+    pub new_record: bool,
+    /// Erreur lors de l'enregistrement du score
+    pub error: Option<String>,
+}
+
+/// Résultat d'un niveau de campagne
+#[derive(Debug, Clone)]
+pub struct CampaignResult {
+    /// Étoiles obtenues, 0 en cas de défaite
+    pub stars: u8,
+    /// Niveau débloqué par cette victoire
+    pub unlocked_level: Option<String>,
+    /// Tours débloquées par cette victoire
+    pub unlocked_towers: Vec<String>,
+    /// Erreur lors de l'enregistrement de la progression
+    pub error: Option<String>,
+}
+
 /// Types de tours disponibles pour la construction
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TowerType {
@@ -87,15 +156,16 @@ pub enum UiMode {
 
 /// Structure pour gérer le menu des améliorations
 pub struct UpgradeMenu {
-    pub tower_index: usize,
+    /// Tour améliorée, retrouvée par identifiant même si d'autres tours sont vendues
+    pub tower: EntityId,
     pub selected_upgrade: usize,
     pub available_upgrades: Vec<(TowerStatType, String)>,
 }
 
 impl UpgradeMenu {
-    pub fn new(tower_index: usize, available_upgrades: Vec<(TowerStatType, String)>) -> Self {
+    pub fn new(tower: EntityId, available_upgrades: Vec<(TowerStatType, String)>) -> Self {
         Self {
-            tower_index,
+            tower,
             selected_upgrade: 0,
             available_upgrades,
         }
@@ -116,11 +186,52 @@ pub struct App {
     pub selected_tower: Option<Tower>,
     pub upgrade_menu: Option<UpgradeMenu>,
     pub tower_selection_on_map: bool,
-    pub selected_tower_index: Option<usize>,
+    /// Tour posée sélectionnée sur la carte
+    pub selected_tower_id: Option<EntityId>,
     pub available_maps: Vec<Map>,
     pub selected_map: Option<Map>,
+    pub random_map_seed: u64,
+    pub random_map_difficulty: MapDifficulty,
+    pub random_map_width: u32,
+    pub random_map_height: u32,
+    pub map_editor: Option<MapEditor>,
+    pub camera: Camera,
+    pub terminal_area: Rect,
+    pub glyphs: Glyphs,
+    pub keymap: Keymap,
+    /// Affiche la liste des touches par-dessus la vue courante
+    pub show_help: bool,
+    /// Multiplicateur appliqué au temps de jeu
+    pub game_speed: f32,
+    pub help_page: HelpPage,
+    /// Vue à réafficher en quittant l'aide
+    pub help_return_view: View,
+    /// Tutoriel en cours, le jeu est en pause tant qu'il est actif
+    pub tutorial: Option<Tutorial>,
+    pub settings: Settings,
+    /// Langue de l'interface, celle des paramètres sauf si l'environnement en impose une
+    pub locale: Locale,
+    /// Erreur lors du dernier enregistrement des paramètres
+    pub settings_error: Option<String>,
+    /// Modificateurs choisis pour la prochaine partie
+    pub mutators: Vec<Mutator>,
+    /// Mode choisi pour la prochaine partie
+    pub game_mode: GameMode,
+    pub endless_summary: Option<EndlessSummary>,
+    /// Toutes les tours du jeu, la campagne n'en propose qu'une partie
+    pub all_towers: Vec<Tower>,
+    pub campaign_levels: Vec<CampaignLevel>,
+    pub campaign_progress: CampaignProgress,
+    /// Index du niveau de campagne en cours
+    pub campaign_level: Option<usize>,
+    pub campaign_result: Option<CampaignResult>,
+    /// Toasts affichés par-dessus l'interface : erreurs et notifications du médiateur
+    pub toasts: ToastNotifier,
 }
 
+/// Vitesses de jeu proposées par l'action de changement de vitesse
+const GAME_SPEEDS: [f32; 3] = [1.0, 2.0, 4.0];
+
 /// Les différentes vues disponibles dans l'application
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum View {
@@ -132,13 +243,32 @@ pub enum View {
     Pause,
     /// Écran de fin de jeu
     GameOver,
+    /// Écran de victoire avec le bilan de la partie
+    Victory,
     /// Écran de sélection de carte
     MapSelection,
+    /// Éditeur de cartes
+    MapEditor,
+    /// Écran des paramètres
+    Settings,
+    /// Écran d'aide
+    Help,
+    /// Bilan d'une partie sans fin
+    EndlessSummary,
+    /// Carte de la campagne
+    Campaign,
+    /// Résultat d'un niveau de campagne
+    CampaignResult,
 }
 
 impl App {
     /// Crée une nouvelle instance de l'application avec le jeu fourni
-    pub fn new(mediator: Arc<MediatorService>, towers: Vec<Tower>, maps: Vec<Map>) -> Self {
+    pub fn new(
+        mediator: Arc<MediatorService>,
+        towers: Vec<Tower>,
+        maps: Vec<Map>,
+        glyphs: Glyphs,
+    ) -> Self {
         // Actions par défaut
         let actions = vec![
             GameAction::BuildTower,
@@ -153,36 +283,130 @@ impl App {
             current_view: View::MainMenu,
             selected_index: 0,
             available_actions: actions,
-            available_towers: towers,
+            available_towers: towers.clone(),
             ui_mode: UiMode::Normal,
             cursor_position: Position::new(5, 5),
             selected_tower: None,
             upgrade_menu: None,
             tower_selection_on_map: false,
-            selected_tower_index: None,
+            selected_tower_id: None,
             available_maps: maps,
             selected_map: None,
+            random_map_seed: rng().random_range(0..1_000_000),
+            random_map_difficulty: MapDifficulty::Normal,
+            random_map_width: DEFAULT_GENERATED_SIZE,
+            random_map_height: DEFAULT_GENERATED_SIZE,
+            map_editor: None,
+            camera: Camera::default(),
+            terminal_area: Rect::default(),
+            glyphs,
+            keymap: Keymap::default(),
+            show_help: false,
+            game_speed: 1.0,
+            help_page: HelpPage::Controls,
+            help_return_view: View::MainMenu,
+            tutorial: None,
+            settings: Settings::default(),
+            locale: Locale::default(),
+            settings_error: None,
+            mutators: Vec::new(),
+            game_mode: GameMode::Standard,
+            endless_summary: None,
+            all_towers: towers,
+            campaign_levels: campaign::levels(),
+            campaign_progress: CampaignProgress::default(),
+            campaign_level: None,
+            campaign_result: None,
+            toasts: ToastNotifier::new(),
         }
     }
 
+    pub fn with_settings(mut self, settings: Settings) -> Self {
+        self.mediator.set_policy(settings.notification_policy());
+        self.locale = settings.locale;
+        self.settings = settings;
+        self
+    }
+
+    pub fn with_locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    pub fn with_campaign_progress(mut self, progress: CampaignProgress) -> Self {
+        self.campaign_progress = progress;
+        self
+    }
+
+    /// Affiche les notifications reçues par ce notifieur, qui doit aussi être confié au médiateur
+    pub fn with_toasts(mut self, toasts: ToastNotifier) -> Self {
+        self.toasts = toasts;
+        self
+    }
+
+    /// Transmet une commande à la partie et signale son éventuel refus au joueur
+    pub fn execute(&mut self, command: GameCommand) -> bool {
+        match self.game.execute(command) {
+            Ok(_) => true,
+            Err(error) => {
+                self.report_error(error);
+                false
+            }
+        }
+    }
+
+    /// Signale au joueur une action impossible, dans le journal et par un toast
+    pub fn report_error(&mut self, error: GameError) {
+        let message = error.message(self.locale);
+        self.game.add_log(format!("❌ {}", message));
+        // Le toast est affiché dans l'interface, il ne peut échouer que si sa file est corrompue
+        let _ = self
+            .toasts
+            .send_notification(self.locale.text("error.title"), &message);
+    }
+
+    pub fn with_keymap(mut self, keymap: Keymap) -> Self {
+        self.keymap = keymap;
+        self
+    }
+
     pub fn run(&mut self, tui: &mut Tui) -> Result<()> {
-        let events = Events::new(EventConfig::default());
+        let mut tick_rate = self.settings.tick_rate();
+        let mut events = Events::new(EventConfig { tick_rate });
+        self.resize(tui.area()?);
+
         while self.running {
+            // Relancer le gestionnaire d'événements si le rafraîchissement a changé dans les paramètres
+            if self.settings.tick_rate() != tick_rate {
+                tick_rate = self.settings.tick_rate();
+                events = Events::new(EventConfig { tick_rate });
+            }
+
             tui.draw(|frame| render(self, frame))?;
 
             match events.next()? {
-                Event::Key(key) => match key.code {
-                    KeyCode::Char('q') => handle_key_q(self),
-                    KeyCode::Up => handle_key_up(self),
-                    KeyCode::Down => handle_key_down(self),
-                    KeyCode::Left => handle_key_left(self),
-                    KeyCode::Right => handle_key_right(self),
-                    KeyCode::Enter => self.confirm_selection(),
-                    KeyCode::Esc => handle_key_esc(self),
-                    KeyCode::Char('p') => handle_key_p(self),
+                Event::Key(key) if self.show_help => handle_help_key(self, key.code),
+                Event::Key(key) if self.current_view == View::MapEditor => {
+                    handle_editor_key(self, key.code)
+                }
+                Event::Key(key) => match (self.keymap.action(key.code), key.code) {
+                    (Some(action), _) => handle_key_action(self, action),
+                    (None, KeyCode::Char(c)) if c.is_ascii_digit() => {
+                        handle_key_digit(self, c.to_digit(10).unwrap())
+                    }
+                    (None, KeyCode::Backspace) => handle_key_backspace(self),
                     _ => {}
                 },
-                Event::Tick => self.tick(0.1),
+                Event::Tick => {
+                    self.tick(tick_rate.as_secs_f32());
+                    // Délivre les notifications regroupées dont le délai est écoulé
+                    if let Err(error) = self.mediator.flush() {
+                        self.game.add_log(error);
+                    }
+                }
+                Event::Resize(width, height) => self.resize(Rect::new(0, 0, width, height)),
+                Event::FocusGained => self.mediator.set_focused(true),
+                Event::FocusLost => self.mediator.set_focused(false),
                 _ => {}
             }
         }
@@ -190,18 +414,69 @@ impl App {
         Ok(())
     }
 
+    /// Enregistre la nouvelle taille du terminal et recalcule la disposition
+    pub fn resize(&mut self, area: Rect) {
+        self.terminal_area = area;
+        self.update_viewport();
+    }
+
+    /// Indique si le terminal est trop petit pour afficher le jeu
+    pub fn is_terminal_too_small(&self) -> bool {
+        is_area_too_small(self.terminal_area)
+    }
+
+    /// Adapte la caméra à la place disponible pour la carte
+    pub fn update_viewport(&mut self) {
+        if let Some(map) = &self.game.current_map {
+            let (width, height) = map_viewport(self.terminal_area);
+            self.camera.set_viewport(width, height, map);
+        }
+    }
+
+    /// Fait défiler la vue de la carte
+    pub fn scroll_camera(&mut self, dx: i32, dy: i32) {
+        if let Some(map) = &self.game.current_map {
+            self.camera.scroll(dx, dy, map);
+        }
+    }
+
+    /// Recentre la vue sur le curseur
+    pub fn center_camera(&mut self) {
+        if let Some(map) = &self.game.current_map {
+            self.camera.center_on(self.cursor_position, map);
+        }
+    }
+
+    /// Garde le curseur visible après un déplacement
+    fn follow_cursor(&mut self) {
+        if let Some(map) = &self.game.current_map {
+            self.camera.follow(self.cursor_position, map);
+        }
+    }
+
     /// Met à jour l'état du jeu
     pub fn tick(&mut self, delta_time: f32) {
-        // Ne met à jour le jeu que si nous sommes dans la vue de jeu active
-        if matches!(self.current_view, View::Game) {
+        // Ne met à jour le jeu que si nous sommes dans la vue de jeu active,
+        // et le met en pause tant que le terminal est trop petit pour l'afficher
+        if matches!(self.current_view, View::Game) && !self.is_terminal_too_small() {
+            // Le tutoriel met le jeu en pause jusqu'à ce que toutes ses étapes soient faites
+            if let Some(tutorial) = &mut self.tutorial {
+                if tutorial.update(&self.game) {
+                    self.finish_tutorial();
+                }
+                return;
+            }
+
             // Déplacer la logique d'update ici pour être contrôlée par l'UI
-            self.game.update(delta_time);
+            self.game.update(delta_time * self.game_speed);
 
             // Vérifier l'état du jeu pour les transitions
-            if self.game.player_life <= 0 {
-                self.current_view = View::GameOver;
-            } else if self.game.waves.is_none() && self.game.current_wave.is_none() {
-                self.current_view = View::GameOver;
+            match (self.game.outcome, self.game.mode) {
+                (None, _) => {}
+                (Some(_), GameMode::Campaign) => self.finish_campaign_level(),
+                (Some(GameOutcome::Defeat), GameMode::Endless) => self.finish_endless_run(),
+                (Some(GameOutcome::Victory), _) => self.set_view(View::Victory),
+                (Some(GameOutcome::Defeat), _) => self.set_view(View::GameOver),
             }
         }
     }
@@ -216,6 +491,14 @@ impl App {
         self.current_view = view;
         self.selected_index = 0; // Réinitialiser la sélection
         self.ui_mode = UiMode::Normal; // Réinitialiser le mode
+        self.upgrade_menu = None;
+        self.clear_tower_selection();
+    }
+
+    /// Oublie la tour sélectionnée sur la carte
+    fn clear_tower_selection(&mut self) {
+        self.tower_selection_on_map = false;
+        self.selected_tower_id = None;
     }
 
     /// Retourne le nombre d'éléments dans le menu actuel
@@ -229,10 +512,17 @@ impl App {
                     UiMode::TowerUpgrade => self.game.towers.len(), // Nombre de tours sur le terrain
                 }
             }
-            View::MainMenu => 2, // Nombre d'options dans le menu principal
-            View::MapSelection => self.available_maps.len(), // Nombre de cartes disponibles
-            View::Pause => 2,    // Nombre d'options dans le menu de pause
+            View::MainMenu => 7, // Nombre d'options dans le menu principal
+            View::MapSelection => self.available_maps.len() + 1 + RunOption::all().len(), // Cartes, carte aléatoire et options de partie
+            View::Pause => 3,    // Nombre d'options dans le menu de pause
             View::GameOver => 2, // Nombre d'options dans le menu de game over
+            View::Victory => 3,
+            View::EndlessSummary => 3,
+            View::Campaign => self.campaign_levels.len(),
+            View::CampaignResult => 3,
+            View::MapEditor => 0, // L'éditeur gère ses propres touches
+            View::Settings => SettingsItem::ALL.len(),
+            View::Help => 0, // Les pages se parcourent avec gauche/droite
         }
     }
 
@@ -283,13 +573,10 @@ impl App {
     pub fn move_cursor(&mut self, dx: i32, dy: i32) {
         if self.ui_mode == UiMode::Placement {
             let current_map = self.game.current_map.as_ref().unwrap();
-            let new_x = (self.cursor_position.x + dx)
-                .max(0)
-                .min(current_map.width.try_into().unwrap());
-            let new_y = (self.cursor_position.y + dy)
-                .max(0)
-                .min(current_map.height.try_into().unwrap());
+            let new_x = (self.cursor_position.x + dx).clamp(0, current_map.width as i32 - 1);
+            let new_y = (self.cursor_position.y + dy).clamp(0, current_map.height as i32 - 1);
             self.cursor_position = Position::new(new_x, new_y);
+            self.follow_cursor();
         }
     }
 
@@ -303,12 +590,11 @@ impl App {
                 // Retourner au mode normal
                 self.ui_mode = UiMode::Normal;
                 self.selected_index = 0; // Réinitialiser la sélection
-                self.tower_selection_on_map = false;
-                self.selected_tower_index = None;
+                self.clear_tower_selection();
             }
             UiMode::Placement => {
                 // Si on est en mode placement, retourner à la sélection de tour
-                if let Some(_) = self.selected_tower {
+                if self.selected_tower.is_some() {
                     self.ui_mode = UiMode::TowerSelection;
                 } else {
                     // Si pas de tour sélectionnée, retourner au mode normal
@@ -321,8 +607,7 @@ impl App {
                 self.ui_mode = UiMode::Normal;
                 self.selected_index = 0;
                 self.upgrade_menu = None;
-                self.tower_selection_on_map = false;
-                self.selected_tower_index = None;
+                self.clear_tower_selection();
             }
         }
     }
@@ -355,8 +640,8 @@ impl App {
                     }
                     UiMode::TowerSelection => {
                         if self.tower_selection_on_map {
-                            if let Some(tower_index) = self.selected_tower_index {
-                                self.upgrade_tower(tower_index, None);
+                            if let Some(tower) = self.selected_tower_id {
+                                self.upgrade_tower(tower, None);
 
                                 self.ui_mode = UiMode::TowerUpgrade;
                                 self.tower_selection_on_map = false;
@@ -377,27 +662,19 @@ impl App {
                                 == GameAction::UpgradeTower;
 
                         if is_upgrade_mode {
-                            let mut found_tower = false;
-                            for (idx, tower) in self.game.towers.iter().enumerate() {
-                                if tower.position.x == self.cursor_position.x
-                                    && tower.position.y == self.cursor_position.y
-                                {
-                                    self.upgrade_tower(idx, None);
-                                    found_tower = true;
-                                    break;
-                                }
-                            }
-
-                            if !found_tower {
-                                self.game.add_log(
-                                    "Aucune tour à cette position pour amélioration.".to_string(),
-                                );
+                            match self.game.tower_at(self.cursor_position).map(|t| t.id) {
+                                Some(tower) => self.upgrade_tower(tower, None),
+                                None => self.game.add_log(
+                                    self.locale.text("log.no_tower_to_upgrade").to_string(),
+                                ),
                             }
                         } else if let Some(tower) = self.selected_tower.clone() {
-                            self.add_tower(tower, self.cursor_position).unwrap();
-
-                            self.ui_mode = UiMode::Normal;
-                            self.selected_tower = None;
+                            // En cas d'échec, rester en placement pour choisir une autre case
+                            let position = self.cursor_position;
+                            if self.execute(GameCommand::PlaceTower { tower, position }) {
+                                self.ui_mode = UiMode::Normal;
+                                self.selected_tower = None;
+                            }
                         } else {
                             self.remove_tower(self.cursor_position);
                             self.ui_mode = UiMode::Normal;
@@ -428,25 +705,56 @@ impl App {
                         self.set_view(View::MapSelection);
                     }
                     1 => {
+                        self.set_view(View::Campaign);
+                    }
+                    2 => {
+                        self.start_tutorial();
+                    }
+                    3 => {
+                        self.open_map_editor();
+                    }
+                    4 => {
+                        self.set_view(View::Settings);
+                    }
+                    5 => {
+                        self.open_help();
+                    }
+                    6 => {
                         self.quit();
                     }
                     _ => {}
                 }
             }
+            View::MapEditor => {}
+            View::Help => self.close_help(),
+            View::Settings => match self.selected_settings_item() {
+                SettingsItem::Back => self.set_view(View::MainMenu),
+                _ => self.adjust_setting(1),
+            },
             View::MapSelection => {
-                if self.selected_index < self.available_maps.len() {
-                    let selected_map = self.available_maps[self.selected_index].clone();
+                if let Some(option) = self.selected_run_option() {
+                    match option {
+                        RunOption::Mode => self.game_mode = self.game_mode.toggle(),
+                        RunOption::Difficulty => self.change_difficulty(1),
+                        RunOption::Mutator(mutator) => self.toggle_mutator(mutator),
+                    }
+                    return;
+                }
 
-                    self.selected_map = Some(selected_map.clone());
-                    self.game = Game::new(self.mediator.clone(), vec![], 10, 1.0);
-                    self.game.current_map = Some(selected_map);
+                let selected_map = if self.is_random_map_selected() {
+                    Some(self.random_map().generate())
+                } else {
+                    self.available_maps.get(self.selected_index).cloned()
+                };
 
-                    self.set_view(View::Game);
+                if let Some(selected_map) = selected_map {
+                    self.start_map(selected_map);
                 }
             }
             View::Pause => match self.selected_index {
                 0 => self.set_view(View::Game),
-                1 => self.quit(),
+                1 => self.open_help(),
+                2 => self.quit(),
                 _ => {}
             },
             View::GameOver => match self.selected_index {
@@ -454,63 +762,535 @@ impl App {
                 1 => self.quit(),
                 _ => {}
             },
+            View::Victory => match self.selected_index {
+                0 => self.set_view(View::MapSelection),
+                1 => self.set_view(View::MainMenu),
+                2 => self.quit(),
+                _ => {}
+            },
+            View::Campaign => self.start_campaign_level(self.selected_index),
+            View::CampaignResult => match self.selected_index {
+                0 => {
+                    // Niveau suivant après une victoire, sinon nouvelle tentative
+                    let won = self
+                        .campaign_result
+                        .as_ref()
+                        .is_some_and(|result| result.stars > 0);
+                    if let Some(level) = self.campaign_level {
+                        let next = if won { level + 1 } else { level };
+                        if next < self.campaign_levels.len() {
+                            self.start_campaign_level(next);
+                        } else {
+                            self.set_view(View::Campaign);
+                        }
+                    }
+                }
+                1 => self.set_view(View::Campaign),
+                2 => self.set_view(View::MainMenu),
+                _ => {}
+            },
+            View::EndlessSummary => match self.selected_index {
+                0 => {
+                    if let Some(map) = self.selected_map.clone() {
+                        self.start_map(map);
+                    }
+                }
+                1 => self.set_view(View::MapSelection),
+                2 => self.set_view(View::MainMenu),
+                _ => {}
+            },
         }
     }
 
-    pub fn reset_game(&mut self) {
-        let map = ForestMap::new();
-        let n = 10;
+    /// Démarre une nouvelle partie sur la carte choisie
+    fn start_map(&mut self, map: Map) {
+        self.selected_map = Some(map.clone());
+        self.game = self.new_game();
+        self.game.current_map = Some(map);
+        self.camera = Camera::default();
+        self.update_viewport();
+        self.clear_tower_selection();
+        self.tutorial = None;
+        self.campaign_level = None;
+        self.available_towers = self.all_towers.clone();
+
+        self.set_view(View::Game);
+    }
+
+    /// Indique si un niveau de campagne peut être joué : le précédent doit être réussi
+    pub fn is_campaign_level_unlocked(&self, index: usize) -> bool {
+        index == 0
+            || self
+                .campaign_levels
+                .get(index - 1)
+                .is_some_and(|level| self.campaign_progress.is_completed(level.id))
+    }
+
+    /// Noms des tours débloquées dans la campagne
+    pub fn unlocked_towers(&self) -> Vec<String> {
+        let unlocked = self
+            .campaign_levels
+            .iter()
+            .filter(|level| self.campaign_progress.is_completed(level.id))
+            .flat_map(|level| level.unlocks_towers.iter());
+
+        STARTING_TOWERS
+            .iter()
+            .chain(unlocked)
+            .map(|name| name.to_string())
+            .collect()
+    }
+
+    /// Lance un niveau de campagne débloqué avec ses vagues scriptées
+    pub fn start_campaign_level(&mut self, index: usize) {
+        if !self.is_campaign_level_unlocked(index) {
+            return;
+        }
+        let Some(map) = self.campaign_levels.get(index).map(|level| (level.map)()) else {
+            return;
+        };
+
+        self.start_map(map.clone());
+
+        let waves = self.campaign_levels[index].build_waves(
+            &map,
+            self.game.spawn_interval,
+            self.game.wave_multiplier,
+        );
+        self.game.waves = Some(waves);
+        self.game.mode = GameMode::Campaign;
+        self.game.wave_limit = None;
+        // Les modificateurs de la sélection de carte ne s'appliquent pas à la campagne
+        self.game.rules =
+            RunRules::new(self.settings.difficulty, vec![]).with_custom(self.settings.is_custom());
+
+        let unlocked = self.unlocked_towers();
+        self.available_towers = self
+            .all_towers
+            .iter()
+            .filter(|tower| unlocked.contains(&tower.name))
+            .cloned()
+            .collect();
+        self.campaign_level = Some(index);
+        self.campaign_result = None;
+    }
+
+    /// Attribue les étoiles du niveau en cours, enregistre la progression et affiche le résultat
+    fn finish_campaign_level(&mut self) {
+        let Some(index) = self.campaign_level else {
+            return;
+        };
+
+        let stars = CampaignLevel::stars(self.game.player_life, self.settings.starting_life);
+        let level_id = self.campaign_levels[index].id;
+        let was_next_unlocked = self.is_campaign_level_unlocked(index + 1);
+        let towers_before = self.unlocked_towers();
+
+        let mut error = None;
+        if stars > 0 && self.campaign_progress.record(level_id, stars) {
+            error = self
+                .campaign_progress
+                .save(&campaign_file(), self.locale)
+                .err();
+        }
+
+        let unlocked_level = self
+            .campaign_levels
+            .get(index + 1)
+            .filter(|_| !was_next_unlocked && self.is_campaign_level_unlocked(index + 1))
+            .map(|level| level.name(self.locale).to_string());
+        let unlocked_towers = self
+            .unlocked_towers()
+            .into_iter()
+            .filter(|tower| !towers_before.contains(tower))
+            .collect();
+
+        self.campaign_result = Some(CampaignResult {
+            stars,
+            unlocked_level,
+            unlocked_towers,
+            error,
+        });
+        self.set_view(View::CampaignResult);
+    }
+
+    /// Démarre la première carte avec le tutoriel guidé
+    pub fn start_tutorial(&mut self) {
+        if let Some(map) = self.available_maps.first().cloned() {
+            self.start_map(map);
+            // Le tutoriel demande de vendre une tour : pas de modificateurs
+            self.game.rules.mutators.clear();
+            self.game.mode = GameMode::Standard;
+            self.game.wave_limit = Some(STANDARD_WAVE_COUNT);
+            self.tutorial = Some(Tutorial::new(&self.game));
+        }
+    }
+
+    /// Termine le tutoriel et lance la partie
+    pub fn finish_tutorial(&mut self) {
+        if self.tutorial.take().is_some() {
+            self.game
+                .add_log(self.locale.text("log.tutorial_finished").to_string());
+        }
+    }
 
-        let mut selected_monsters = Vec::new();
-        for _ in 0..n {
-            if let Some(monster) = map.monsters.choose(&mut rng()) {
-                selected_monsters.push(monster.clone());
+    /// Enregistre le score de la partie sans fin et affiche son bilan
+    fn finish_endless_run(&mut self) {
+        let map = self
+            .game
+            .current_map
+            .as_ref()
+            .map(|map| map.name.clone())
+            .unwrap_or_default();
+        let rules = self.game.rules.key();
+        let score = self.game.score;
+
+        let summary = match ScoreBoard::load(&scores_file(), self.locale) {
+            Ok(mut board) => {
+                let best = board.best(&map, &rules).map(|entry| entry.score);
+                let new_record = board.record(&map, &rules, score);
+                let error = match new_record {
+                    true => board.save(&scores_file(), self.locale).err(),
+                    false => None,
+                };
+
+                EndlessSummary {
+                    best,
+                    new_record,
+                    error,
+                }
             }
+            Err(error) => EndlessSummary {
+                best: None,
+                new_record: false,
+                error: Some(error),
+            },
+        };
+
+        self.endless_summary = Some(summary);
+        self.set_view(View::EndlessSummary);
+    }
+
+    /// Ouvre l'aide depuis la vue courante
+    pub fn open_help(&mut self) {
+        self.help_return_view = self.current_view;
+        self.help_page = HelpPage::Controls;
+        self.set_view(View::Help);
+    }
+
+    /// Revient à la vue depuis laquelle l'aide a été ouverte
+    pub fn close_help(&mut self) {
+        self.set_view(self.help_return_view);
+    }
+
+    /// Indique si l'entrée "Carte aléatoire" est sélectionnée dans la sélection de carte
+    pub fn is_random_map_selected(&self) -> bool {
+        self.current_view == View::MapSelection && self.selected_index == self.available_maps.len()
+    }
+
+    /// Option de partie sélectionnée dans la sélection de carte
+    pub fn selected_run_option(&self) -> Option<RunOption> {
+        if self.current_view != View::MapSelection {
+            return None;
         }
 
-        self.game = Game::new(self.mediator.clone(), vec![], 10, 1.0);
-        self.game.current_map = Some(map);
+        self.selected_index
+            .checked_sub(self.available_maps.len() + 1)
+            .and_then(|index| RunOption::all().get(index).copied())
+    }
 
-        self.ui_mode = UiMode::Normal;
-        self.selected_index = 0;
-        self.selected_tower = None;
+    /// Passe à la difficulté suivante (`step > 0`) ou précédente et l'enregistre
+    pub fn change_difficulty(&mut self, step: i32) {
+        self.settings.adjust(SettingsItem::Difficulty, step);
+        self.settings_error = self.settings.save(&settings_file(), self.locale).err();
     }
 
-    pub fn add_tower(&mut self, tower: Tower, position: Position) -> Result<(), String> {
-        if !self.game.has_enough_money(tower.cost) {
-            return Err("Pas assez d'argent".to_string());
+    pub fn toggle_mutator(&mut self, mutator: Mutator) {
+        if let Some(index) = self.mutators.iter().position(|m| *m == mutator) {
+            self.mutators.remove(index);
+        } else {
+            self.mutators.push(mutator);
         }
+    }
+
+    /// Règles de la prochaine partie selon les paramètres et les modificateurs choisis
+    pub fn run_rules(&self) -> RunRules {
+        RunRules::new(self.settings.difficulty, self.mutators.clone())
+            .with_custom(self.settings.is_custom())
+    }
+
+    /// Générateur correspondant à la graine, à la taille et à la difficulté choisies
+    pub fn random_map(&self) -> RandomMap {
+        RandomMap::new(
+            self.random_map_seed,
+            self.random_map_width,
+            self.random_map_height,
+            self.random_map_difficulty,
+        )
+        .with_locale(self.locale)
+    }
+
+    /// Agrandit ou réduit la carte aléatoire de `dx` colonnes et `dy` lignes
+    pub fn resize_random_map(&mut self, dx: i32, dy: i32) {
+        let resize = |size: u32, delta: i32| {
+            size.saturating_add_signed(delta)
+                .clamp(MIN_GENERATED_SIZE, MAX_MAP_SIZE)
+        };
 
-        if !self.is_position_valid(&position) {
-            return Err("Position invalide".to_string());
+        self.random_map_width = resize(self.random_map_width, dx);
+        self.random_map_height = resize(self.random_map_height, dy);
+    }
+
+    pub fn reroll_random_map_seed(&mut self) {
+        self.random_map_seed = rng().random_range(0..1_000_000);
+    }
+
+    /// Ajoute un chiffre à la fin de la graine pour pouvoir rejouer une carte connue
+    pub fn push_random_map_seed_digit(&mut self, digit: u32) {
+        self.random_map_seed = self
+            .random_map_seed
+            .checked_mul(10)
+            .and_then(|seed| seed.checked_add(digit as u64))
+            .unwrap_or(self.random_map_seed);
+    }
+
+    pub fn pop_random_map_seed_digit(&mut self) {
+        self.random_map_seed /= 10;
+    }
+
+    /// Affiche ou masque l'aide des touches
+    pub fn toggle_help(&mut self) {
+        self.show_help = !self.show_help;
+    }
+
+    /// Déclenche une action du menu de jeu sans passer par la liste des actions
+    pub fn trigger_action(&mut self, action: GameAction) {
+        if self.current_view != View::Game {
+            return;
         }
 
-        if self.game.spend_money(tower.cost) {
-            let mut new_tower = tower.clone();
-            new_tower.position = position;
-            self.game.towers.push(new_tower);
+        if let Some(index) = self.available_actions.iter().position(|a| *a == action) {
+            self.cancel_action();
+            self.ui_mode = UiMode::Normal;
+            self.selected_index = index;
+            self.confirm_selection();
+        }
+    }
 
-            self.game.add_log(format!(
-                "{} placed at [{}, {}]",
-                tower.name, position.x, position.y
-            ));
+    /// Passe à la vitesse de jeu suivante
+    pub fn cycle_game_speed(&mut self) {
+        let index = GAME_SPEEDS
+            .iter()
+            .position(|speed| *speed == self.game_speed)
+            .map_or(0, |i| (i + 1) % GAME_SPEEDS.len());
+
+        self.game_speed = GAME_SPEEDS[index];
+        self.game.add_log(
+            self.locale
+                .format("log.game_speed", &[("speed", &self.game_speed)]),
+        );
+    }
 
-            Ok(())
+    /// Tour visée par les raccourcis : tour sélectionnée sur la carte, en cours
+    /// d'amélioration, ou sous le curseur de placement
+    fn targeted_tower(&self) -> Option<&Tower> {
+        let id = if self.tower_selection_on_map {
+            self.selected_tower_id
         } else {
-            Err("Erreur lors de la construction".to_string())
+            match self.ui_mode {
+                UiMode::TowerUpgrade => self.upgrade_menu.as_ref().map(|menu| menu.tower),
+                UiMode::Placement => return self.game.tower_at(self.cursor_position),
+                _ => None,
+            }
+        };
+
+        self.game.towers.entity(id?)
+    }
+
+    /// Passe directement en placement de la tour correspondant au raccourci numérique
+    pub fn quick_build(&mut self, index: usize) {
+        let Some(tower) = self.available_towers.get(index).cloned() else {
+            return;
+        };
+
+        self.cancel_action();
+        self.ui_mode = UiMode::Placement;
+        self.selected_index = index;
+        self.clear_tower_selection();
+        self.upgrade_menu = None;
+        self.selected_tower = Some(tower);
+    }
+
+    /// Sélectionne la tour posée suivante (ou précédente) sur la carte, dans l'ordre de construction
+    pub fn cycle_placed_tower(&mut self, step: isize) {
+        let count = self.game.towers.len();
+        if count == 0 {
+            self.game
+                .add_log(self.locale.text("log.no_tower_to_select").to_string());
+            return;
         }
+
+        let current = self
+            .selected_tower_id
+            .filter(|_| self.tower_selection_on_map)
+            .and_then(|id| self.game.towers.position(id));
+        let index = match current {
+            Some(index) => (index as isize + step).rem_euclid(count as isize) as usize,
+            None if step < 0 => count - 1,
+            None => 0,
+        };
+
+        self.cancel_action();
+        self.ui_mode = UiMode::TowerSelection;
+        self.tower_selection_on_map = true;
+        self.select_tower_on_map(self.game.towers[index].id);
     }
 
-    pub fn remove_tower(&mut self, position: Position) {
-        self.game.remove_tower(position);
+    /// Vend la tour visée, ou passe en mode suppression si aucune tour n'est visée
+    pub fn sell_targeted_tower(&mut self) {
+        let Some(position) = self.targeted_tower().map(|tower| tower.position) else {
+            self.trigger_action(GameAction::RemoveTower);
+            return;
+        };
+
+        self.cancel_action();
+        self.ui_mode = UiMode::Normal;
+        self.remove_tower(position);
+    }
+
+    /// Améliore directement une caractéristique de la tour visée
+    pub fn upgrade_targeted_tower(&mut self, stat: TowerStatType) {
+        let Some(tower) = self.targeted_tower().map(|tower| tower.id) else {
+            self.game
+                .add_log(self.locale.text("log.no_tower_selected").to_string());
+            return;
+        };
+
+        // Rafraîchir les prix du menu d'amélioration ouvert
+        if self.execute(GameCommand::UpgradeTower { tower, stat })
+            && self.ui_mode == UiMode::TowerUpgrade
+        {
+            self.upgrade_tower(tower, None);
+        }
     }
 
-    pub fn upgrade_tower(&mut self, index: usize, keep_selection: Option<usize>) {
-        if index >= self.game.towers.len() {
+    /// Change la stratégie de ciblage de la tour visée
+    pub fn cycle_tower_targeting(&mut self) {
+        let Some(tower) = self.targeted_tower().map(|tower| tower.id) else {
             self.game
-                .add_log("❌ Tour non trouvée pour l'amélioration.".to_string());
+                .add_log(self.locale.text("log.no_tower_selected").to_string());
             return;
+        };
+
+        self.execute(GameCommand::CycleTargeting(tower));
+    }
+
+    /// Nouvelle partie configurée selon les paramètres du joueur
+    fn new_game(&self) -> Game {
+        let mut game = Game::new(
+            self.mediator.clone(),
+            vec![],
+            self.settings.starting_life,
+            self.settings.wave_multiplier,
+        );
+        game.money = self.settings.starting_money;
+        game.locale = self.locale;
+        // Les préférences du joueur sont appliquées par le médiateur
+        game.subscribe(Box::new(
+            NotificationSubscriber::new(self.mediator.clone(), self.locale)
+                .with_context(game.notification_context()),
+        ));
+        game.set_spawn_interval(self.settings.difficulty.preset().spawn_interval);
+        game.rules = self.run_rules();
+        game.mode = self.game_mode;
+        if game.mode == GameMode::Standard {
+            game.wave_limit = Some(STANDARD_WAVE_COUNT);
         }
+        game
+    }
+
+    pub fn selected_settings_item(&self) -> SettingsItem {
+        SettingsItem::ALL[self.selected_index.min(SettingsItem::ALL.len() - 1)]
+    }
+
+    /// Modifie le paramètre sélectionné puis enregistre les paramètres
+    pub fn adjust_setting(&mut self, step: i32) {
+        let item = self.selected_settings_item();
+        self.settings.adjust(item, step);
+
+        match item {
+            SettingsItem::DisplayMode => self.glyphs.mode = self.settings.display_mode,
+            SettingsItem::Language => {
+                self.locale = self.settings.locale;
+                self.game.locale = self.locale;
+            }
+            SettingsItem::Notifications => self
+                .mediator
+                .set_policy(self.settings.notification_policy()),
+            _ => {}
+        }
+
+        self.settings_error = self.settings.save(&settings_file(), self.locale).err();
+    }
+
+    /// Ouvre l'éditeur de cartes avec une carte vierge
+    pub fn open_map_editor(&mut self) {
+        self.map_editor = Some(MapEditor::new(self.locale));
+        self.set_view(View::MapEditor);
+    }
+
+    /// Ferme l'éditeur et revient au menu principal
+    pub fn close_map_editor(&mut self) {
+        self.map_editor = None;
+        self.set_view(View::MainMenu);
+    }
+
+    /// Valide et enregistre la carte de l'éditeur, puis la rend disponible à la sélection
+    pub fn save_edited_map(&mut self) {
+        let Some(editor) = &mut self.map_editor else {
+            return;
+        };
+
+        let Ok(map) = editor.validate() else {
+            return;
+        };
+
+        match MapFile::save(&map, &maps_dir(), self.locale) {
+            Ok(path) => {
+                editor.status = Some(Ok(self
+                    .locale
+                    .format("editor.saved", &[("path", &path.display())])));
+
+                self.available_maps.retain(|m| m.name != map.name);
+                self.available_maps.push(map);
+            }
+            Err(error) => editor.status = Some(Err(error)),
+        }
+    }
+
+    pub fn reset_game(&mut self) {
+        let map = ForestMap::new();
+
+        self.game = self.new_game();
+        self.game.current_map = Some(map);
+
+        self.ui_mode = UiMode::Normal;
+        self.selected_index = 0;
+        self.selected_tower = None;
+        self.upgrade_menu = None;
+        self.clear_tower_selection();
+    }
+
+    pub fn remove_tower(&mut self, position: Position) {
+        self.execute(GameCommand::SellTower(position));
+    }
+
+    pub fn upgrade_tower(&mut self, id: EntityId, keep_selection: Option<usize>) {
+        let Some(tower) = self.game.towers.entity(id) else {
+            self.game
+                .add_log(self.locale.text("log.no_tower_selected").to_string());
+            return;
+        };
 
         let selected_upgrade = if let Some(current_menu) = &self.upgrade_menu {
             keep_selection.unwrap_or(current_menu.selected_upgrade)
@@ -518,11 +1298,10 @@ impl App {
             keep_selection.unwrap_or(0)
         };
 
-        let tower = &self.game.towers[index].clone();
-        self.game
-            .add_log(format!("🔍 Tour {} (Niveau {})", tower.name, tower.level));
-
-        let tower = &self.game.towers[index];
+        let log = self.locale.format(
+            "log.tower_inspected",
+            &[("name", &tower.name), ("level", &tower.level)],
+        );
         let mut upgrades = vec![];
 
         let upgradeable_stats = tower
@@ -532,13 +1311,17 @@ impl App {
             .collect::<Vec<_>>();
 
         for element in upgradeable_stats {
-            if let Ok(format) = element.upgrade.as_ref().unwrap().format(element) {
-                upgrades.push((element.stat_type.clone(), format));
-            }
+            let format = element
+                .upgrade
+                .as_ref()
+                .unwrap()
+                .format(element, self.locale);
+            upgrades.push((element.stat_type.clone(), format));
         }
 
+        self.game.add_log(log);
         self.upgrade_menu = Some(UpgradeMenu {
-            tower_index: index,
+            tower: id,
             selected_upgrade,
             available_upgrades: upgrades,
         });
@@ -548,26 +1331,27 @@ impl App {
 
     pub fn apply_upgrade(&mut self) {
         if let Some(upgrade_menu) = &self.upgrade_menu {
-            let tower_index = upgrade_menu.tower_index;
+            let tower = upgrade_menu.tower;
             let current_selection = upgrade_menu.selected_upgrade;
 
             if current_selection < upgrade_menu.available_upgrades.len() {
                 let (upgrade_type, _) = &upgrade_menu.available_upgrades[current_selection];
 
-                let tower = &self.game.towers[tower_index];
-                let cost = tower.upgrade_cost_for_attribute(upgrade_type.clone());
-
-                if cost.is_none() {
-                    self.game
-                        .add_log(format!("❌ Cette amélioration est déjà au niveau maximum."));
-                    return;
-                }
-
-                if let Ok(_) = self.game.upgrade_tower(tower_index, upgrade_type.clone()) {
-                    if tower_index < self.game.towers.len() {
-                        self.upgrade_tower(tower_index, Some(current_selection));
+                let command = GameCommand::UpgradeTower {
+                    tower,
+                    stat: upgrade_type.clone(),
+                };
+                match self.game.execute(command) {
+                    Ok(_) => {
+                        self.upgrade_tower(tower, Some(current_selection));
                         return;
                     }
+                    // Le menu reste ouvert pour choisir une autre amélioration
+                    Err(error @ GameError::MaxLevel(_)) => {
+                        self.report_error(error);
+                        return;
+                    }
+                    Err(error) => self.report_error(error),
                 }
             }
         }
@@ -607,189 +1391,77 @@ impl App {
     pub fn start_tower_selection_on_map(&mut self) {
         if self.game.towers.is_empty() {
             self.game
-                .add_log("Aucune tour à sélectionner sur la carte.".to_string());
+                .add_log(self.locale.text("log.no_tower_to_select").to_string());
             return;
         }
 
         self.ui_mode = UiMode::TowerSelection;
         self.tower_selection_on_map = true;
-
-        self.selected_tower_index = Some(0);
-        if let Some(index) = self.selected_tower_index {
-            if index < self.game.towers.len() {
-                let tower = &self.game.towers[index];
-                self.cursor_position = tower.position;
-
-                let tower_type = tower.name.clone();
-                self.game.add_log(format!(
-                    "🔍 Tour {} (Niveau {}) sélectionnée",
-                    tower_type, tower.level
-                ));
-            }
-        }
+        self.select_tower_on_map(self.game.towers[0].id);
     }
 
-    pub fn select_tower_on_map_up(&mut self) {
-        if let Some(current_index) = self.selected_tower_index {
-            let current_pos = self.game.towers[current_index].position;
-
-            let mut closest_tower_index = None;
-            let mut min_distance = f32::MAX;
-
-            for (i, tower) in self.game.towers.iter().enumerate() {
-                let pos = tower.position;
-                if pos.y < current_pos.y {
-                    let dx = (pos.x - current_pos.x) as f32;
-                    let dy = (pos.y - current_pos.y) as f32;
-                    let distance = (dx * dx + dy * dy).sqrt();
-
-                    if distance < min_distance {
-                        min_distance = distance;
-                        closest_tower_index = Some(i);
-                    }
-                }
-            }
-
-            if let Some(index) = closest_tower_index {
-                self.selected_tower_index = Some(index);
-                self.cursor_position = self.game.towers[index].position;
+    /// Sélectionne la tour posée et place le curseur dessus
+    fn select_tower_on_map(&mut self, id: EntityId) {
+        let Some(tower) = self.game.towers.entity(id) else {
+            return;
+        };
 
-                let tower = &self.game.towers[index];
-                let tower_type = tower.name.clone();
+        let position = tower.position;
+        let log = self.locale.format(
+            "log.tower_selected",
+            &[("name", &tower.name), ("level", &tower.level)],
+        );
 
-                self.game.add_log(format!(
-                    "🔍 Tour {} (Niveau {}) sélectionnée",
-                    tower_type, tower.level
-                ));
-            }
-        }
+        self.selected_tower_id = Some(id);
+        self.cursor_position = position;
+        self.follow_cursor();
+        self.game.add_log(log);
     }
 
-    pub fn select_tower_on_map_down(&mut self) {
-        if let Some(current_index) = self.selected_tower_index {
-            let current_pos = self.game.towers[current_index].position;
-
-            let mut closest_tower_index = None;
-            let mut min_distance = f32::MAX;
-
-            for (i, tower) in self.game.towers.iter().enumerate() {
-                let pos = tower.position;
-                if pos.y > current_pos.y {
-                    let dx = (pos.x - current_pos.x) as f32;
-                    let dy = (pos.y - current_pos.y) as f32;
-                    let distance = (dx * dx + dy * dy).sqrt();
-
-                    if distance < min_distance {
-                        min_distance = distance;
-                        closest_tower_index = Some(i);
-                    }
-                }
-            }
-
-            if let Some(index) = closest_tower_index {
-                self.selected_tower_index = Some(index);
-                self.cursor_position = self.game.towers[index].position;
-
-                let tower = &self.game.towers[index];
-                let tower_type = tower.name.clone();
+    /// Sélectionne la tour la plus proche de la tour sélectionnée parmi celles situées
+    /// dans la direction voulue, d'après leur position par rapport à la sélection
+    fn select_closest_tower_on_map(&mut self, in_direction: impl Fn(Position, Position) -> bool) {
+        let Some(current) = self
+            .selected_tower_id
+            .and_then(|id| self.game.towers.entity(id))
+            .map(|tower| tower.position)
+        else {
+            return;
+        };
 
-                self.game.add_log(format!(
-                    "🔍 Tour {} (Niveau {}) sélectionnée",
-                    tower_type, tower.level
-                ));
-            }
+        let closest = self
+            .game
+            .towers
+            .iter()
+            .filter(|tower| in_direction(current, tower.position))
+            .min_by(|a, b| {
+                let distance = |position: Position| {
+                    let dx = (position.x - current.x) as f32;
+                    let dy = (position.y - current.y) as f32;
+                    (dx * dx + dy * dy).sqrt()
+                };
+                distance(a.position).total_cmp(&distance(b.position))
+            })
+            .map(|tower| tower.id);
+
+        if let Some(id) = closest {
+            self.select_tower_on_map(id);
         }
     }
 
-    pub fn select_tower_on_map_left(&mut self) {
-        if let Some(current_index) = self.selected_tower_index {
-            let current_pos = self.game.towers[current_index].position;
-
-            let mut closest_tower_index = None;
-            let mut min_distance = f32::MAX;
-
-            for (i, tower) in self.game.towers.iter().enumerate() {
-                let pos = tower.position;
-                if pos.x < current_pos.x {
-                    let dx = (pos.x - current_pos.x) as f32;
-                    let dy = (pos.y - current_pos.y) as f32;
-                    let distance = (dx * dx + dy * dy).sqrt();
-
-                    if distance < min_distance {
-                        min_distance = distance;
-                        closest_tower_index = Some(i);
-                    }
-                }
-            }
-
-            if let Some(index) = closest_tower_index {
-                self.selected_tower_index = Some(index);
-                self.cursor_position = self.game.towers[index].position;
-
-                let tower = &self.game.towers[index];
-                let tower_type = tower.name.clone();
-
-                self.game.add_log(format!(
-                    "🔍 Tour {} (Niveau {}) sélectionnée",
-                    tower_type, tower.level
-                ));
-            }
-        }
+    pub fn select_tower_on_map_up(&mut self) {
+        self.select_closest_tower_on_map(|current, pos| pos.y < current.y);
     }
 
-    pub fn select_tower_on_map_right(&mut self) {
-        if let Some(current_index) = self.selected_tower_index {
-            let current_pos = self.game.towers[current_index].position;
-
-            let mut closest_tower_index = None;
-            let mut min_distance = f32::MAX;
-
-            for (i, tower) in self.game.towers.iter().enumerate() {
-                let pos = tower.position;
-                if pos.x > current_pos.x {
-                    let dx = (pos.x - current_pos.x) as f32;
-                    let dy = (pos.y - current_pos.y) as f32;
-                    let distance = (dx * dx + dy * dy).sqrt();
-
-                    if distance < min_distance {
-                        min_distance = distance;
-                        closest_tower_index = Some(i);
-                    }
-                }
-            }
-
-            if let Some(index) = closest_tower_index {
-                self.selected_tower_index = Some(index);
-                self.cursor_position = self.game.towers[index].position;
-
-                let tower = &self.game.towers[index];
-                let tower_type = tower.name.clone();
-
-                self.game.add_log(format!(
-                    "🔍 Tour {} (Niveau {}) sélectionnée",
-                    tower_type, tower.level
-                ));
-            }
-        }
+    pub fn select_tower_on_map_down(&mut self) {
+        self.select_closest_tower_on_map(|current, pos| pos.y > current.y);
     }
 
-    fn is_position_valid(&self, position: &Position) -> bool {
-        if let Some(map) = &self.game.current_map {
-            if map.is_position_on_path(position) {
-                return false;
-            }
-        }
-
-        let tower = self
-            .game
-            .towers
-            .iter()
-            .any(|t| t.position.x == position.x && t.position.y == position.y);
-
-        if tower {
-            return false;
-        }
+    pub fn select_tower_on_map_left(&mut self) {
+        self.select_closest_tower_on_map(|current, pos| pos.x < current.x);
+    }
 
-        true
+    pub fn select_tower_on_map_right(&mut self) {
+        self.select_closest_tower_on_map(|current, pos| pos.x > current.x);
     }
 }
@@ -0,0 +1,96 @@
+use crate::domain::entities::{map::Map, position::Position};
+
+/// Nombre de cases gardées entre le curseur suivi et le bord de la vue
+const FOLLOW_MARGIN: i32 = 2;
+
+/// Caméra déterminant la partie de la carte visible à l'écran
+#[derive(Debug, Clone, Copy)]
+pub struct Camera {
+    /// Case de la carte affichée en haut à gauche de la vue
+    pub offset: Position,
+    /// Nombre de cases visibles horizontalement
+    pub viewport_width: i32,
+    /// Nombre de cases visibles verticalement
+    pub viewport_height: i32,
+}
+
+impl Default for Camera {
+    fn default() -> Self {
+        Self {
+            offset: Position::initial(),
+            viewport_width: 1,
+            viewport_height: 1,
+        }
+    }
+}
+
+impl Camera {
+    /// Met à jour la taille de la vue (en cases) puis recadre la caméra sur la carte
+    pub fn set_viewport(&mut self, width: u16, height: u16, map: &Map) {
+        self.viewport_width = (width as i32).max(1);
+        self.viewport_height = (height as i32).max(1);
+        self.clamp(map);
+    }
+
+    /// Indique si la carte entière tient dans la vue
+    pub fn fits(&self, map: &Map) -> bool {
+        (map.width as i32) <= self.viewport_width && (map.height as i32) <= self.viewport_height
+    }
+
+    /// Déplace la caméra d'un certain nombre de cases
+    pub fn scroll(&mut self, dx: i32, dy: i32, map: &Map) {
+        self.offset.x += dx;
+        self.offset.y += dy;
+        self.clamp(map);
+    }
+
+    /// Centre la caméra sur une position
+    pub fn center_on(&mut self, target: Position, map: &Map) {
+        self.offset = Position::new(
+            target.x - self.viewport_width / 2,
+            target.y - self.viewport_height / 2,
+        );
+        self.clamp(map);
+    }
+
+    /// Déplace la caméra juste assez pour que la position reste visible, avec une marge
+    pub fn follow(&mut self, target: Position, map: &Map) {
+        let margin_x = FOLLOW_MARGIN.min((self.viewport_width - 1) / 2);
+        let margin_y = FOLLOW_MARGIN.min((self.viewport_height - 1) / 2);
+
+        if target.x - margin_x < self.offset.x {
+            self.offset.x = target.x - margin_x;
+        } else if target.x + margin_x >= self.offset.x + self.viewport_width {
+            self.offset.x = target.x + margin_x - self.viewport_width + 1;
+        }
+
+        if target.y - margin_y < self.offset.y {
+            self.offset.y = target.y - margin_y;
+        } else if target.y + margin_y >= self.offset.y + self.viewport_height {
+            self.offset.y = target.y + margin_y - self.viewport_height + 1;
+        }
+
+        self.clamp(map);
+    }
+
+    /// Empêche la caméra de sortir de la carte
+    pub fn clamp(&mut self, map: &Map) {
+        let max_x = (map.width as i32 - self.viewport_width).max(0);
+        let max_y = (map.height as i32 - self.viewport_height).max(0);
+
+        self.offset.x = self.offset.x.clamp(0, max_x);
+        self.offset.y = self.offset.y.clamp(0, max_y);
+    }
+
+    /// Convertit une position de la carte en coordonnées de la vue, si elle est visible
+    pub fn to_view(&self, position: Position) -> Option<(usize, usize)> {
+        let x = position.x - self.offset.x;
+        let y = position.y - self.offset.y;
+
+        if x >= 0 && y >= 0 && x < self.viewport_width && y < self.viewport_height {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use crate::{
+    application::engine::monsters::basics::ROSTER,
+    domain::{
+        entities::{
+            map::{Decoration, MAX_MAP_SIZE, MIN_MAP_SIZE, Map},
+            position::Position,
+        },
+        i18n::Locale,
+    },
+};
+
+/// Symboles proposés pour le départ des monstres
+pub const START_SYMBOLS: [&str; 4] = ["🌵", "🚪", "🌀", "🐉"];
+/// Symboles proposés pour la base à défendre
+pub const END_SYMBOLS: [&str; 4] = ["🏠", "🏰", "🏯", "⛺"];
+/// Symboles proposés pour peindre le terrain
+pub const TERRAIN_SYMBOLS: [&str; 6] = ["🌲", "🌳", "🪨", "🌵", "💎", "🍄"];
+
+/// Outils disponibles dans l'éditeur de cartes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorTool {
+    /// Ajoute un waypoint à la fin du chemin
+    Path,
+    /// Peint un élément de décor
+    Terrain,
+    /// Efface le décor ou le waypoint sous le curseur
+    Erase,
+}
+
+impl EditorTool {
+    pub fn label(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            EditorTool::Path => "editor.tool.path",
+            EditorTool::Terrain => "editor.tool.terrain",
+            EditorTool::Erase => "editor.tool.erase",
+        })
+    }
+
+    fn next(&self) -> Self {
+        match self {
+            EditorTool::Path => EditorTool::Terrain,
+            EditorTool::Terrain => EditorTool::Erase,
+            EditorTool::Erase => EditorTool::Path,
+        }
+    }
+}
+
+/// État de l'éditeur de cartes
+pub struct MapEditor {
+    pub name: String,
+    pub editing_name: bool,
+    pub width: u32,
+    pub height: u32,
+    pub waypoints: Vec<Position>,
+    pub decorations: Vec<Decoration>,
+    pub cursor: Position,
+    pub tool: EditorTool,
+    pub terrain_symbol: usize,
+    pub start_symbol: usize,
+    pub end_symbol: usize,
+    /// Monstres activés, dans l'ordre de `ROSTER`
+    pub roster: Vec<bool>,
+    /// Dernier message affiché à l'utilisateur (`Err` pour une erreur)
+    pub status: Option<Result<String, String>>,
+    pub locale: Locale,
+}
+
+impl Default for MapEditor {
+    fn default() -> Self {
+        Self::new(Locale::default())
+    }
+}
+
+impl MapEditor {
+    /// Éditeur d'une carte vierge, dont les textes sont dans la langue donnée
+    pub fn new(locale: Locale) -> Self {
+        Self {
+            name: locale.text("editor.new_map").to_string(),
+            editing_name: false,
+            width: 20,
+            height: 20,
+            waypoints: Vec::new(),
+            decorations: Vec::new(),
+            cursor: Position::initial(),
+            tool: EditorTool::Path,
+            terrain_symbol: 0,
+            start_symbol: 0,
+            end_symbol: 0,
+            roster: vec![true; ROSTER.len()],
+            status: None,
+            locale,
+        }
+    }
+
+    /// Déplace le curseur en restant dans les limites de la carte
+    pub fn move_cursor(&mut self, dx: i32, dy: i32) {
+        self.cursor = Position::new(
+            (self.cursor.x + dx).clamp(0, self.width as i32 - 1),
+            (self.cursor.y + dy).clamp(0, self.height as i32 - 1),
+        );
+    }
+
+    /// Modifie les dimensions de la carte
+    pub fn resize(&mut self, dw: i32, dh: i32) {
+        let range = MIN_MAP_SIZE as i32..=MAX_MAP_SIZE as i32;
+        self.width = (self.width as i32 + dw).clamp(*range.start(), *range.end()) as u32;
+        self.height = (self.height as i32 + dh).clamp(*range.start(), *range.end()) as u32;
+
+        let map = self.to_map();
+        self.decorations.retain(|d| map.contains(&d.position));
+        self.move_cursor(0, 0);
+    }
+
+    pub fn cycle_tool(&mut self) {
+        self.tool = self.tool.next();
+    }
+
+    pub fn cycle_start_symbol(&mut self) {
+        self.start_symbol = (self.start_symbol + 1) % START_SYMBOLS.len();
+    }
+
+    pub fn cycle_end_symbol(&mut self) {
+        self.end_symbol = (self.end_symbol + 1) % END_SYMBOLS.len();
+    }
+
+    pub fn cycle_terrain_symbol(&mut self) {
+        self.terrain_symbol = (self.terrain_symbol + 1) % TERRAIN_SYMBOLS.len();
+    }
+
+    /// Active ou désactive un monstre de la liste
+    pub fn toggle_roster(&mut self, index: usize) {
+        if let Some(enabled) = self.roster.get_mut(index) {
+            *enabled = !*enabled;
+        }
+    }
+
+    /// Applique l'outil courant à la case sous le curseur
+    pub fn apply_tool(&mut self) {
+        let cursor = self.cursor;
+
+        match self.tool {
+            EditorTool::Path => {
+                let is_last = self
+                    .waypoints
+                    .last()
+                    .is_some_and(|wp| wp.x == cursor.x && wp.y == cursor.y);
+
+                if !is_last {
+                    self.decorations
+                        .retain(|d| !same_cell(&d.position, &cursor));
+                    self.waypoints.push(cursor);
+                }
+            }
+            EditorTool::Terrain => {
+                if self.to_map().is_position_on_path(&cursor) {
+                    self.status = Some(Err(self
+                        .locale
+                        .text("editor.cannot_paint_path")
+                        .to_string()));
+                    return;
+                }
+
+                self.decorations
+                    .retain(|d| !same_cell(&d.position, &cursor));
+                self.decorations.push(Decoration::new(
+                    cursor,
+                    TERRAIN_SYMBOLS[self.terrain_symbol].to_string(),
+                ));
+            }
+            EditorTool::Erase => {
+                self.decorations
+                    .retain(|d| !same_cell(&d.position, &cursor));
+                self.waypoints.retain(|wp| !same_cell(wp, &cursor));
+            }
+        }
+
+        self.status = None;
+    }
+
+    /// Retire le dernier waypoint placé
+    pub fn undo_waypoint(&mut self) {
+        self.waypoints.pop();
+        self.status = None;
+    }
+
+    /// Construit la carte correspondant à l'état de l'éditeur
+    pub fn to_map(&self) -> Map {
+        let start_position = self
+            .waypoints
+            .first()
+            .copied()
+            .unwrap_or(Position::initial());
+
+        let monsters = ROSTER
+            .iter()
+            .zip(&self.roster)
+            .filter(|(_, enabled)| **enabled)
+            .map(|((_, build), _)| build(start_position))
+            .collect();
+
+        Map::new(
+            self.name.clone(),
+            self.locale.format(
+                "editor.map_description",
+                &[("width", &self.width), ("height", &self.height)],
+            ),
+            START_SYMBOLS[self.start_symbol].to_string(),
+            END_SYMBOLS[self.end_symbol].to_string(),
+            self.waypoints.clone(),
+            self.width,
+            self.height,
+            monsters,
+            None,
+        )
+        .with_decorations(self.decorations.clone())
+    }
+
+    /// Valide la carte avec les mêmes règles que le chargement des cartes
+    pub fn validate(&mut self) -> Result<Map, String> {
+        let map = self.to_map();
+        let result = map.validate(self.locale).map(|_| map);
+
+        self.status = Some(match &result {
+            Ok(_) => Ok(self.locale.text("editor.map_valid").to_string()),
+            Err(error) => Err(error.clone()),
+        });
+
+        result
+    }
+}
+
+fn same_cell(a: &Position, b: &Position) -> bool {
+    a.x == b.x && a.y == b.y
+}
@@ -17,6 +17,10 @@ pub enum Event {
     Mouse(MouseEvent),
     /// Événement de redimensionnement du terminal
     Resize(u16, u16),
+    /// Le terminal a gagné le focus
+    FocusGained,
+    /// Le terminal a perdu le focus
+    FocusLost,
 }
 
 /// Configuration du gestionnaire d'événements
@@ -59,20 +63,30 @@ impl Events {
 
                 if event::poll(timeout).unwrap() {
                     match event::read().unwrap() {
-                        CrosstermEvent::Key(key) => {
-                            if thread_sender.send(Event::Key(key)).is_err() {
-                                break;
-                            }
+                        CrosstermEvent::Key(key)
+                            if thread_sender.send(Event::Key(key)).is_err() =>
+                        {
+                            break;
                         }
-                        CrosstermEvent::Mouse(mouse) => {
-                            if thread_sender.send(Event::Mouse(mouse)).is_err() {
-                                break;
-                            }
+                        CrosstermEvent::Mouse(mouse)
+                            if thread_sender.send(Event::Mouse(mouse)).is_err() =>
+                        {
+                            break;
                         }
-                        CrosstermEvent::Resize(width, height) => {
-                            if thread_sender.send(Event::Resize(width, height)).is_err() {
-                                break;
-                            }
+                        CrosstermEvent::Resize(width, height)
+                            if thread_sender.send(Event::Resize(width, height)).is_err() =>
+                        {
+                            break;
+                        }
+                        CrosstermEvent::FocusGained
+                            if thread_sender.send(Event::FocusGained).is_err() =>
+                        {
+                            break;
+                        }
+                        CrosstermEvent::FocusLost
+                            if thread_sender.send(Event::FocusLost).is_err() =>
+                        {
+                            break;
                         }
                         _ => {}
                     }
@@ -1,4 +1,72 @@
-use crate::infrastructure::ui::app::{App, UiMode, View};
+use crossterm::event::KeyCode;
+
+use crate::domain::entities::{command::GameCommand, tower::TowerStatType};
+use crate::infrastructure::ui::{
+    app::{App, GameAction, RunOption, UiMode, View},
+    keymap::{EditorAction, KeyAction},
+};
+
+/// Exécute l'action associée à une touche par le jeu de touches
+pub fn handle_key_action(app: &mut App, action: KeyAction) {
+    match action {
+        KeyAction::MoveUp => handle_key_up(app),
+        KeyAction::MoveDown => handle_key_down(app),
+        KeyAction::MoveLeft => handle_key_left(app),
+        KeyAction::MoveRight => handle_key_right(app),
+        KeyAction::Confirm => handle_key_enter(app),
+        KeyAction::Cancel => handle_key_esc(app),
+        KeyAction::Pause => handle_key_p(app),
+        KeyAction::Quit => handle_key_q(app),
+        KeyAction::Build => app.trigger_action(GameAction::BuildTower),
+        KeyAction::Sell => {
+            if app.current_view == View::Game {
+                app.sell_targeted_tower();
+            }
+        }
+        KeyAction::Upgrade => app.trigger_action(GameAction::UpgradeTower),
+        KeyAction::Speed => {
+            if app.current_view == View::Game {
+                app.cycle_game_speed();
+            }
+        }
+        KeyAction::NextWave => {
+            if app.current_view == View::Game && app.tutorial.is_some() {
+                app.finish_tutorial();
+            } else if app.current_view == View::Game {
+                app.execute(GameCommand::StartWave);
+            }
+        }
+        KeyAction::Targeting => {
+            if app.current_view == View::Game {
+                app.cycle_tower_targeting();
+            }
+        }
+        KeyAction::NextTower => handle_tower_cycle(app, 1),
+        KeyAction::PreviousTower => handle_tower_cycle(app, -1),
+        KeyAction::UpgradeRange => handle_stat_upgrade(app, TowerStatType::Range),
+        KeyAction::UpgradeDamage => handle_stat_upgrade(app, TowerStatType::Damage),
+        KeyAction::UpgradeSpeed => handle_stat_upgrade(app, TowerStatType::AttackSpeed),
+        KeyAction::UpgradeMoney => handle_stat_upgrade(app, TowerStatType::Money),
+        KeyAction::ScrollUp => handle_scroll(app, 0, -1),
+        KeyAction::ScrollDown => handle_scroll(app, 0, 1),
+        KeyAction::ScrollLeft => handle_scroll(app, -1, 0),
+        KeyAction::ScrollRight => handle_scroll(app, 1, 0),
+        KeyAction::CenterCamera => handle_key_c(app),
+        KeyAction::RerollSeed => handle_key_r(app),
+        KeyAction::Help => app.toggle_help(),
+    }
+}
+
+/// Ferme l'aide des touches, les autres touches sont ignorées tant qu'elle est affichée
+pub fn handle_help_key(app: &mut App, key: KeyCode) {
+    let action = app.keymap.action(key);
+    if matches!(
+        action,
+        Some(KeyAction::Help | KeyAction::Cancel | KeyAction::Quit)
+    ) {
+        app.toggle_help();
+    }
+}
 
 pub fn handle_key_up(app: &mut App) {
     if app.ui_mode == UiMode::Placement {
@@ -21,7 +89,17 @@ pub fn handle_key_down(app: &mut App) {
 }
 
 pub fn handle_key_left(app: &mut App) {
-    if app.ui_mode == UiMode::Placement {
+    if app.current_view == View::Help {
+        app.help_page = app.help_page.previous();
+    } else if app.current_view == View::Settings {
+        app.adjust_setting(-1);
+    } else if app.is_random_map_selected() {
+        app.random_map_difficulty = app.random_map_difficulty.previous();
+    } else if app.selected_run_option() == Some(RunOption::Mode) {
+        app.game_mode = app.game_mode.toggle();
+    } else if app.selected_run_option() == Some(RunOption::Difficulty) {
+        app.change_difficulty(-1);
+    } else if app.ui_mode == UiMode::Placement {
         app.move_cursor(-1, 0);
     } else if app.ui_mode == UiMode::TowerSelection && app.is_tower_selection_on_map() {
         app.select_tower_on_map_left();
@@ -29,7 +107,17 @@ pub fn handle_key_left(app: &mut App) {
 }
 
 pub fn handle_key_right(app: &mut App) {
-    if app.ui_mode == UiMode::Placement {
+    if app.current_view == View::Help {
+        app.help_page = app.help_page.next();
+    } else if app.current_view == View::Settings {
+        app.adjust_setting(1);
+    } else if app.is_random_map_selected() {
+        app.random_map_difficulty = app.random_map_difficulty.next();
+    } else if app.selected_run_option() == Some(RunOption::Mode) {
+        app.game_mode = app.game_mode.toggle();
+    } else if app.selected_run_option() == Some(RunOption::Difficulty) {
+        app.change_difficulty(1);
+    } else if app.ui_mode == UiMode::Placement {
         app.move_cursor(1, 0);
     } else if app.ui_mode == UiMode::TowerSelection && app.is_tower_selection_on_map() {
         app.select_tower_on_map_right();
@@ -41,7 +129,11 @@ pub fn handle_key_enter(app: &mut App) {
 }
 
 pub fn handle_key_esc(app: &mut App) {
-    if app.ui_mode == UiMode::Placement
+    if app.current_view == View::Settings || app.current_view == View::Campaign {
+        app.set_view(View::MainMenu);
+    } else if app.current_view == View::Help {
+        app.close_help();
+    } else if app.ui_mode == UiMode::Placement
         || app.ui_mode == UiMode::TowerSelection
         || app.ui_mode == UiMode::TowerUpgrade
     {
@@ -58,6 +150,8 @@ pub fn handle_key_p(app: &mut App) {
 pub fn handle_key_q(app: &mut App) {
     if app.current_view == View::Game {
         app.set_view(View::Pause);
+    } else if app.current_view == View::Help {
+        app.close_help();
     } else {
         app.quit();
     }
@@ -68,3 +162,108 @@ pub fn handle_key_t(app: &mut App) {
         app.start_tower_selection_on_map();
     }
 }
+
+pub fn handle_scroll(app: &mut App, dx: i32, dy: i32) {
+    if app.current_view == View::Game {
+        app.scroll_camera(dx, dy);
+    } else if app.is_random_map_selected() {
+        // Les touches de défilement règlent la taille de la carte générée
+        app.resize_random_map(dx * 2, -dy * 2);
+    }
+}
+
+pub fn handle_key_c(app: &mut App) {
+    if app.current_view == View::Game {
+        app.center_camera();
+    }
+}
+
+pub fn handle_key_r(app: &mut App) {
+    if app.is_random_map_selected() {
+        app.reroll_random_map_seed();
+    }
+}
+
+pub fn handle_tower_cycle(app: &mut App, step: isize) {
+    if app.current_view == View::Game {
+        app.cycle_placed_tower(step);
+    }
+}
+
+pub fn handle_stat_upgrade(app: &mut App, stat: TowerStatType) {
+    if app.current_view == View::Game {
+        app.upgrade_targeted_tower(stat);
+    }
+}
+
+pub fn handle_key_digit(app: &mut App, digit: u32) {
+    if app.is_random_map_selected() {
+        app.push_random_map_seed_digit(digit);
+    } else if app.current_view == View::Game && digit > 0 {
+        // 1 construit la première tour de la liste, 2 la deuxième...
+        app.quick_build(digit as usize - 1);
+    }
+}
+
+pub fn handle_key_backspace(app: &mut App) {
+    if app.is_random_map_selected() {
+        app.pop_random_map_seed_digit();
+    }
+}
+
+/// Gère les touches de l'éditeur de cartes
+pub fn handle_editor_key(app: &mut App, key: KeyCode) {
+    let action = app.keymap.action(key);
+    let editor_action = app.keymap.editor_action(key);
+    let Some(editor) = &mut app.map_editor else {
+        return;
+    };
+
+    if editor.editing_name {
+        // Les caractères sont saisis tels quels, seules les autres touches passent par le jeu de touches
+        match key {
+            KeyCode::Char(c) => editor.name.push(c),
+            KeyCode::Backspace => {
+                editor.name.pop();
+            }
+            _ if matches!(action, Some(KeyAction::Confirm | KeyAction::Cancel)) => {
+                editor.editing_name = false
+            }
+            _ => {}
+        }
+        return;
+    }
+
+    if let Some(editor_action) = editor_action {
+        match editor_action {
+            EditorAction::NextTool => editor.cycle_tool(),
+            EditorAction::UndoWaypoint => editor.undo_waypoint(),
+            EditorAction::Wider => editor.resize(1, 0),
+            EditorAction::Narrower => editor.resize(-1, 0),
+            EditorAction::Taller => editor.resize(0, 1),
+            EditorAction::Shorter => editor.resize(0, -1),
+            EditorAction::StartSymbol => editor.cycle_start_symbol(),
+            EditorAction::EndSymbol => editor.cycle_end_symbol(),
+            EditorAction::TerrainSymbol => editor.cycle_terrain_symbol(),
+            EditorAction::Rename => editor.editing_name = true,
+            EditorAction::Validate => {
+                let _ = editor.validate();
+            }
+            EditorAction::Save => app.save_edited_map(),
+        }
+        return;
+    }
+
+    match (action, key) {
+        (Some(KeyAction::MoveUp), _) => editor.move_cursor(0, -1),
+        (Some(KeyAction::MoveDown), _) => editor.move_cursor(0, 1),
+        (Some(KeyAction::MoveLeft), _) => editor.move_cursor(-1, 0),
+        (Some(KeyAction::MoveRight), _) => editor.move_cursor(1, 0),
+        (Some(KeyAction::Confirm), _) => editor.apply_tool(),
+        (Some(KeyAction::Cancel), _) => app.close_map_editor(),
+        (None, KeyCode::Char(c)) if ('1'..='9').contains(&c) => {
+            editor.toggle_roster(c.to_digit(10).unwrap() as usize - 1)
+        }
+        _ => {}
+    }
+}
@@ -0,0 +1,182 @@
+use std::{borrow::Cow, collections::HashMap, str::FromStr};
+
+use ratatui::style::Color;
+
+use crate::domain::i18n::Locale;
+
+/// Clé du symbole de départ des monstres, quel que soit l'emoji de la carte
+pub const START_KEY: &str = "start";
+/// Clé du symbole de la base à défendre, quel que soit l'emoji de la carte
+pub const END_KEY: &str = "end";
+
+/// Mode d'affichage des symboles de la carte
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DisplayMode {
+    /// Symboles emoji d'origine
+    Emoji,
+    /// Un caractère ASCII coloré par symbole, pour les terminaux qui affichent mal les emoji
+    Ascii,
+}
+
+impl DisplayMode {
+    pub fn label(&self) -> &str {
+        match self {
+            DisplayMode::Emoji => "Emoji",
+            DisplayMode::Ascii => "ASCII",
+        }
+    }
+
+    pub fn toggle(&self) -> Self {
+        match self {
+            DisplayMode::Emoji => DisplayMode::Ascii,
+            DisplayMode::Ascii => DisplayMode::Emoji,
+        }
+    }
+}
+
+impl DisplayMode {
+    /// Mode correspondant à un nom du fichier de paramètres ou de la ligne de commande
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "emoji" => Some(DisplayMode::Emoji),
+            "ascii" => Some(DisplayMode::Ascii),
+            _ => None,
+        }
+    }
+}
+
+/// Caractère de remplacement d'un symbole en mode ASCII
+#[derive(Debug, Clone)]
+pub struct Glyph {
+    /// Contenu d'une case de la carte (le caractère suivi d'un espace)
+    pub cell: String,
+    pub color: Color,
+}
+
+impl Glyph {
+    pub fn new(glyph: char, color: Color) -> Self {
+        Self {
+            cell: format!("{} ", glyph),
+            color,
+        }
+    }
+}
+
+/// Table de correspondance entre les symboles emoji et leur remplacement ASCII
+pub struct Glyphs {
+    pub mode: DisplayMode,
+    table: HashMap<String, Glyph>,
+    fallback: Glyph,
+}
+
+impl Glyphs {
+    pub fn new(mode: DisplayMode) -> Self {
+        let defaults = [
+            // Départ et arrivée
+            (START_KEY, '>', Color::Green),
+            (END_KEY, '#', Color::LightBlue),
+            // Tours
+            ("🔥", 'F', Color::LightRed),
+            ("🔭", 'S', Color::Cyan),
+            ("💰", '$', Color::Yellow),
+            // Monstres
+            ("😈", 'g', Color::LightGreen),
+            ("👹", 'O', Color::LightMagenta),
+            // Décors
+            ("🌲", '^', Color::Green),
+            ("🌳", 'T', Color::Green),
+            ("🍄", 'm', Color::Red),
+            ("🌵", 'Y', Color::LightGreen),
+            ("🪨", 'o', Color::Gray),
+            ("🦴", '~', Color::White),
+            ("💎", '*', Color::LightCyan),
+            ("🦇", 'v', Color::DarkGray),
+        ];
+
+        let table = defaults
+            .into_iter()
+            .map(|(key, glyph, color)| (key.to_string(), Glyph::new(glyph, color)))
+            .collect();
+
+        Self {
+            mode,
+            table,
+            fallback: Glyph::new('?', Color::White),
+        }
+    }
+
+    /// Applique des remplacements personnalisés, une ligne `symbole = caractère couleur` par entrée
+    ///
+    /// ```text
+    /// 🔥 = f red
+    /// start = S #00ff00
+    /// ```
+    pub fn load_overrides(&mut self, content: &str, locale: Locale) -> Result<(), String> {
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| locale.format("config.invalid_line", &[("line", &line)]))?;
+
+            let mut parts = value.split_whitespace();
+            let glyph = parts
+                .next()
+                .and_then(|glyph| glyph.chars().next())
+                .ok_or_else(|| locale.format("glyphs.missing_glyph", &[("line", &line)]))?;
+            let color = match parts.next() {
+                Some(color) => Color::from_str(color)
+                    .map_err(|_| locale.format("glyphs.invalid_color", &[("color", &color)]))?,
+                None => Color::White,
+            };
+
+            self.table
+                .insert(key.trim().to_string(), Glyph::new(glyph, color));
+        }
+
+        Ok(())
+    }
+
+    /// Contenu d'une case de carte pour un symbole, avec la couleur à appliquer en mode ASCII
+    pub fn cell<'a>(&'a self, symbol: &'a str) -> (&'a str, Option<Color>) {
+        self.cell_for(symbol, symbol)
+    }
+
+    /// Comme `cell`, mais en cherchant le remplacement ASCII sous une clé dédiée
+    pub fn cell_for<'a>(&'a self, key: &str, symbol: &'a str) -> (&'a str, Option<Color>) {
+        match self.mode {
+            DisplayMode::Emoji => (symbol, None),
+            DisplayMode::Ascii => {
+                let glyph = self.table.get(key).unwrap_or(&self.fallback);
+                (&glyph.cell, Some(glyph.color))
+            }
+        }
+    }
+
+    /// Retire les emoji d'un texte en mode ASCII pour garder l'alignement des colonnes
+    pub fn text<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        match self.mode {
+            DisplayMode::Emoji => Cow::Borrowed(text),
+            DisplayMode::Ascii => Cow::Owned(
+                text.chars()
+                    .filter(|c| !is_emoji(*c))
+                    .collect::<String>()
+                    .trim()
+                    .to_string(),
+            ),
+        }
+    }
+}
+
+/// Approximation des caractères affichés en emoji (pictogrammes et sélecteurs de variante)
+fn is_emoji(c: char) -> bool {
+    let code = c as u32;
+    (0x2300..=0x23FF).contains(&code)
+        || (0x2600..=0x27BF).contains(&code)
+        || (0x1F000..=0x1FAFF).contains(&code)
+        || code == 0xFE0F
+        || code == 0x200D
+}
@@ -0,0 +1,130 @@
+use crate::domain::{
+    entities::game::Game,
+    i18n::{Arg, Locale},
+};
+
+use super::keymap::{KeyAction, Keymap};
+
+/// Pages de l'écran d'aide
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HelpPage {
+    Controls,
+    Elements,
+    Towers,
+    Monsters,
+}
+
+impl HelpPage {
+    pub const ALL: [HelpPage; 4] = [
+        HelpPage::Controls,
+        HelpPage::Elements,
+        HelpPage::Towers,
+        HelpPage::Monsters,
+    ];
+
+    pub fn label(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            HelpPage::Controls => "help.controls",
+            HelpPage::Elements => "help.elements",
+            HelpPage::Towers => "help.towers",
+            HelpPage::Monsters => "help.monsters",
+        })
+    }
+
+    pub fn index(&self) -> usize {
+        HelpPage::ALL
+            .iter()
+            .position(|page| page == self)
+            .unwrap_or(0)
+    }
+
+    pub fn next(&self) -> Self {
+        HelpPage::ALL[(self.index() + 1) % HelpPage::ALL.len()]
+    }
+
+    pub fn previous(&self) -> Self {
+        HelpPage::ALL[(self.index() + HelpPage::ALL.len() - 1) % HelpPage::ALL.len()]
+    }
+}
+
+/// Étapes du tutoriel guidé
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TutorialStep {
+    PlaceTower,
+    UpgradeTower,
+    SellTower,
+}
+
+/// Tutoriel de la première carte : le jeu reste en pause tant qu'il n'est pas terminé
+pub struct Tutorial {
+    pub step: TutorialStep,
+    /// Valeur de référence relevée au début de l'étape, pour détecter l'action attendue
+    baseline: usize,
+}
+
+impl Tutorial {
+    pub fn new(game: &Game) -> Self {
+        Self {
+            step: TutorialStep::PlaceTower,
+            baseline: game.towers.len(),
+        }
+    }
+
+    /// Passe à l'étape suivante lorsque le joueur a fait l'action demandée
+    ///
+    /// Retourne `true` lorsque le tutoriel est terminé.
+    pub fn update(&mut self, game: &Game) -> bool {
+        match self.step {
+            TutorialStep::PlaceTower if game.towers.len() > self.baseline => {
+                self.step = TutorialStep::UpgradeTower;
+                self.baseline = upgrade_levels(game);
+            }
+            TutorialStep::UpgradeTower if upgrade_levels(game) > self.baseline => {
+                self.step = TutorialStep::SellTower;
+                self.baseline = game.towers.len();
+            }
+            TutorialStep::SellTower if game.towers.len() < self.baseline => return true,
+            _ => {}
+        }
+
+        false
+    }
+
+    /// Consignes de l'étape courante, avec les touches configurées
+    pub fn instructions(&self, keymap: &Keymap, locale: Locale) -> Vec<String> {
+        let confirm = keymap.label(KeyAction::Confirm, locale);
+        let next_tower = keymap.label(KeyAction::NextTower, locale);
+        let sell = keymap.label(KeyAction::Sell, locale);
+        let build = locale.text("action.build");
+
+        let (title, hint) = match self.step {
+            TutorialStep::PlaceTower => ("tutorial.place.title", "tutorial.place.hint"),
+            TutorialStep::UpgradeTower => ("tutorial.upgrade.title", "tutorial.upgrade.hint"),
+            TutorialStep::SellTower => ("tutorial.sell.title", "tutorial.sell.hint"),
+        };
+        let args: [Arg; 4] = [
+            ("confirm", &confirm),
+            ("next_tower", &next_tower),
+            ("sell", &sell),
+            ("build", &build),
+        ];
+
+        vec![
+            locale.text(title).to_string(),
+            locale.format(hint, &args),
+            locale.format(
+                "tutorial.paused",
+                &[("skip", &keymap.label(KeyAction::NextWave, locale))],
+            ),
+        ]
+    }
+}
+
+/// Somme des niveaux des caractéristiques de toutes les tours
+fn upgrade_levels(game: &Game) -> usize {
+    game.towers
+        .iter()
+        .flat_map(|tower| &tower.stats)
+        .map(|stat| stat.level as usize)
+        .sum()
+}
@@ -0,0 +1,510 @@
+use std::collections::HashMap;
+
+use crossterm::event::KeyCode;
+
+use crate::domain::i18n::Locale;
+
+/// Actions du jeu pouvant être associées à une touche
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyAction {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Confirm,
+    Cancel,
+    Pause,
+    Quit,
+    Build,
+    Sell,
+    Upgrade,
+    Speed,
+    NextWave,
+    Targeting,
+    NextTower,
+    PreviousTower,
+    UpgradeRange,
+    UpgradeDamage,
+    UpgradeSpeed,
+    UpgradeMoney,
+    ScrollUp,
+    ScrollDown,
+    ScrollLeft,
+    ScrollRight,
+    CenterCamera,
+    RerollSeed,
+    Help,
+}
+
+impl KeyAction {
+    /// Toutes les actions, dans l'ordre d'affichage de l'aide
+    pub const ALL: [KeyAction; 27] = [
+        KeyAction::MoveUp,
+        KeyAction::MoveDown,
+        KeyAction::MoveLeft,
+        KeyAction::MoveRight,
+        KeyAction::Confirm,
+        KeyAction::Cancel,
+        KeyAction::Pause,
+        KeyAction::Quit,
+        KeyAction::Build,
+        KeyAction::Sell,
+        KeyAction::Upgrade,
+        KeyAction::Speed,
+        KeyAction::NextWave,
+        KeyAction::Targeting,
+        KeyAction::NextTower,
+        KeyAction::PreviousTower,
+        KeyAction::UpgradeRange,
+        KeyAction::UpgradeDamage,
+        KeyAction::UpgradeSpeed,
+        KeyAction::UpgradeMoney,
+        KeyAction::ScrollUp,
+        KeyAction::ScrollDown,
+        KeyAction::ScrollLeft,
+        KeyAction::ScrollRight,
+        KeyAction::CenterCamera,
+        KeyAction::RerollSeed,
+        KeyAction::Help,
+    ];
+
+    /// Actions communes au jeu et à l'éditeur de cartes
+    pub const EDITOR_SHARED: [KeyAction; 6] = [
+        KeyAction::MoveUp,
+        KeyAction::MoveDown,
+        KeyAction::MoveLeft,
+        KeyAction::MoveRight,
+        KeyAction::Confirm,
+        KeyAction::Cancel,
+    ];
+
+    /// Nom de l'action dans le fichier de configuration
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyAction::MoveUp => "move_up",
+            KeyAction::MoveDown => "move_down",
+            KeyAction::MoveLeft => "move_left",
+            KeyAction::MoveRight => "move_right",
+            KeyAction::Confirm => "confirm",
+            KeyAction::Cancel => "cancel",
+            KeyAction::Pause => "pause",
+            KeyAction::Quit => "quit",
+            KeyAction::Build => "build",
+            KeyAction::Sell => "sell",
+            KeyAction::Upgrade => "upgrade",
+            KeyAction::Speed => "speed",
+            KeyAction::NextWave => "next_wave",
+            KeyAction::Targeting => "targeting",
+            KeyAction::NextTower => "next_tower",
+            KeyAction::PreviousTower => "previous_tower",
+            KeyAction::UpgradeRange => "upgrade_range",
+            KeyAction::UpgradeDamage => "upgrade_damage",
+            KeyAction::UpgradeSpeed => "upgrade_speed",
+            KeyAction::UpgradeMoney => "upgrade_money",
+            KeyAction::ScrollUp => "scroll_up",
+            KeyAction::ScrollDown => "scroll_down",
+            KeyAction::ScrollLeft => "scroll_left",
+            KeyAction::ScrollRight => "scroll_right",
+            KeyAction::CenterCamera => "center_camera",
+            KeyAction::RerollSeed => "reroll_seed",
+            KeyAction::Help => "help",
+        }
+    }
+
+    /// Description affichée dans l'aide
+    pub fn label(&self, locale: Locale) -> String {
+        locale.text(&format!("key.{}", self.name())).to_string()
+    }
+
+    /// Action correspondant à un nom du fichier de configuration
+    pub fn from_name(name: &str) -> Option<Self> {
+        KeyAction::ALL
+            .into_iter()
+            .find(|action| action.name() == name.trim())
+    }
+}
+
+/// Actions propres à l'éditeur de cartes, en plus des déplacements, de la confirmation
+/// et de l'annulation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EditorAction {
+    NextTool,
+    UndoWaypoint,
+    Wider,
+    Narrower,
+    Taller,
+    Shorter,
+    StartSymbol,
+    EndSymbol,
+    TerrainSymbol,
+    Rename,
+    Validate,
+    Save,
+}
+
+impl EditorAction {
+    pub const ALL: [EditorAction; 12] = [
+        EditorAction::NextTool,
+        EditorAction::UndoWaypoint,
+        EditorAction::Wider,
+        EditorAction::Narrower,
+        EditorAction::Taller,
+        EditorAction::Shorter,
+        EditorAction::StartSymbol,
+        EditorAction::EndSymbol,
+        EditorAction::TerrainSymbol,
+        EditorAction::Rename,
+        EditorAction::Validate,
+        EditorAction::Save,
+    ];
+
+    /// Nom de l'action dans le fichier de configuration
+    pub fn name(&self) -> &'static str {
+        match self {
+            EditorAction::NextTool => "editor_next_tool",
+            EditorAction::UndoWaypoint => "editor_undo_waypoint",
+            EditorAction::Wider => "editor_wider",
+            EditorAction::Narrower => "editor_narrower",
+            EditorAction::Taller => "editor_taller",
+            EditorAction::Shorter => "editor_shorter",
+            EditorAction::StartSymbol => "editor_start_symbol",
+            EditorAction::EndSymbol => "editor_end_symbol",
+            EditorAction::TerrainSymbol => "editor_terrain_symbol",
+            EditorAction::Rename => "editor_rename",
+            EditorAction::Validate => "editor_validate",
+            EditorAction::Save => "editor_save",
+        }
+    }
+
+    /// Touche associée par défaut, quel que soit le préréglage
+    fn default_key(&self) -> KeyCode {
+        match self {
+            EditorAction::NextTool => KeyCode::Tab,
+            EditorAction::UndoWaypoint => KeyCode::Backspace,
+            EditorAction::Wider => KeyCode::Char('+'),
+            EditorAction::Narrower => KeyCode::Char('-'),
+            EditorAction::Taller => KeyCode::Char('>'),
+            EditorAction::Shorter => KeyCode::Char('<'),
+            EditorAction::StartSymbol => KeyCode::Char('d'),
+            EditorAction::EndSymbol => KeyCode::Char('f'),
+            EditorAction::TerrainSymbol => KeyCode::Char('t'),
+            EditorAction::Rename => KeyCode::Char('n'),
+            EditorAction::Validate => KeyCode::Char('v'),
+            EditorAction::Save => KeyCode::Char('s'),
+        }
+    }
+
+    /// Action correspondant à un nom du fichier de configuration
+    pub fn from_name(name: &str) -> Option<Self> {
+        EditorAction::ALL
+            .into_iter()
+            .find(|action| action.name() == name.trim())
+    }
+}
+
+/// Jeux de touches prédéfinis
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeymapPreset {
+    /// Flèches pour se déplacer, WASD pour faire défiler la carte
+    Default,
+    /// hjkl pour se déplacer, HJKL pour faire défiler la carte
+    Vim,
+}
+
+impl KeymapPreset {
+    /// Préréglage correspondant à un nom du fichier de configuration
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "default" => Some(KeymapPreset::Default),
+            "vim" => Some(KeymapPreset::Vim),
+            _ => None,
+        }
+    }
+}
+
+/// Association entre les actions du jeu et les touches du clavier
+#[derive(Debug, Clone)]
+pub struct Keymap {
+    bindings: HashMap<KeyAction, Vec<KeyCode>>,
+    editor_bindings: HashMap<EditorAction, Vec<KeyCode>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::preset(KeymapPreset::Default)
+    }
+}
+
+impl Keymap {
+    pub fn preset(preset: KeymapPreset) -> Self {
+        let (moves, scrolls) = match preset {
+            KeymapPreset::Default => (
+                [
+                    vec![KeyCode::Up],
+                    vec![KeyCode::Down],
+                    vec![KeyCode::Left],
+                    vec![KeyCode::Right],
+                ],
+                [
+                    KeyCode::Char('w'),
+                    KeyCode::Char('s'),
+                    KeyCode::Char('a'),
+                    KeyCode::Char('d'),
+                ],
+            ),
+            KeymapPreset::Vim => (
+                [
+                    vec![KeyCode::Char('k'), KeyCode::Up],
+                    vec![KeyCode::Char('j'), KeyCode::Down],
+                    vec![KeyCode::Char('h'), KeyCode::Left],
+                    vec![KeyCode::Char('l'), KeyCode::Right],
+                ],
+                [
+                    KeyCode::Char('K'),
+                    KeyCode::Char('J'),
+                    KeyCode::Char('H'),
+                    KeyCode::Char('L'),
+                ],
+            ),
+        };
+        let [up, down, left, right] = moves;
+        let [scroll_up, scroll_down, scroll_left, scroll_right] = scrolls;
+
+        let bindings = [
+            (KeyAction::MoveUp, up),
+            (KeyAction::MoveDown, down),
+            (KeyAction::MoveLeft, left),
+            (KeyAction::MoveRight, right),
+            (KeyAction::Confirm, vec![KeyCode::Enter]),
+            (KeyAction::Cancel, vec![KeyCode::Esc]),
+            (KeyAction::Pause, vec![KeyCode::Char('p')]),
+            (KeyAction::Quit, vec![KeyCode::Char('q')]),
+            (KeyAction::Build, vec![KeyCode::Char('b')]),
+            (KeyAction::Sell, vec![KeyCode::Char('x')]),
+            (KeyAction::Upgrade, vec![KeyCode::Char('u')]),
+            (KeyAction::Speed, vec![KeyCode::Char('f')]),
+            (KeyAction::NextWave, vec![KeyCode::Char('n')]),
+            (KeyAction::Targeting, vec![KeyCode::Char('t')]),
+            (KeyAction::NextTower, vec![KeyCode::Tab]),
+            (KeyAction::PreviousTower, vec![KeyCode::BackTab]),
+            (KeyAction::UpgradeRange, vec![KeyCode::Char('P')]),
+            (KeyAction::UpgradeDamage, vec![KeyCode::Char('D')]),
+            (KeyAction::UpgradeSpeed, vec![KeyCode::Char('V')]),
+            (KeyAction::UpgradeMoney, vec![KeyCode::Char('G')]),
+            (KeyAction::ScrollUp, vec![scroll_up]),
+            (KeyAction::ScrollDown, vec![scroll_down]),
+            (KeyAction::ScrollLeft, vec![scroll_left]),
+            (KeyAction::ScrollRight, vec![scroll_right]),
+            (KeyAction::CenterCamera, vec![KeyCode::Char('c')]),
+            (KeyAction::RerollSeed, vec![KeyCode::Char('r')]),
+            (KeyAction::Help, vec![KeyCode::Char('?')]),
+        ];
+
+        Self {
+            bindings: bindings.into_iter().collect(),
+            editor_bindings: EditorAction::ALL
+                .into_iter()
+                .map(|action| (action, vec![action.default_key()]))
+                .collect(),
+        }
+    }
+
+    /// Construit un jeu de touches à partir d'un fichier de configuration
+    ///
+    /// La ligne `preset` choisit la base, les autres lignes remplacent les touches d'une action :
+    ///
+    /// ```text
+    /// preset = vim
+    /// build = b, F1
+    /// pause = space
+    /// editor_save = F2
+    /// ```
+    ///
+    /// Les erreurs sont rédigées dans la langue demandée.
+    pub fn parse(content: &str, locale: Locale) -> Result<Self, String> {
+        let mut preset = KeymapPreset::Default;
+        let mut overrides = Vec::new();
+        let mut editor_overrides = Vec::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| locale.format("config.invalid_line", &[("line", &line)]))?;
+
+            if key.trim() == "preset" {
+                preset = KeymapPreset::from_name(value).ok_or_else(|| {
+                    locale.format("keymap.unknown_preset", &[("preset", &value.trim())])
+                })?;
+                continue;
+            }
+
+            let keys = value
+                .split(',')
+                .map(|key| parse_key(key, locale))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            if let Some(action) = KeyAction::from_name(key) {
+                overrides.push((action, keys));
+            } else if let Some(action) = EditorAction::from_name(key) {
+                editor_overrides.push((action, keys));
+            } else {
+                return Err(locale.format("keymap.unknown_action", &[("action", &key.trim())]));
+            }
+        }
+
+        let mut keymap = Self::preset(preset);
+        keymap.bindings.extend(overrides);
+        keymap.editor_bindings.extend(editor_overrides);
+
+        if let Some((key, first, second)) = keymap.conflicts().first() {
+            return Err(locale.format(
+                "keymap.conflict",
+                &[
+                    ("key", &key_label(key, locale)),
+                    ("first", first),
+                    ("second", second),
+                ],
+            ));
+        }
+
+        Ok(keymap)
+    }
+
+    /// Action associée à une touche
+    pub fn action(&self, key: KeyCode) -> Option<KeyAction> {
+        KeyAction::ALL
+            .into_iter()
+            .find(|action| self.keys(*action).contains(&key))
+    }
+
+    pub fn keys(&self, action: KeyAction) -> &[KeyCode] {
+        self.bindings
+            .get(&action)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Action de l'éditeur de cartes associée à une touche
+    pub fn editor_action(&self, key: KeyCode) -> Option<EditorAction> {
+        EditorAction::ALL
+            .into_iter()
+            .find(|action| self.editor_keys(*action).contains(&key))
+    }
+
+    pub fn editor_keys(&self, action: EditorAction) -> &[KeyCode] {
+        self.editor_bindings
+            .get(&action)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Touches d'une action, telles qu'affichées à l'écran (ex: "k/↑")
+    pub fn label(&self, action: KeyAction, locale: Locale) -> String {
+        keys_label(self.keys(action), locale)
+    }
+
+    /// Touches d'une action de l'éditeur, telles qu'affichées à l'écran
+    pub fn editor_label(&self, action: EditorAction, locale: Locale) -> String {
+        keys_label(self.editor_keys(action), locale)
+    }
+
+    /// Touches associées à plusieurs actions d'un même écran, avec le nom des deux actions
+    pub fn conflicts(&self) -> Vec<(KeyCode, &'static str, &'static str)> {
+        let game = KeyAction::ALL.map(|action| (action.name(), self.keys(action)));
+        let shared = KeyAction::EDITOR_SHARED.map(|action| (action.name(), self.keys(action)));
+        let editor = EditorAction::ALL.map(|action| (action.name(), self.editor_keys(action)));
+
+        let mut conflicts = Vec::new();
+        let mut check = |first: &(&'static str, &[KeyCode]),
+                         others: &[(&'static str, &[KeyCode])]| {
+            for (name, keys) in others {
+                for key in first.1 {
+                    if keys.contains(key) {
+                        conflicts.push((*key, first.0, *name));
+                    }
+                }
+            }
+        };
+
+        for (i, first) in game.iter().enumerate() {
+            check(first, &game[i + 1..]);
+        }
+
+        // Les actions de l'éditeur ne sont comparées qu'à celles disponibles dans l'éditeur
+        for (i, first) in editor.iter().enumerate() {
+            check(first, &shared);
+            check(first, &editor[i + 1..]);
+        }
+
+        conflicts
+    }
+}
+
+/// Lit le nom d'une touche (`a`, `enter`, `space`, `f1`...)
+fn parse_key(value: &str, locale: Locale) -> Result<KeyCode, String> {
+    let value = value.trim();
+    let mut chars = value.chars();
+
+    if let (Some(c), None) = (chars.next(), chars.next()) {
+        return Ok(KeyCode::Char(c));
+    }
+
+    let key = match value.to_lowercase().as_str() {
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "enter" => KeyCode::Enter,
+        "esc" | "escape" => KeyCode::Esc,
+        "tab" => KeyCode::Tab,
+        "backtab" | "shift+tab" => KeyCode::BackTab,
+        "backspace" => KeyCode::Backspace,
+        "space" => KeyCode::Char(' '),
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "delete" => KeyCode::Delete,
+        other => match other.strip_prefix('f').and_then(|n| n.parse().ok()) {
+            Some(n) if (1..=12).contains(&n) => KeyCode::F(n),
+            _ => return Err(locale.format("keymap.unknown_key", &[("key", &value)])),
+        },
+    };
+
+    Ok(key)
+}
+
+fn keys_label(keys: &[KeyCode], locale: Locale) -> String {
+    if keys.is_empty() {
+        return "-".to_string();
+    }
+
+    keys.iter()
+        .map(|key| key_label(key, locale))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Nom court d'une touche pour l'affichage
+pub fn key_label(key: &KeyCode, locale: Locale) -> String {
+    match key {
+        KeyCode::Up => "↑".to_string(),
+        KeyCode::Down => "↓".to_string(),
+        KeyCode::Left => "←".to_string(),
+        KeyCode::Right => "→".to_string(),
+        KeyCode::Enter => locale.text("key_name.enter").to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::BackTab => locale.text("key_name.backtab").to_string(),
+        KeyCode::Backspace => locale.text("key_name.backspace").to_string(),
+        KeyCode::Char(' ') => locale.text("key_name.space").to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("F{}", n),
+        other => format!("{:?}", other),
+    }
+}
@@ -0,0 +1,327 @@
+use std::{fs, path::Path, time::Duration};
+
+use crate::domain::{
+    entities::rules::Difficulty,
+    i18n::Locale,
+    services::notifications::{
+        policy::{DEFAULT_NOTIFICATION_INTERVAL, NotificationCategory, NotificationPolicy},
+        webhook::WebhookUrl,
+    },
+};
+
+use super::glyphs::DisplayMode;
+
+/// Sommes de départ proposées dans les paramètres
+const MONEY_CHOICES: [u32; 7] = [100, 150, 250, 400, 1_000, 10_000, 100_000_000];
+
+/// Entrées de l'écran des paramètres, dans l'ordre d'affichage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SettingsItem {
+    Difficulty,
+    WaveMultiplier,
+    StartingMoney,
+    StartingLife,
+    TickRate,
+    DisplayMode,
+    Language,
+    Notifications,
+    Back,
+}
+
+impl SettingsItem {
+    pub const ALL: [SettingsItem; 9] = [
+        SettingsItem::Difficulty,
+        SettingsItem::WaveMultiplier,
+        SettingsItem::StartingMoney,
+        SettingsItem::StartingLife,
+        SettingsItem::TickRate,
+        SettingsItem::DisplayMode,
+        SettingsItem::Language,
+        SettingsItem::Notifications,
+        SettingsItem::Back,
+    ];
+
+    pub fn label(&self, locale: Locale) -> &'static str {
+        locale.text(match self {
+            SettingsItem::Difficulty => "settings.difficulty",
+            SettingsItem::WaveMultiplier => "settings.wave_multiplier",
+            SettingsItem::StartingMoney => "settings.starting_money",
+            SettingsItem::StartingLife => "settings.starting_life",
+            SettingsItem::TickRate => "settings.tick_rate",
+            SettingsItem::DisplayMode => "settings.display_mode",
+            SettingsItem::Language => "settings.language",
+            SettingsItem::Notifications => "settings.notifications",
+            SettingsItem::Back => "settings.back",
+        })
+    }
+}
+
+/// Paramètres du joueur, enregistrés dans le fichier de configuration
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// Préréglage appliqué aux valeurs de départ et aux récompenses
+    pub difficulty: Difficulty,
+    /// Progression des points de vie des monstres à chaque vague
+    pub wave_multiplier: f32,
+    pub starting_money: u32,
+    pub starting_life: i32,
+    /// Intervalle entre deux mises à jour du jeu, en millisecondes
+    pub tick_rate_ms: u64,
+    pub display_mode: DisplayMode,
+    /// Langue de l'interface, `TOWER_DEFENSE_LANG` la remplace sans la modifier
+    pub locale: Locale,
+    pub notifications: bool,
+    /// Délai minimal entre deux notifications d'une même catégorie, en secondes
+    pub notification_interval_secs: u64,
+    /// Pas de notification du bureau tant que le terminal a le focus
+    pub notification_quiet_focus: bool,
+    /// Catégories de notifications désactivées, réglées dans le fichier de paramètres
+    pub muted_notifications: Vec<NotificationCategory>,
+    /// Adresse à laquelle les notifications sont aussi envoyées en JSON
+    pub webhook_url: Option<String>,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        let preset = Difficulty::Normal.preset();
+
+        Self {
+            difficulty: Difficulty::Normal,
+            wave_multiplier: preset.wave_multiplier,
+            starting_money: preset.starting_money,
+            starting_life: preset.starting_life,
+            tick_rate_ms: 100,
+            display_mode: DisplayMode::Emoji,
+            locale: Locale::system(),
+            notifications: true,
+            notification_interval_secs: DEFAULT_NOTIFICATION_INTERVAL.as_secs(),
+            notification_quiet_focus: true,
+            muted_notifications: Vec::new(),
+            webhook_url: None,
+        }
+    }
+}
+
+impl Settings {
+    pub fn tick_rate(&self) -> Duration {
+        Duration::from_millis(self.tick_rate_ms)
+    }
+
+    /// Préférences de notification transmises au médiateur
+    pub fn notification_policy(&self) -> NotificationPolicy {
+        NotificationPolicy {
+            enabled: self.notifications,
+            disabled_categories: self.muted_notifications.clone(),
+            min_interval: Duration::from_secs(self.notification_interval_secs),
+            quiet_when_focused: self.notification_quiet_focus,
+        }
+    }
+
+    /// Choisit une difficulté et reprend ses valeurs de départ
+    pub fn set_difficulty(&mut self, difficulty: Difficulty) {
+        let preset = difficulty.preset();
+
+        self.difficulty = difficulty;
+        self.wave_multiplier = preset.wave_multiplier;
+        self.starting_money = preset.starting_money;
+        self.starting_life = preset.starting_life;
+    }
+
+    /// Indique si les valeurs de départ diffèrent de celles de la difficulté choisie
+    pub fn is_custom(&self) -> bool {
+        let preset = self.difficulty.preset();
+
+        self.wave_multiplier != preset.wave_multiplier
+            || self.starting_money != preset.starting_money
+            || self.starting_life != preset.starting_life
+    }
+
+    /// Valeur affichée pour une entrée de l'écran des paramètres
+    pub fn value_label(&self, item: SettingsItem, locale: Locale) -> String {
+        match item {
+            SettingsItem::Difficulty if self.is_custom() => locale.format(
+                "difficulty.custom",
+                &[("difficulty", &self.difficulty.label(locale))],
+            ),
+            SettingsItem::Difficulty => self.difficulty.label(locale).to_string(),
+            SettingsItem::WaveMultiplier => format!("x{:.2}", self.wave_multiplier),
+            SettingsItem::StartingMoney => self.starting_money.to_string(),
+            SettingsItem::StartingLife => self.starting_life.to_string(),
+            SettingsItem::TickRate => format!("{} ms", self.tick_rate_ms),
+            SettingsItem::DisplayMode => self.display_mode.label().to_string(),
+            SettingsItem::Language => self.locale.label().to_string(),
+            SettingsItem::Notifications => match self.notifications {
+                true => locale.text("settings.enabled").to_string(),
+                false => locale.text("settings.disabled").to_string(),
+            },
+            SettingsItem::Back => String::new(),
+        }
+    }
+
+    /// Augmente (`step > 0`) ou diminue la valeur d'une entrée
+    pub fn adjust(&mut self, item: SettingsItem, step: i32) {
+        match item {
+            SettingsItem::Difficulty => self.set_difficulty(self.difficulty.cycle(step)),
+            SettingsItem::WaveMultiplier => {
+                self.wave_multiplier = (self.wave_multiplier + 0.25 * step as f32).clamp(0.25, 3.0);
+            }
+            SettingsItem::StartingMoney => {
+                let current = MONEY_CHOICES
+                    .iter()
+                    .position(|money| *money >= self.starting_money)
+                    .unwrap_or(MONEY_CHOICES.len() - 1) as i32;
+                let index = (current + step).clamp(0, MONEY_CHOICES.len() as i32 - 1);
+                self.starting_money = MONEY_CHOICES[index as usize];
+            }
+            SettingsItem::StartingLife => {
+                self.starting_life = (self.starting_life + step).clamp(1, 100);
+            }
+            SettingsItem::TickRate => {
+                self.tick_rate_ms =
+                    (self.tick_rate_ms as i64 + 10 * step as i64).clamp(30, 500) as u64;
+            }
+            SettingsItem::DisplayMode => self.display_mode = self.display_mode.toggle(),
+            SettingsItem::Language => self.locale = self.locale.cycle(step),
+            SettingsItem::Notifications => self.notifications = !self.notifications,
+            SettingsItem::Back => {}
+        }
+    }
+
+    /// Lit les paramètres au format `clé = valeur`, les clés absentes gardent leur valeur par défaut
+    ///
+    /// Les erreurs sont rédigées dans la langue demandée.
+    pub fn parse(content: &str, locale: Locale) -> Result<Self, String> {
+        let mut settings = Self::default();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| locale.format("config.invalid_line", &[("line", &line)]))?;
+            let value = value.trim();
+
+            match key.trim() {
+                "difficulty" => {
+                    settings.difficulty = known(Difficulty::from_name(value), key, value, locale)?
+                }
+                "wave_multiplier" => settings.wave_multiplier = parse_value(value, key, locale)?,
+                "starting_money" => settings.starting_money = parse_value(value, key, locale)?,
+                "starting_life" => settings.starting_life = parse_value(value, key, locale)?,
+                "tick_rate_ms" => settings.tick_rate_ms = parse_value(value, key, locale)?,
+                "display_mode" => {
+                    settings.display_mode =
+                        known(DisplayMode::from_name(value), key, value, locale)?
+                }
+                "locale" => settings.locale = known(Locale::from_name(value), key, value, locale)?,
+                "notifications" => settings.notifications = parse_value(value, key, locale)?,
+                "notification_interval" => {
+                    settings.notification_interval_secs = parse_value(value, key, locale)?
+                }
+                "notification_quiet_focus" => {
+                    settings.notification_quiet_focus = parse_value(value, key, locale)?
+                }
+                "webhook_url" if value.is_empty() => settings.webhook_url = None,
+                "webhook_url" => {
+                    WebhookUrl::parse(value, locale)
+                        .map_err(|_| locale.format("config.invalid_webhook", &[("url", &value)]))?;
+                    settings.webhook_url = Some(value.to_string());
+                }
+                other => match other.strip_prefix("notify.") {
+                    Some(name) => {
+                        let category = NotificationCategory::from_name(name).ok_or_else(|| {
+                            locale.format("config.unknown_key", &[("key", &other)])
+                        })?;
+                        let enabled: bool = parse_value(value, key, locale)?;
+                        settings
+                            .muted_notifications
+                            .retain(|muted| *muted != category);
+                        if !enabled {
+                            settings.muted_notifications.push(category);
+                        }
+                    }
+                    None => {
+                        return Err(locale.format("config.unknown_key", &[("key", &other)]));
+                    }
+                },
+            }
+        }
+
+        Ok(settings)
+    }
+
+    pub fn serialize(&self) -> String {
+        [
+            format!("difficulty = {}", self.difficulty.name()),
+            format!("wave_multiplier = {}", self.wave_multiplier),
+            format!("starting_money = {}", self.starting_money),
+            format!("starting_life = {}", self.starting_life),
+            format!("tick_rate_ms = {}", self.tick_rate_ms),
+            format!(
+                "display_mode = {}",
+                self.display_mode.label().to_lowercase()
+            ),
+            format!("locale = {}", self.locale.name()),
+            format!("notifications = {}", self.notifications),
+            format!(
+                "notification_interval = {}",
+                self.notification_interval_secs
+            ),
+            format!(
+                "notification_quiet_focus = {}",
+                self.notification_quiet_focus
+            ),
+        ]
+        .into_iter()
+        .chain(NotificationCategory::ALL.iter().map(|category| {
+            format!(
+                "notify.{} = {}",
+                category.name(),
+                !self.muted_notifications.contains(category)
+            )
+        }))
+        .chain(
+            self.webhook_url
+                .iter()
+                .map(|url| format!("webhook_url = {}", url)),
+        )
+        .collect::<Vec<_>>()
+        .join("\n")
+            + "\n"
+    }
+
+    pub fn save(&self, path: &Path, locale: Locale) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| {
+                locale.format(
+                    "file.create_dir_failed",
+                    &[("path", &dir.display()), ("error", &e)],
+                )
+            })?;
+        }
+
+        fs::write(path, self.serialize()).map_err(|e| {
+            locale.format(
+                "file.write_failed",
+                &[("path", &path.display()), ("error", &e)],
+            )
+        })
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(value: &str, key: &str, locale: Locale) -> Result<T, String> {
+    known(value.parse().ok(), key, value, locale)
+}
+
+/// Valeur reconnue, sinon l'erreur signalant la valeur invalide de la clé
+fn known<T>(found: Option<T>, key: &str, value: &str, locale: Locale) -> Result<T, String> {
+    found.ok_or_else(|| {
+        locale.format(
+            "config.invalid_value",
+            &[("key", &key.trim()), ("value", &value)],
+        )
+    })
+}
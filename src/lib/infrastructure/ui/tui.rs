@@ -2,10 +2,13 @@ use std::{io, panic};
 
 use color_eyre::Result;
 use crossterm::{
-    event::{DisableMouseCapture, EnableMouseCapture},
+    event::{DisableFocusChange, DisableMouseCapture, EnableFocusChange, EnableMouseCapture},
     terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use ratatui::prelude::{CrosstermBackend, Terminal};
+use ratatui::{
+    layout::Rect,
+    prelude::{CrosstermBackend, Terminal},
+};
 
 /// Gestionnaire pour le terminal TUI
 pub struct Tui {
@@ -23,7 +26,12 @@ impl Tui {
     /// Initialise le terminal pour l'utilisation de Ratatui
     pub fn init(&mut self) -> Result<()> {
         terminal::enable_raw_mode()?;
-        crossterm::execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture)?;
+        crossterm::execute!(
+            io::stdout(),
+            EnterAlternateScreen,
+            EnableMouseCapture,
+            EnableFocusChange
+        )?;
 
         // Configurez un hook de panique pour nettoyer le terminal en cas de panique
         let panic_hook = panic::take_hook();
@@ -45,6 +53,12 @@ impl Tui {
         Ok(())
     }
 
+    /// Retourne la zone actuellement disponible dans le terminal
+    pub fn area(&self) -> Result<Rect> {
+        let size = self.terminal.size()?;
+        Ok(Rect::new(0, 0, size.width, size.height))
+    }
+
     /// Réinitialise le terminal à son état d'origine
     pub fn exit(&mut self) -> Result<()> {
         Self::reset()?;
@@ -55,7 +69,12 @@ impl Tui {
     /// Réinitialise les paramètres du terminal
     fn reset() -> Result<()> {
         terminal::disable_raw_mode()?;
-        crossterm::execute!(io::stdout(), LeaveAlternateScreen, DisableMouseCapture)?;
+        crossterm::execute!(
+            io::stdout(),
+            LeaveAlternateScreen,
+            DisableMouseCapture,
+            DisableFocusChange
+        )?;
         Ok(())
     }
 }
@@ -3,30 +3,128 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style, Stylize},
     text::{Line, Span},
-    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Widget},
+    widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap},
 };
 
-use crate::infrastructure::ui::app::{App, GameAction, UiMode, View};
+use crate::{
+    application::engine::monsters::basics::ROSTER,
+    domain::entities::{element::Element, endless::GameMode, map::Map, position::Position},
+    infrastructure::ui::{
+        app::{App, GameAction, RunOption, UiMode, View},
+        camera::Camera,
+        editor::{END_SYMBOLS, START_SYMBOLS, TERRAIN_SYMBOLS},
+        glyphs::{DisplayMode, END_KEY, START_KEY},
+        help::HelpPage,
+        keymap::{EditorAction, KeyAction},
+        settings::SettingsItem,
+    },
+};
 
 use super::widgets::popup::Popup;
 
+/// Taille minimale du terminal pour afficher le jeu
+pub const MIN_TERMINAL_WIDTH: u16 = 80;
+pub const MIN_TERMINAL_HEIGHT: u16 = 24;
+
+/// Indique si une zone est trop petite pour afficher l'interface
+pub fn is_area_too_small(area: Rect) -> bool {
+    area.width < MIN_TERMINAL_WIDTH || area.height < MIN_TERMINAL_HEIGHT
+}
+
 /// Gère le rendu de l'interface utilisateur
 pub fn render(app: &App, frame: &mut Frame) {
+    if is_area_too_small(frame.area()) {
+        render_terminal_too_small(app, frame);
+        return;
+    }
+
     match app.current_view {
         View::Game => render_game_view(app, frame),
         View::MainMenu => render_main_menu(app, frame),
         View::MapSelection => render_map_selection(app, frame),
         View::Pause => render_pause_menu(app, frame),
         View::GameOver => render_game_over(app, frame),
+        View::Victory => render_victory(app, frame),
+        View::MapEditor => render_map_editor(app, frame),
+        View::Settings => render_settings(app, frame),
+        View::Help => render_help_view(app, frame),
+        View::EndlessSummary => render_endless_summary(app, frame),
+        View::Campaign => render_campaign(app, frame),
+        View::CampaignResult => render_campaign_result(app, frame),
+    }
+
+    if app.show_help {
+        render_help(app, frame);
+    }
+
+    render_toasts(app, frame);
+}
+
+/// Affiche les notifications récentes en haut à droite de l'écran
+fn render_toasts(app: &App, frame: &mut Frame) {
+    let frame_area = frame.area();
+    let width = 40.min(frame_area.width);
+    let mut y = frame_area.y + 1;
+
+    for toast in app.toasts.active().iter().rev() {
+        let height = 4;
+        if y + height > frame_area.bottom() {
+            break;
+        }
+
+        let area = Rect::new(frame_area.right() - width - 1, y, width, height);
+        let toast = Paragraph::new(toast.message.as_str())
+            .wrap(Wrap { trim: true })
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(toast.title.as_str())
+                    .style(Style::default().bg(Color::Black).fg(Color::Yellow)),
+            );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(toast, area);
+        y += height;
+    }
+}
+
+/// Affiche un écran d'avertissement lorsque le terminal est trop petit
+fn render_terminal_too_small(app: &App, frame: &mut Frame) {
+    let area = frame.area();
+    let locale = app.locale;
+
+    let mut lines = vec![
+        Line::from(locale.text("terminal.too_small").red().bold()),
+        Line::from(""),
+        Line::from(locale.format(
+            "terminal.current_size",
+            &[("width", &area.width), ("height", &area.height)],
+        )),
+        Line::from(locale.format(
+            "terminal.required_size",
+            &[
+                ("width", &MIN_TERMINAL_WIDTH),
+                ("height", &MIN_TERMINAL_HEIGHT),
+            ],
+        )),
+    ];
+
+    if app.current_view == View::Game {
+        lines.push(Line::from(""));
+        lines.push(Line::from(locale.text("terminal.paused").yellow()));
     }
+
+    let warning = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .alignment(Alignment::Center);
+
+    frame.render_widget(warning, area);
 }
 
 /// Affiche la vue de jeu principale
 fn render_game_view(app: &App, frame: &mut Frame) {
     // Diviser l'écran en sections
-    let [map_area, info_area] =
-        Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
-            .areas(frame.area());
+    let [map_area, info_area] = game_layout(frame.area());
 
     let [header_area, actions_area, logs_area] = Layout::vertical([
         Constraint::Percentage(20),
@@ -45,180 +143,402 @@ fn render_game_view(app: &App, frame: &mut Frame) {
 
     render_monsters_bar(app, frame, monsters_area);
     render_logs(app, frame, logs_display_area);
+
+    if app.tutorial.is_some() {
+        render_tutorial(app, frame, map_area);
+    }
+}
+
+/// Affiche les consignes du tutoriel en bas de la carte
+fn render_tutorial(app: &App, frame: &mut Frame, area: Rect) {
+    let Some(tutorial) = &app.tutorial else {
+        return;
+    };
+
+    let instructions = tutorial.instructions(&app.keymap, app.locale);
+    let mut lines = vec![Line::from(instructions[0].clone().yellow().bold())];
+    lines.extend(
+        instructions[1..]
+            .iter()
+            .map(|line| Line::from(app.glyphs.text(line).to_string())),
+    );
+
+    // À l'intérieur de la bordure de la carte
+    let height = 8.min(area.height.saturating_sub(2));
+    let popup_area = Rect::new(
+        area.x + 1,
+        area.y + area.height - 1 - height,
+        area.width.saturating_sub(2),
+        height,
+    );
+
+    let popup = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(app.locale.text("tutorial.title"))
+            .style(Style::default().bg(Color::Black)),
+    );
+
+    frame.render_widget(Clear, popup_area);
+    frame.render_widget(popup, popup_area);
 }
 
 /// Affiche l'en-tête avec des informations de base
 fn render_header(app: &App, frame: &mut Frame, area: Rect) {
+    let glyphs = &app.glyphs;
+    let locale = app.locale;
+
+    let mut wave = match app.game.total_waves() {
+        Some(total) => format!(
+            "😈 {}",
+            locale.format(
+                "hud.wave_of",
+                &[("wave", &app.game.wave_index), ("total", &total)]
+            )
+        ),
+        None => format!(
+            "😈 {}",
+            locale.format("hud.wave", &[("wave", &app.game.wave_index)])
+        ),
+    };
+    if app.game_speed != 1.0 {
+        wave.push_str(&format!(" | ⏩ x{}", app.game_speed));
+    }
+    if app.game.mode == GameMode::Endless {
+        wave.push_str(&format!(
+            " | 🏅 {}",
+            locale.format("hud.score", &[("score", &app.game.score.points())])
+        ));
+    }
+
     let content = vec![
-        format!("❤️ Vie: {}", app.game.player_life).red().into(),
-        format!("😈 Wave: {}", app.game.wave_index).cyan().into(),
-        format!("💰 Money: {}", app.game.money).yellow().into(),
+        glyphs
+            .text(&format!(
+                "❤️ {}",
+                locale.format("hud.life", &[("life", &app.game.player_life)])
+            ))
+            .to_string()
+            .red()
+            .into(),
+        glyphs.text(&wave).to_string().cyan().into(),
+        glyphs
+            .text(&format!(
+                "💰 {}",
+                locale.format("hud.money", &[("money", &app.game.money)])
+            ))
+            .to_string()
+            .yellow()
+            .into(),
     ];
 
     frame.render_widget(Paragraph::new(content).block(Block::bordered()), area);
 }
 
-/// Affiche la carte de jeu
-fn render_map(app: &App, frame: &mut Frame, area: Rect) {
-    if let Some(map) = &app.game.current_map {
-        let game = &app.game;
+/// Calcule la zone de la carte dans la vue de jeu
+fn game_layout(area: Rect) -> [Rect; 2] {
+    Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)]).areas(area)
+}
+
+/// Nombre de cases de carte visibles pour une taille de terminal donnée
+///
+/// Chaque case occupe deux colonnes pour laisser la place aux symboles emoji.
+pub fn map_viewport(area: Rect) -> (u16, u16) {
+    let [map_area, _] = game_layout(area);
+    let inner = Block::default().borders(Borders::ALL).inner(map_area);
+
+    (inner.width / 2, inner.height)
+}
 
-        let mut map_chars = vec![vec!["  "; area.width as usize]; area.height as usize];
-        let mut map_styles =
-            vec![vec![Style::default(); area.width as usize]; area.height as usize];
+/// Grille de rendu limitée à la partie de la carte visible par la caméra
+struct MapCanvas<'a> {
+    camera: Camera,
+    cells: Vec<Vec<(&'a str, Style)>>,
+}
 
-        for waypoint in &map.waypoints {
-            if waypoint.x < area.width as i32 && waypoint.y < area.height as i32 {
-                map_styles[waypoint.y as usize][waypoint.x as usize] =
-                    Style::default().bg(Color::DarkGray).fg(Color::White);
+impl<'a> MapCanvas<'a> {
+    fn new(camera: Camera, map: &Map) -> Self {
+        let mut cells = vec![
+            vec![("  ", Style::default()); camera.viewport_width as usize];
+            camera.viewport_height as usize
+        ];
+
+        // Griser ce qui dépasse de la carte
+        for (y, row) in cells.iter_mut().enumerate() {
+            for (x, cell) in row.iter_mut().enumerate() {
+                let position =
+                    Position::new(camera.offset.x + x as i32, camera.offset.y + y as i32);
+                if !map.contains(&position) {
+                    *cell = ("░░", Style::default().fg(Color::DarkGray));
+                }
             }
         }
 
-        if map.waypoints.len() > 1 {
-            for i in 0..map.waypoints.len() - 1 {
-                let start = map.waypoints[i];
-                let end = map.waypoints[i + 1];
+        Self { camera, cells }
+    }
 
-                let dx = (end.x - start.x).signum();
-                let dy = (end.y - start.y).signum();
+    /// Case de la vue correspondant à une position de la carte, si elle est visible
+    fn cell(&mut self, position: Position) -> Option<&mut (&'a str, Style)> {
+        let (x, y) = self.camera.to_view(position)?;
+        self.cells.get_mut(y)?.get_mut(x)
+    }
 
-                let mut x = start.x;
-                let mut y = start.y;
+    fn set_symbol(&mut self, position: Position, symbol: &'a str) {
+        if let Some(cell) = self.cell(position) {
+            cell.0 = symbol;
+        }
+    }
 
-                // Commencer par l'horizontal, puis le vertical
-                while x != end.x {
-                    x += dx;
-                    if x >= 0 && x < area.width as i32 && y >= 0 && y < area.height as i32 {
-                        map_chars[y as usize][x as usize] = "  ";
-                        map_styles[y as usize][x as usize] =
-                            Style::default().bg(Color::DarkGray).fg(Color::White);
-                    }
-                }
+    fn set_style(&mut self, position: Position, style: Style) {
+        if let Some(cell) = self.cell(position) {
+            cell.1 = style;
+        }
+    }
 
-                while y != end.y {
-                    y += dy;
-                    if x >= 0 && x < area.width as i32 && y >= 0 && y < area.height as i32 {
-                        map_chars[y as usize][x as usize] = "  ";
-                        map_styles[y as usize][x as usize] =
-                            Style::default().bg(Color::DarkGray).fg(Color::White);
-                    }
-                }
+    /// Affiche un symbole, avec sa couleur de remplacement en mode ASCII
+    fn set_glyph(&mut self, position: Position, (symbol, color): (&'a str, Option<Color>)) {
+        if let Some(cell) = self.cell(position) {
+            cell.0 = symbol;
+            if let Some(color) = color {
+                cell.1 = cell.1.fg(color);
             }
         }
+    }
+
+    fn into_lines(self) -> Vec<Line<'a>> {
+        self.cells
+            .into_iter()
+            .map(|row| {
+                Line::from(
+                    row.into_iter()
+                        .map(|(symbol, style)| Span::styled(symbol, style))
+                        .collect::<Vec<_>>(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Affiche la carte de jeu
+fn render_map(app: &App, frame: &mut Frame, area: Rect) {
+    if let Some(map) = &app.game.current_map {
+        let game = &app.game;
+        let glyphs = &app.glyphs;
+        let mut canvas = MapCanvas::new(app.camera, map);
+        let path_style = Style::default().bg(Color::DarkGray).fg(Color::White);
+
+        for cell in map.path_cells() {
+            canvas.set_style(cell, path_style);
+        }
 
-        let first_waypoint = map.waypoints[0];
-        let last_waypoint = map.waypoints[map.waypoints.len() - 1];
+        // Dessiner les éléments de décor
+        for decoration in &map.decorations {
+            canvas.set_glyph(decoration.position, glyphs.cell(&decoration.symbol));
+        }
 
-        map_chars[first_waypoint.y as usize][first_waypoint.x as usize] = &map.start_symbol;
-        map_chars[last_waypoint.y as usize][last_waypoint.x as usize] = &map.end_symbol;
+        if let (Some(first_waypoint), Some(last_waypoint)) =
+            (map.waypoints.first(), map.waypoints.last())
+        {
+            canvas.set_glyph(
+                *first_waypoint,
+                glyphs.cell_for(START_KEY, &map.start_symbol),
+            );
+            canvas.set_glyph(*last_waypoint, glyphs.cell_for(END_KEY, &map.end_symbol));
+        }
 
         // Dessiner les tourelles
-        for (i, tower) in game.towers.iter().enumerate() {
+        for tower in game.towers.iter() {
             let pos = tower.position;
-            map_chars[pos.y as usize][pos.x as usize] = &tower.symbol;
 
-            let is_selected = app.tower_selection_on_map
-                && app.selected_tower_index.map_or(false, |index| index == i);
+            let is_selected = app.tower_selection_on_map && app.selected_tower_id == Some(tower.id);
 
             if is_selected {
-                map_styles[pos.y as usize][pos.x as usize] = Style::default()
-                    .bg(Color::Green)
-                    .add_modifier(Modifier::BOLD);
+                canvas.set_style(
+                    pos,
+                    Style::default()
+                        .bg(Color::Green)
+                        .add_modifier(Modifier::BOLD),
+                );
             }
 
-            if let Some(highlight) = tower.highlight.clone() {
-                map_styles[pos.y as usize][pos.x as usize] =
-                    Style::default().bg(highlight).add_modifier(Modifier::BOLD);
+            if let Some(highlight) = tower.highlight {
+                canvas.set_style(
+                    pos,
+                    Style::default().bg(highlight).add_modifier(Modifier::BOLD),
+                );
             }
+
+            canvas.set_glyph(pos, glyphs.cell(&tower.symbol));
         }
 
         // Dessiner les monstres
         if let Some(wave) = &game.current_wave {
-            for monster in &wave.monsters {
-                if monster.active {
-                    let x = monster.position.x;
-                    let y = monster.position.y;
-                    if x < area.width as i32 && y < area.height as i32 {
-                        map_chars[y as usize][x as usize] = &monster.symbol;
-                        map_styles[y as usize][x as usize] = Style::default()
-                            .fg(Color::Red)
-                            .add_modifier(Modifier::BOLD)
-                            .bg(Color::DarkGray);
-                    }
-                }
+            for monster in wave.monsters.iter().filter(|m| m.active) {
+                let cell = monster.cell();
+                canvas.set_style(
+                    cell,
+                    Style::default()
+                        .fg(Color::Red)
+                        .add_modifier(Modifier::BOLD)
+                        .bg(Color::DarkGray),
+                );
+                canvas.set_glyph(cell, glyphs.cell(&monster.symbol));
             }
         }
 
-        if let Some(_) = app.selected_map
-            && app.ui_mode == UiMode::TowerUpgrade
-        {
-            let cursor_x = app.cursor_position.x;
-            let cursor_y = app.cursor_position.y;
+        let cursor = app.cursor_position;
 
-            map_styles[cursor_y as usize][cursor_x as usize] = Style::default()
-                .bg(Color::Yellow)
-                .add_modifier(Modifier::BOLD);
+        if app.selected_map.is_some() && app.ui_mode == UiMode::TowerUpgrade {
+            canvas.set_style(
+                cursor,
+                Style::default()
+                    .bg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            );
         }
 
         // Dessiner le curseur en mode placement ou en mode sélection sur la carte
         if app.ui_mode == UiMode::Placement
             || (app.ui_mode == UiMode::TowerSelection && app.tower_selection_on_map)
         {
-            let cursor_x = app.cursor_position.x;
-            let cursor_y = app.cursor_position.y;
-            if cursor_x < area.width as i32 && cursor_y < area.height as i32 {
-                let is_upgrade_mode = app.selected_index < app.available_actions.len()
-                    && app.selected_tower.is_none()
-                    && app.available_actions[app.selected_index] == GameAction::UpgradeTower;
+            let is_upgrade_mode = app.selected_index < app.available_actions.len()
+                && app.selected_tower.is_none()
+                && app.available_actions[app.selected_index] == GameAction::UpgradeTower;
 
-                if is_upgrade_mode {
-                    map_styles[cursor_y as usize][cursor_x as usize] =
-                        Style::default().add_modifier(Modifier::BOLD);
+            if is_upgrade_mode {
+                let background = if is_cursor_on_tower(app, cursor.x, cursor.y) {
+                    Color::Green
+                } else {
+                    Color::LightRed
+                };
 
-                    if is_cursor_on_tower(app, cursor_x, cursor_y) {
-                        map_styles[cursor_y as usize][cursor_x as usize] = Style::default()
-                            .bg(Color::Green)
-                            .add_modifier(Modifier::BOLD);
+                canvas.set_style(
+                    cursor,
+                    Style::default().bg(background).add_modifier(Modifier::BOLD),
+                );
+            } else {
+                canvas.set_symbol(cursor, "  ");
+                canvas.set_style(
+                    cursor,
+                    if is_cursor_on_tower(app, cursor.x, cursor.y)
+                        || is_cursor_on_waypoint(app, cursor.x, cursor.y)
+                    {
+                        Style::default().bg(Color::Red).add_modifier(Modifier::BOLD)
                     } else {
-                        map_styles[cursor_y as usize][cursor_x as usize] = Style::default()
-                            .bg(Color::LightRed)
-                            .add_modifier(Modifier::BOLD);
-                    }
-                } else {
-                    map_chars[cursor_y as usize][cursor_x as usize] = "  ";
-                    map_styles[cursor_y as usize][cursor_x as usize] =
-                        if is_cursor_on_tower(app, cursor_x, cursor_y)
-                            || is_cursor_on_waypoint(app, cursor_x, cursor_y)
-                        {
-                            Style::default().bg(Color::Red).add_modifier(Modifier::BOLD)
-                        } else {
-                            Style::default()
-                                .bg(Color::Green)
-                                .add_modifier(Modifier::BOLD)
-                        };
-                }
+                        Style::default()
+                            .bg(Color::Green)
+                            .add_modifier(Modifier::BOLD)
+                    },
+                );
             }
         }
 
-        let map_text: Vec<Line> = (0..map_chars.len())
-            .map(|y| {
-                let spans: Vec<Span> = (0..map_chars[y].len())
-                    .map(|x| Span::styled(map_chars[y][x].to_string(), map_styles[y][x]))
-                    .collect();
-                Line::from(spans)
-            })
-            .collect();
-
-        let map_widget = Paragraph::new(map_text)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(format!(" {} ", map.name.clone())),
+        let name = map.title(app.locale);
+        let rules = app.game.rules.label(app.locale);
+        let title = if app.camera.fits(map) {
+            format!(" {} · {} ", name, rules)
+        } else {
+            format!(
+                " {} · {} ({}, {}) ",
+                name, rules, app.camera.offset.x, app.camera.offset.y
             )
+        };
+
+        let map_widget = Paragraph::new(canvas.into_lines())
+            .block(Block::default().borders(Borders::ALL).title(title))
             .style(Style::default());
 
         frame.render_widget(map_widget, area);
+
+        if !app.camera.fits(map) {
+            render_minimap(app, map, frame, area);
+        }
+    }
+}
+
+/// Affiche une vue d'ensemble de la carte dans le coin inférieur droit de la zone
+fn render_minimap(app: &App, map: &Map, frame: &mut Frame, area: Rect) {
+    let map_width = map.width as usize;
+    let map_height = map.height as usize;
+
+    let max_width = (area.width / 3).saturating_sub(2) as usize;
+    let max_height = (area.height / 3).saturating_sub(2) as usize;
+    if max_width < 4 || max_height < 2 {
+        return;
+    }
+
+    // Nombre de cases de la carte regroupées dans un caractère de la mini-carte
+    let scale = map_width
+        .div_ceil(max_width)
+        .max(map_height.div_ceil(max_height))
+        .max(1);
+    let width = map_width.div_ceil(scale);
+    let height = map_height.div_ceil(scale);
+
+    let mut cells = vec![vec![(' ', Style::default()); width]; height];
+    let mut plot = |position: Position, symbol: char, style: Style| {
+        if map.contains(&position) {
+            let x = position.x as usize / scale;
+            let y = position.y as usize / scale;
+            if let Some(cell) = cells.get_mut(y).and_then(|row| row.get_mut(x)) {
+                *cell = (symbol, style);
+            }
+        }
+    };
+
+    for cell in map.path_cells() {
+        plot(cell, '·', Style::default().fg(Color::DarkGray));
+    }
+
+    for tower in &app.game.towers {
+        plot(tower.position, '■', Style::default().fg(Color::Cyan));
+    }
+
+    if let Some(wave) = &app.game.current_wave {
+        for monster in wave.monsters.iter().filter(|m| m.active) {
+            plot(monster.cell(), '•', Style::default().fg(Color::Red));
+        }
+    }
+
+    // Surligner la partie actuellement visible
+    let camera = app.camera;
+    for (y, row) in cells.iter_mut().enumerate() {
+        for (x, cell) in row.iter_mut().enumerate() {
+            let map_x = (x * scale) as i32;
+            let map_y = (y * scale) as i32;
+            let visible = map_x + scale as i32 > camera.offset.x
+                && map_x < camera.offset.x + camera.viewport_width
+                && map_y + scale as i32 > camera.offset.y
+                && map_y < camera.offset.y + camera.viewport_height;
+
+            if visible {
+                cell.1 = cell.1.bg(Color::Rgb(40, 40, 60));
+            }
+        }
     }
+
+    let lines: Vec<Line> = cells
+        .into_iter()
+        .map(|row| {
+            Line::from(
+                row.into_iter()
+                    .map(|(symbol, style)| Span::styled(symbol.to_string(), style))
+                    .collect::<Vec<_>>(),
+            )
+        })
+        .collect();
+
+    let minimap_area = Rect {
+        x: area.x + area.width - width as u16 - 3,
+        y: area.y + area.height - height as u16 - 3,
+        width: width as u16 + 2,
+        height: height as u16 + 2,
+    };
+
+    frame.render_widget(Clear, minimap_area);
+    frame.render_widget(
+        Paragraph::new(lines).block(Block::default().borders(Borders::ALL)),
+        minimap_area,
+    );
 }
 
 /// Affiche la barre d'informations
@@ -232,13 +552,18 @@ fn render_monsters_bar(app: &App, frame: &mut Frame, area: Rect) {
         ])
         .split(area);
 
+    let locale = app.locale;
+
     // Section des monstres actifs
     let mut monster_items = Vec::new();
     if let Some(wave) = &app.game.current_wave {
         for (i, monster) in wave.monsters.iter().enumerate().filter(|(_, m)| m.active) {
-            monster_items.push(ListItem::new(format!(
-                "{}: HP {:.1}",
-                monster.name, monster.hp
+            monster_items.push(ListItem::new(locale.format(
+                "monsters.hp",
+                &[
+                    ("name", &monster.name),
+                    ("hp", &format!("{:.1}", monster.hp)),
+                ],
             )));
 
             // Limiter le nombre de monstres affichés
@@ -250,26 +575,47 @@ fn render_monsters_bar(app: &App, frame: &mut Frame, area: Rect) {
     }
 
     let monsters_list = List::new(monster_items)
-        .block(Block::default().borders(Borders::ALL).title("Monstres"))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(locale.text("monsters.title")),
+        )
         .style(Style::default().fg(Color::White));
 
     // Section des instructions
-    let mut instructions_text = vec![Line::from("q: Quitter | p: Pause")];
-
-    match app.ui_mode {
-        UiMode::Normal => {
-            instructions_text.push(Line::from("↑ ↓: Sélectionner action"));
-            instructions_text.push(Line::from("Enter: Confirmer action"));
-        }
-        UiMode::TowerSelection => {
-            if app.tower_selection_on_map {
-                instructions_text.push(Line::from("↑ ↓ ← →: Naviguer entre les tours"));
-                instructions_text.push(Line::from("Enter: Sélectionner | Esc: Annuler"));
-            } else {
-                instructions_text.push(Line::from("↑ ↓: Sélectionner type"));
-                instructions_text.push(Line::from("Enter: Choisir | Esc: Annuler"));
-            }
-        }
+    let keys = |action| app.keymap.label(action, locale);
+    let hint = |keys: &str, id| format!("{}: {}", keys, locale.text(id));
+    let vertical = format!("{} {}", keys(KeyAction::MoveUp), keys(KeyAction::MoveDown));
+    let all_directions = format!(
+        "{} {} {} {}",
+        keys(KeyAction::MoveUp),
+        keys(KeyAction::MoveDown),
+        keys(KeyAction::MoveLeft),
+        keys(KeyAction::MoveRight)
+    );
+    let confirm = keys(KeyAction::Confirm);
+    let cancel = hint(&keys(KeyAction::Cancel), "controls.cancel");
+
+    let mut instructions_text = vec![Line::from(format!(
+        "{} | {} | {}",
+        hint(&keys(KeyAction::Quit), "controls.quit"),
+        hint(&keys(KeyAction::Pause), "controls.pause"),
+        hint(&keys(KeyAction::Help), "controls.help")
+    ))];
+
+    let (first, second) = match app.ui_mode {
+        UiMode::Normal => (
+            hint(&vertical, "controls.select_action"),
+            hint(&confirm, "controls.confirm_action"),
+        ),
+        UiMode::TowerSelection if app.tower_selection_on_map => (
+            hint(&all_directions, "controls.browse_towers"),
+            format!("{} | {}", hint(&confirm, "controls.select"), cancel),
+        ),
+        UiMode::TowerSelection => (
+            hint(&vertical, "controls.select_type"),
+            format!("{} | {}", hint(&confirm, "controls.choose"), cancel),
+        ),
         UiMode::Placement => {
             // Déterminons si nous sommes en mode amélioration
             let is_upgrade_mode = app.selected_index < app.available_actions.len()
@@ -277,21 +623,31 @@ fn render_monsters_bar(app: &App, frame: &mut Frame, area: Rect) {
                 && app.available_actions[app.selected_index] == GameAction::UpgradeTower;
 
             if is_upgrade_mode {
-                instructions_text.push(Line::from("↑ ↓ ← →: Déplacer le curseur sur la carte"));
-                instructions_text.push(Line::from("Enter: Sélectionner la tour | Esc: Annuler"));
+                (
+                    hint(&all_directions, "controls.move_cursor_map"),
+                    format!("{} | {}", hint(&confirm, "controls.select_tower"), cancel),
+                )
             } else {
-                instructions_text.push(Line::from("↑ ↓ ← →: Déplacer curseur"));
-                instructions_text.push(Line::from("Enter: Confirmer | Esc: Annuler"));
+                (
+                    hint(&all_directions, "controls.move_cursor"),
+                    format!("{} | {}", hint(&confirm, "controls.confirm"), cancel),
+                )
             }
         }
-        UiMode::TowerUpgrade => {
-            instructions_text.push(Line::from("↑ ↓: Sélectionner amélioration"));
-            instructions_text.push(Line::from("Enter: Améliorer | Esc: Annuler"));
-        }
-    }
+        UiMode::TowerUpgrade => (
+            hint(&vertical, "controls.select_upgrade"),
+            format!("{} | {}", hint(&confirm, "controls.upgrade"), cancel),
+        ),
+    };
+    instructions_text.push(Line::from(first));
+    instructions_text.push(Line::from(second));
 
     let instructions = Paragraph::new(instructions_text)
-        .block(Block::default().borders(Borders::ALL).title("Commandes"))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(locale.text("controls.title")),
+        )
         .style(Style::default().fg(Color::White));
 
     frame.render_widget(monsters_list, chunks[0]);
@@ -299,13 +655,10 @@ fn render_monsters_bar(app: &App, frame: &mut Frame, area: Rect) {
 }
 
 fn is_cursor_on_tower(app: &App, cursor_x: i32, cursor_y: i32) -> bool {
-    let cursor_on_tower = app
-        .game
+    app.game
         .towers
         .iter()
-        .any(|tower| tower.position.x == cursor_x && tower.position.y == cursor_y);
-
-    cursor_on_tower
+        .any(|tower| tower.position.x == cursor_x && tower.position.y == cursor_y)
 }
 
 fn is_cursor_on_waypoint(app: &App, cursor_x: i32, cursor_y: i32) -> bool {
@@ -368,6 +721,7 @@ fn is_cursor_on_waypoint(app: &App, cursor_x: i32, cursor_y: i32) -> bool {
 }
 
 fn render_actions(app: &App, frame: &mut Frame, area: Rect) {
+    let locale = app.locale;
     let mut action_items = Vec::new();
 
     match app.ui_mode {
@@ -375,9 +729,9 @@ fn render_actions(app: &App, frame: &mut Frame, area: Rect) {
             // Afficher les actions principales
             for (i, action) in app.available_actions.iter().enumerate() {
                 let text = match action {
-                    GameAction::BuildTower => "🧱 Build tower",
-                    GameAction::RemoveTower => "🗑️ Remove tower",
-                    GameAction::UpgradeTower => "🔧 Upgrade existing tower",
+                    GameAction::BuildTower => format!("🧱 {}", locale.text("action.build")),
+                    GameAction::RemoveTower => format!("🗑️ {}", locale.text("action.remove")),
+                    GameAction::UpgradeTower => format!("🔧 {}", locale.text("action.upgrade")),
                 };
 
                 // Mettre en surbrillance l'action sélectionnée
@@ -389,18 +743,28 @@ fn render_actions(app: &App, frame: &mut Frame, area: Rect) {
                     Style::default().fg(Color::White)
                 };
 
-                action_items.push(ListItem::new(Span::styled(text, style)));
+                action_items.push(ListItem::new(Span::styled(
+                    app.glyphs.text(&text).to_string(),
+                    style,
+                )));
             }
 
             let actions_list = List::new(action_items)
-                .block(Block::default().borders(Borders::ALL).title(" Actions "))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(format!(" {} ", locale.text("action.title"))),
+                )
                 .style(Style::default().fg(Color::White));
 
             frame.render_widget(actions_list, area);
         }
         UiMode::TowerSelection => {
             for (i, tower) in app.available_towers.iter().enumerate() {
-                let text = format!("{} - 💰 {}", tower.name, tower.cost);
+                let text = app
+                    .glyphs
+                    .text(&format!("{} - 💰 {}", tower.name, tower.cost))
+                    .to_string();
                 let style = if i == app.selected_index {
                     Style::default()
                         .fg(Color::Yellow)
@@ -416,7 +780,7 @@ fn render_actions(app: &App, frame: &mut Frame, area: Rect) {
                 .block(
                     Block::default()
                         .borders(Borders::ALL)
-                        .title(" Types of towers "),
+                        .title(format!(" {} ", locale.text("action.tower_types"))),
                 )
                 .style(Style::default().fg(Color::White));
 
@@ -425,12 +789,12 @@ fn render_actions(app: &App, frame: &mut Frame, area: Rect) {
         UiMode::Placement => {
             let (mode_text, instructions) = if let Some(tower) = &app.selected_tower {
                 (
-                    format!("Mode placement - Tour {}", tower.name),
+                    locale.format("placement.build.title", &[("name", &tower.name)]),
                     vec![
                         Line::from(""),
-                        Line::from("Utilisez les flèches pour positionner le curseur"),
-                        Line::from("Appuyez sur Enter pour placer la tour"),
-                        Line::from("Appuyez sur Escape pour annuler"),
+                        Line::from(locale.text("placement.move_cursor")),
+                        Line::from(locale.text("placement.build.confirm")),
+                        Line::from(locale.text("placement.cancel")),
                     ],
                 )
             } else {
@@ -442,28 +806,24 @@ fn render_actions(app: &App, frame: &mut Frame, area: Rect) {
                 if is_upgrade_mode {
                     // Mode sélection de tour pour amélioration
                     (
-                        "Mode amélioration de tour".to_string(),
+                        locale.text("placement.upgrade.title").to_string(),
                         vec![
                             Line::from(""),
-                            Line::from(
-                                "Utilisez les flèches pour déplacer le curseur sur la carte",
-                            ),
-                            Line::from(
-                                "Positionnez-vous sur une tour existante (lettres B, F, W, E, A)",
-                            ),
-                            Line::from("Appuyez sur Enter pour sélectionner la tour à améliorer"),
-                            Line::from("Appuyez sur Escape pour annuler"),
+                            Line::from(locale.text("placement.move_cursor_map")),
+                            Line::from(locale.text("placement.upgrade.target")),
+                            Line::from(locale.text("placement.upgrade.confirm")),
+                            Line::from(locale.text("placement.cancel")),
                         ],
                     )
                 } else {
                     // Mode suppression de tour
                     (
-                        "Mode suppression de tour".to_string(),
+                        locale.text("placement.remove.title").to_string(),
                         vec![
                             Line::from(""),
-                            Line::from("Utilisez les flèches pour positionner le curseur"),
-                            Line::from("Appuyez sur Enter pour supprimer la tour"),
-                            Line::from("Appuyez sur Escape pour annuler"),
+                            Line::from(locale.text("placement.move_cursor")),
+                            Line::from(locale.text("placement.remove.confirm")),
+                            Line::from(locale.text("placement.cancel")),
                         ],
                     )
                 }
@@ -479,95 +839,103 @@ fn render_actions(app: &App, frame: &mut Frame, area: Rect) {
             placement_lines.extend(instructions);
 
             let placement_info = Paragraph::new(placement_lines)
-                .block(Block::default().borders(Borders::ALL).title("Placement"))
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .title(locale.text("placement.title")),
+                )
                 .style(Style::default().fg(Color::White));
 
             frame.render_widget(placement_info, area);
         }
         UiMode::TowerUpgrade => {
             // Vérifier si nous avons un menu d'amélioration
-            if let Some(upgrade_menu) = &app.upgrade_menu {
-                let tower_index = upgrade_menu.tower_index;
-
-                if tower_index < app.game.towers.len() {
-                    let tower = &app.game.towers[tower_index];
-                    let position = tower.position;
-                    let level = tower.level;
-
-                    // Afficher d'abord les informations sur la tour
-                    let tower_info = format!(
-                        "Tour {} (x:{}, y:{}) - Level {}",
-                        tower.name.clone(),
-                        position.x,
-                        position.y,
-                        level
-                    );
-
-                    action_items.push(ListItem::new(Span::styled(
-                        tower_info,
-                        Style::default()
-                            .fg(Color::Cyan)
-                            .add_modifier(Modifier::BOLD),
-                    )));
-
-                    action_items.push(ListItem::new(""));
-                    action_items.push(ListItem::new("Choisissez une amélioration:"));
-                    action_items.push(ListItem::new(""));
-
-                    let stats = upgrade_menu.available_upgrades.iter().enumerate();
-                    for (i, (stat_type, description)) in stats {
-                        let cost = tower.upgrade_cost_for_attribute(stat_type.clone());
-                        let is_maxed = cost.is_none();
-
-                        let item_rect = Rect {
-                            x: area.x + 1,
-                            y: area.y + action_items.len() as u16, // Position Y basée sur le nombre d'éléments déjà ajoutés
-                            width: area.width - 2,
-                            height: 1,
-                        };
-
-                        let chunks = Layout::default()
-                            .direction(Direction::Horizontal)
-                            .constraints(vec![
-                                Constraint::Percentage(70),
-                                Constraint::Percentage(30),
-                            ])
-                            .split(item_rect);
-
-                        let style = if i == upgrade_menu.selected_upgrade {
-                            Style::default()
-                                .fg(Color::Yellow)
-                                .add_modifier(Modifier::BOLD)
-                        } else {
-                            Style::default().fg(Color::White)
-                        };
-
-                        let content = Paragraph::new(description.clone())
-                            .alignment(Alignment::Left)
-                            .style(style);
-
-                        let price = match is_maxed {
-                            true => "Maxed".to_string(),
-                            false => format!("{} 💰", cost.unwrap()),
-                        };
+            if let Some(upgrade_menu) = &app.upgrade_menu
+                && let Some(tower) = app.game.towers.entity(upgrade_menu.tower)
+            {
+                let position = tower.position;
+                let level = tower.level;
+
+                // Afficher d'abord les informations sur la tour
+                let tower_info = locale.format(
+                    "upgrade.tower_info",
+                    &[
+                        ("name", &tower.name),
+                        ("x", &position.x),
+                        ("y", &position.y),
+                        ("level", &level),
+                    ],
+                );
 
-                        let price = Paragraph::new(price)
-                            .alignment(Alignment::Right)
-                            .style(style);
+                action_items.push(ListItem::new(Span::styled(
+                    tower_info,
+                    Style::default()
+                        .fg(Color::Cyan)
+                        .add_modifier(Modifier::BOLD),
+                )));
+
+                action_items.push(ListItem::new(""));
+                action_items.push(ListItem::new(locale.text("upgrade.choose")));
+                action_items.push(ListItem::new(""));
+
+                let stats = upgrade_menu.available_upgrades.iter().enumerate();
+                for (i, (stat_type, description)) in stats {
+                    let cost = tower.upgrade_cost_for_attribute(stat_type.clone());
+                    let is_maxed = cost.is_none();
+
+                    let item_rect = Rect {
+                        x: area.x + 1,
+                        y: area.y + action_items.len() as u16, // Position Y basée sur le nombre d'éléments déjà ajoutés
+                        width: area.width.saturating_sub(2),
+                        height: 1,
+                    };
+
+                    // Ne pas dessiner en dehors du cadre si la liste est trop longue
+                    if item_rect.bottom() >= area.bottom() {
+                        break;
+                    }
 
-                        frame.render_widget(content, chunks[0]);
-                        frame.render_widget(price, chunks[1]);
+                    let chunks = Layout::default()
+                        .direction(Direction::Horizontal)
+                        .constraints(vec![Constraint::Percentage(70), Constraint::Percentage(30)])
+                        .split(item_rect);
 
-                        let empty_item = ListItem::new("");
-                        action_items.push(empty_item);
-                    }
+                    let style = if i == upgrade_menu.selected_upgrade {
+                        Style::default()
+                            .fg(Color::Yellow)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::White)
+                    };
+
+                    let content = Paragraph::new(app.glyphs.text(description).to_string())
+                        .alignment(Alignment::Left)
+                        .style(style);
+
+                    let price = match is_maxed {
+                        true => locale.text("upgrade.maxed").to_string(),
+                        false => app
+                            .glyphs
+                            .text(&format!("{} 💰", cost.unwrap()))
+                            .to_string(),
+                    };
+
+                    let price = Paragraph::new(price)
+                        .alignment(Alignment::Right)
+                        .style(style);
+
+                    frame.render_widget(content, chunks[0]);
+                    frame.render_widget(price, chunks[1]);
+
+                    let empty_item = ListItem::new("");
+                    action_items.push(empty_item);
                 }
             }
 
             let upgrade_list = List::new(action_items).block(
                 Block::default()
                     .borders(Borders::ALL)
-                    .title("Améliorer une tour"),
+                    .title(locale.text("upgrade.title")),
             );
 
             frame.render_widget(upgrade_list, area);
@@ -585,14 +953,18 @@ fn render_logs(app: &App, frame: &mut Frame, area: Rect) {
         .rev()
         .map(|log| {
             ListItem::new(Line::from(vec![Span::styled(
-                log.message.clone(),
+                app.glyphs.text(&log.message).to_string(),
                 Style::default().fg(Color::White),
             )]))
         })
         .collect();
 
     let logs_list = List::new(log_items)
-        .block(Block::default().borders(Borders::ALL).title("Logs"))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(app.locale.text("logs.title")),
+        )
         .style(Style::default().fg(Color::White));
 
     frame.render_widget(logs_list, area);
@@ -608,13 +980,21 @@ fn render_main_menu(app: &App, frame: &mut Frame) {
             .add_modifier(Modifier::BOLD),
     ));
 
-    let items = vec![
-        ("Démarrer une nouvelle partie", app.selected_index == 0),
-        ("Quitter", app.selected_index == 1),
+    let locale = app.locale;
+    let items = [
+        "menu.new_game",
+        "menu.campaign",
+        "menu.tutorial",
+        "menu.map_editor",
+        "menu.settings",
+        "menu.help",
+        "menu.quit",
     ];
 
     let menu_items: Vec<Line> = items
         .into_iter()
+        .enumerate()
+        .map(|(i, id)| (locale.text(id), i == app.selected_index))
         .map(|(text, selected)| {
             if selected {
                 Line::from(Span::styled(
@@ -636,7 +1016,7 @@ fn render_main_menu(app: &App, frame: &mut Frame) {
         title,
         Line::from(""),
         Line::from(Span::styled(
-            "Défendez votre territoire contre des vagues d'ennemis",
+            locale.text("menu.subtitle"),
             Style::default().fg(Color::Gray),
         )),
         Line::from(""),
@@ -646,7 +1026,7 @@ fn render_main_menu(app: &App, frame: &mut Frame) {
     // Ajouter des instructions
     all_lines.push(Line::from(""));
     all_lines.push(Line::from(Span::styled(
-        "Utilisez ↑↓ pour naviguer et Entrée pour sélectionner",
+        locale.text("menu.hint"),
         Style::default().fg(Color::Gray),
     )));
 
@@ -654,57 +1034,299 @@ fn render_main_menu(app: &App, frame: &mut Frame) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Menu Principal"),
+                .title(locale.text("menu.title")),
         )
         .alignment(ratatui::layout::Alignment::Center);
 
     // Centrer le menu sur l'écran
-    frame.render_widget(menu, centered_rect(60, 40, frame.area()));
+    frame.render_widget(menu, centered_rect(60, 70, frame.area()));
 }
 
-/// Affiche le menu de pause
-fn render_pause_menu(app: &App, frame: &mut Frame) {
-    // Afficher le jeu en arrière-plan
-    render_game_view(app, frame);
+/// Étoiles obtenues sur trois, en symboles simples en mode ASCII
+fn star_rating(app: &App, stars: u8) -> String {
+    let (full, empty) = match app.glyphs.mode {
+        DisplayMode::Emoji => ("★", "☆"),
+        DisplayMode::Ascii => ("*", "."),
+    };
 
-    let items = vec![
-        ("Reprendre", app.selected_index == 0),
-        ("Quitter", app.selected_index == 1),
-    ];
+    (0..3)
+        .map(|i| if i < stars { full } else { empty })
+        .collect()
+}
 
-    let menu_items: Vec<Line> = items
-        .into_iter()
-        .map(|(text, selected)| {
-            if selected {
-                Line::from(Span::styled(
-                    format!("> {}", text),
-                    Style::default()
-                        .fg(Color::Yellow)
-                        .add_modifier(Modifier::BOLD),
-                ))
-            } else {
-                Line::from(Span::styled(
-                    format!("  {}", text),
-                    Style::default().fg(Color::White),
-                ))
-            }
-        })
-        .collect();
+/// Affiche la carte de la campagne : niveaux, étoiles obtenues et déblocages
+fn render_campaign(app: &App, frame: &mut Frame) {
+    let locale = app.locale;
+    let chunks = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(10),
+        Constraint::Length(1),
+    ])
+    .split(frame.area());
 
-    let title = Line::from(Span::styled(
-        "PAUSE",
+    let title = Paragraph::new(Line::from(Span::styled(
+        locale.text("campaign.title").to_uppercase(),
         Style::default()
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD),
-    ));
-
-    let mut all_lines = vec![title, Line::from("")];
-    all_lines.extend(menu_items);
-
-    let popup = Popup::default()
-        .content(all_lines)
-        .style(Style::new().white())
-        .title("Pause")
+    )))
+    .block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Tower Defense"),
+    )
+    .alignment(ratatui::layout::Alignment::Center);
+    frame.render_widget(title, chunks[0]);
+
+    let [levels_area, details_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .areas(chunks[1]);
+
+    let levels: Vec<Line> = app
+        .campaign_levels
+        .iter()
+        .enumerate()
+        .map(|(index, level)| {
+            let is_selected = index == app.selected_index;
+            let unlocked = app.is_campaign_level_unlocked(index);
+            let style = match (is_selected, unlocked) {
+                (true, _) => Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+                (false, true) => Style::default().fg(Color::White),
+                (false, false) => Style::default().fg(Color::DarkGray),
+            };
+            let status = match (unlocked, app.glyphs.mode) {
+                (true, _) => star_rating(app, app.campaign_progress.stars(level.id)),
+                (false, DisplayMode::Emoji) => "🔒".to_string(),
+                (false, DisplayMode::Ascii) => format!("[{}]", locale.text("campaign.locked")),
+            };
+
+            Line::from(vec![
+                Span::styled(if is_selected { "> " } else { "  " }, style),
+                Span::styled(format!("{}. {}", index + 1, level.name(locale)), style),
+                Span::raw("  "),
+                Span::styled(status, Style::default().fg(Color::Yellow)),
+            ])
+        })
+        .collect();
+
+    frame.render_widget(
+        Paragraph::new(levels).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(locale.text("campaign.levels")),
+        ),
+        levels_area,
+    );
+
+    let mut details = Vec::new();
+    if let Some(level) = app.campaign_levels.get(app.selected_index) {
+        details.push(Line::from(Span::styled(
+            level.name(locale),
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        details.push(Line::from(Span::styled(
+            level.description(locale),
+            Style::default().fg(Color::Gray),
+        )));
+        details.push(Line::from(""));
+        details.push(Line::from(locale.plural(
+            "campaign.objective",
+            level.waves.len() as u32,
+            &[],
+        )));
+        details.push(Line::from(locale.text("campaign.stars_rule")));
+
+        if !level.unlocks_towers.is_empty() {
+            details.push(Line::from(locale.format(
+                "campaign.unlocks",
+                &[("towers", &level.unlocks_towers.join(", "))],
+            )));
+        }
+
+        if !app.is_campaign_level_unlocked(app.selected_index) {
+            details.push(Line::from(""));
+            details.push(Line::from(Span::styled(
+                locale.text("campaign.locked_hint"),
+                Style::default().fg(Color::Red),
+            )));
+        }
+    }
+
+    details.push(Line::from(""));
+    details.push(Line::from(Span::styled(
+        locale.format(
+            "campaign.available_towers",
+            &[("towers", &app.unlocked_towers().join(", "))],
+        ),
+        Style::default().fg(Color::Cyan),
+    )));
+
+    frame.render_widget(
+        Paragraph::new(details)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(locale.text("campaign.details")),
+            )
+            .wrap(Wrap { trim: false }),
+        details_area,
+    );
+
+    let instructions = Paragraph::new(Line::from(Span::styled(
+        locale.text("campaign.hint"),
+        Style::default().fg(Color::Gray),
+    )))
+    .alignment(ratatui::layout::Alignment::Center);
+    frame.render_widget(instructions, chunks[2]);
+}
+
+/// Affiche le résultat du niveau de campagne qui vient de se terminer
+fn render_campaign_result(app: &App, frame: &mut Frame) {
+    let Some(result) = &app.campaign_result else {
+        return;
+    };
+    let level = app
+        .campaign_level
+        .and_then(|index| app.campaign_levels.get(index));
+    let won = result.stars > 0;
+    let locale = app.locale;
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            locale.text(match won {
+                true => "campaign.level_cleared",
+                false => "campaign.level_failed",
+            }),
+            Style::default()
+                .fg(if won { Color::Green } else { Color::Red })
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    if let Some(level) = level {
+        lines.push(Line::from(level.name(locale)));
+    }
+    lines.push(Line::from(locale.format(
+        "campaign.result",
+        &[
+            ("waves", &app.game.score.waves_survived),
+            ("life", &app.game.player_life.max(0)),
+        ],
+    )));
+
+    if won {
+        lines.push(Line::from(Span::styled(
+            star_rating(app, result.stars),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )));
+    }
+
+    if let Some(next) = &result.unlocked_level {
+        lines.push(Line::from(Span::styled(
+            locale.format("campaign.unlocked_level", &[("level", next)]),
+            Style::default().fg(Color::Green),
+        )));
+    }
+    if !result.unlocked_towers.is_empty() {
+        lines.push(Line::from(Span::styled(
+            locale.format(
+                "campaign.unlocked_towers",
+                &[("towers", &result.unlocked_towers.join(", "))],
+            ),
+            Style::default().fg(Color::Green),
+        )));
+    }
+    if let Some(error) = &result.error {
+        lines.push(Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    let has_next = app
+        .campaign_level
+        .is_some_and(|index| index + 1 < app.campaign_levels.len());
+    let first = match (won, has_next) {
+        (true, true) => "campaign.next_level",
+        (true, false) => "campaign.back",
+        (false, _) => "campaign.retry",
+    };
+
+    lines.push(Line::from(""));
+    for (index, id) in [first, "campaign.map", "menu.main_menu"].iter().enumerate() {
+        let label = locale.text(id);
+        lines.push(if index == app.selected_index {
+            Line::from(Span::styled(
+                format!("> {}", label),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else {
+            Line::from(format!("  {}", label))
+        });
+    }
+
+    let block = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(locale.text("campaign.title")),
+        )
+        .alignment(ratatui::layout::Alignment::Center)
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(block, centered_rect(70, 70, frame.area()));
+}
+
+/// Affiche le menu de pause
+fn render_pause_menu(app: &App, frame: &mut Frame) {
+    // Afficher le jeu en arrière-plan
+    render_game_view(app, frame);
+
+    let locale = app.locale;
+    let items = ["pause.resume", "menu.help", "menu.quit"];
+
+    let menu_items: Vec<Line> = items
+        .into_iter()
+        .enumerate()
+        .map(|(i, id)| (locale.text(id), i == app.selected_index))
+        .map(|(text, selected)| {
+            if selected {
+                Line::from(Span::styled(
+                    format!("> {}", text),
+                    Style::default()
+                        .fg(Color::Yellow)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::styled(
+                    format!("  {}", text),
+                    Style::default().fg(Color::White),
+                ))
+            }
+        })
+        .collect();
+
+    let title = Line::from(Span::styled(
+        locale.text("pause.title").to_uppercase(),
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD),
+    ));
+
+    let mut all_lines = vec![title, Line::from("")];
+    all_lines.extend(menu_items);
+
+    let popup = Popup::default()
+        .content(all_lines)
+        .style(Style::new().white())
+        .title(locale.text("pause.title"))
         .title_style(Style::new().white().bold())
         .floating(true);
 
@@ -713,19 +1335,15 @@ fn render_pause_menu(app: &App, frame: &mut Frame) {
 
 /// Affiche l'écran de fin de jeu
 fn render_game_over(app: &App, frame: &mut Frame) {
-    let title = if app.game.player_life <= 0 {
-        "GAME OVER"
-    } else {
-        "VICTOIRE!"
-    };
+    let locale = app.locale;
+    let title = locale.text("game_over.title");
 
-    let items = vec![
-        ("Nouvelle partie", app.selected_index == 0),
-        ("Quitter", app.selected_index == 1),
-    ];
+    let items = ["game_over.new_game", "menu.quit"];
 
     let menu_items: Vec<Line> = items
         .into_iter()
+        .enumerate()
+        .map(|(i, id)| (locale.text(id), i == app.selected_index))
         .map(|(text, selected)| {
             if selected {
                 Line::from(Span::styled(
@@ -753,7 +1371,8 @@ fn render_game_over(app: &App, frame: &mut Frame) {
     let mut all_lines = vec![
         title,
         Line::from(""),
-        Line::from(format!("Vagues complétées: {}", app.game.wave_index)),
+        Line::from(locale.format("game_over.waves", &[("waves", &app.game.wave_index)])),
+        Line::from(locale.format("summary.rules", &[("rules", &app.game.rules.label(locale))])),
         Line::from(""),
     ];
     all_lines.extend(menu_items);
@@ -762,11 +1381,183 @@ fn render_game_over(app: &App, frame: &mut Frame) {
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Fin de partie"),
+                .title(locale.text("game_over.block")),
         )
         .style(Style::default().fg(Color::White));
 
-    frame.render_widget(menu, centered_rect(30, 20, frame.area()));
+    frame.render_widget(menu, centered_rect(50, 40, frame.area()));
+}
+
+/// Affiche l'écran de victoire avec le bilan de la partie
+fn render_victory(app: &App, frame: &mut Frame) {
+    let game = &app.game;
+    let locale = app.locale;
+    let score = game.score;
+    let map_name = game
+        .current_map
+        .as_ref()
+        .map(|map| map.title(locale))
+        .unwrap_or_default();
+    let duration = game.elapsed_time as u32;
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            app.glyphs
+                .text(&format!("🎉 {}", locale.text("victory.title")))
+                .to_string(),
+            Style::default()
+                .fg(Color::Green)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(locale.format("summary.map", &[("map", &map_name)])),
+        Line::from(locale.format("summary.rules", &[("rules", &game.rules.label(locale))])),
+        Line::from(""),
+        Line::from(locale.format(
+            "summary.waves_repelled",
+            &[("waves", &score.waves_survived)],
+        )),
+        Line::from(locale.format(
+            "summary.lives_left",
+            &[
+                ("life", &game.player_life),
+                ("total", &app.settings.starting_life),
+            ],
+        )),
+        Line::from(locale.format("summary.kills", &[("kills", &score.monsters_killed)])),
+        Line::from(locale.format("summary.leaks", &[("leaks", &score.leaks)])),
+        Line::from(locale.format("summary.money_earned", &[("money", &score.money_earned)])),
+        Line::from(locale.format("summary.towers", &[("towers", &game.towers.len())])),
+        Line::from(locale.format(
+            "summary.duration",
+            &[(
+                "duration",
+                &format!("{}:{:02}", duration / 60, duration % 60),
+            )],
+        )),
+        Line::from(""),
+    ];
+
+    for (index, id) in ["game_over.new_game", "menu.main_menu", "menu.quit"]
+        .iter()
+        .enumerate()
+    {
+        let label = locale.text(id);
+        lines.push(if index == app.selected_index {
+            Line::from(Span::styled(
+                format!("> {}", label),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else {
+            Line::from(format!("  {}", label))
+        });
+    }
+
+    let block = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(locale.text("game_over.block")),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(block, centered_rect(60, 80, frame.area()));
+}
+
+/// Affiche le bilan d'une partie sans fin
+fn render_endless_summary(app: &App, frame: &mut Frame) {
+    let locale = app.locale;
+    let score = app.game.score;
+    let summary = app.endless_summary.as_ref();
+    let map_name = app
+        .game
+        .current_map
+        .as_ref()
+        .map(|map| map.title(locale))
+        .unwrap_or_default();
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            locale.text("endless.title"),
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(locale.format("summary.map", &[("map", &map_name)])),
+        Line::from(locale.format("summary.rules", &[("rules", &app.game.rules.label(locale))])),
+        Line::from(""),
+        Line::from(locale.format(
+            "summary.waves_survived",
+            &[("waves", &score.waves_survived)],
+        )),
+        Line::from(locale.format("summary.kills", &[("kills", &score.monsters_killed)])),
+        Line::from(locale.format("summary.money_earned", &[("money", &score.money_earned)])),
+        Line::from(locale.format("summary.leaks", &[("leaks", &score.leaks)])),
+        Line::from(""),
+        Line::from(Span::styled(
+            locale.format("hud.score", &[("score", &score.points())]),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+    ];
+
+    match summary {
+        Some(summary) if summary.new_record => lines.push(Line::from(Span::styled(
+            app.glyphs
+                .text(&format!("🏆 {}", locale.text("endless.new_record")))
+                .to_string(),
+            Style::default().fg(Color::Green),
+        ))),
+        _ => {}
+    }
+
+    if let Some(best) = summary.and_then(|summary| summary.best) {
+        lines.push(Line::from(Span::styled(
+            locale.plural(
+                "endless.previous_best",
+                best.waves_survived,
+                &[("score", &best.points())],
+            ),
+            Style::default().fg(Color::Gray),
+        )));
+    }
+
+    if let Some(error) = summary.and_then(|summary| summary.error.as_ref()) {
+        lines.push(Line::from(Span::styled(
+            error.clone(),
+            Style::default().fg(Color::Red),
+        )));
+    }
+
+    lines.push(Line::from(""));
+    for (index, id) in ["endless.replay", "endless.other_map", "menu.main_menu"]
+        .iter()
+        .enumerate()
+    {
+        let label = locale.text(id);
+        lines.push(if index == app.selected_index {
+            Line::from(Span::styled(
+                format!("> {}", label),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else {
+            Line::from(format!("  {}", label))
+        });
+    }
+
+    let block = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(locale.text("endless.block")),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(block, centered_rect(70, 90, frame.area()));
 }
 
 /// Helper pour créer un rectangle centré
@@ -796,7 +1587,7 @@ fn render_map_selection(app: &App, frame: &mut Frame) {
 
     // Créer un titre pour l'écran
     let title = Paragraph::new(Line::from(Span::styled(
-        "SÉLECTION DE CARTE",
+        app.locale.text("map_selection.title").to_uppercase(),
         Style::default()
             .fg(Color::Yellow)
             .add_modifier(Modifier::BOLD),
@@ -819,6 +1610,7 @@ fn render_map_selection(app: &App, frame: &mut Frame) {
     frame.render_widget(title, chunks[0]);
 
     // Créer la liste des cartes
+    let locale = app.locale;
     let mut map_items = Vec::new();
 
     for (idx, map) in app.available_maps.iter().enumerate() {
@@ -842,46 +1634,735 @@ fn render_map_selection(app: &App, frame: &mut Frame) {
         }
 
         // Ajouter le nom de la carte
-        content.push(Span::styled(map.name.clone(), style));
+        content.push(Span::styled(map.title(locale).to_string(), style));
         content.push(Span::raw(" - "));
         content.push(Span::styled(
-            map.description.clone(),
+            map.summary(locale),
             Style::default().fg(Color::Gray),
         ));
 
         map_items.push(ListItem::new(Line::from(content)));
     }
 
+    // Ajouter l'entrée de la carte générée aléatoirement
+    let is_selected = app.is_random_map_selected();
+    let style = if is_selected {
+        Style::default()
+            .fg(Color::Yellow)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::White)
+    };
+
+    let mut content = vec![
+        Span::styled(if is_selected { "> " } else { "  " }, style),
+        Span::styled(locale.text("map_selection.random"), style),
+        Span::raw(" - "),
+        Span::styled(
+            locale.format(
+                "map_selection.random_details",
+                &[
+                    ("difficulty", &app.random_map_difficulty.label(locale)),
+                    ("width", &app.random_map_width),
+                    ("height", &app.random_map_height),
+                    ("seed", &app.random_map_seed),
+                ],
+            ),
+            Style::default().fg(Color::Gray),
+        ),
+    ];
+
+    if is_selected {
+        content.push(Span::styled(
+            format!("  ({})", locale.text("map_selection.random_hint")),
+            Style::default().fg(Color::DarkGray),
+        ));
+    }
+
+    map_items.push(ListItem::new(Line::from(content)));
+
     let maps_list = List::new(map_items)
         .block(
             Block::default()
                 .borders(Borders::ALL)
-                .title("Cartes disponibles"),
+                .title(locale.text("map_selection.maps")),
         )
         .highlight_style(Style::default().add_modifier(Modifier::BOLD))
         .highlight_symbol("> ");
 
-    frame.render_widget(maps_list, chunks[1]);
+    let [maps_area, options_area] =
+        Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .areas(chunks[1]);
+
+    frame.render_widget(maps_list, maps_area);
+    render_run_options(app, frame, options_area);
 
     // Ajouter des instructions
-    let instructions = Paragraph::new(Line::from(vec![
-        Span::styled("Utilisez ", Style::default().fg(Color::Gray)),
-        Span::styled(
-            "↑↓",
+    let instructions = Paragraph::new(Line::from(Span::styled(
+        locale.text("menu.hint"),
+        Style::default().fg(Color::Gray),
+    )))
+    .alignment(ratatui::layout::Alignment::Center);
+
+    frame.render_widget(instructions, chunks[2]);
+}
+
+/// Affiche la difficulté et les modificateurs de la prochaine partie
+fn render_run_options(app: &App, frame: &mut Frame, area: Rect) {
+    let locale = app.locale;
+    let selected = app.selected_run_option();
+    let preset = app.settings.difficulty.preset();
+    let mut lines = Vec::new();
+
+    for option in RunOption::all() {
+        let is_selected = selected == Some(option);
+        let style = if is_selected {
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::White)
+        };
+        let prefix = if is_selected { "> " } else { "  " };
+
+        match option {
+            RunOption::Mode => {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "{}{}: < {} >",
+                        prefix,
+                        locale.text("run.mode"),
+                        app.game_mode.label(locale)
+                    ),
+                    style,
+                )));
+            }
+            RunOption::Difficulty => {
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "{}{}: < {} >",
+                        prefix,
+                        SettingsItem::Difficulty.label(locale),
+                        app.settings.value_label(SettingsItem::Difficulty, locale)
+                    ),
+                    style,
+                )));
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "    {}",
+                        locale.format(
+                            "run.resources",
+                            &[
+                                ("money", &app.settings.starting_money),
+                                ("life", &app.settings.starting_life),
+                            ],
+                        )
+                    ),
+                    Style::default().fg(Color::Gray),
+                )));
+                lines.push(Line::from(Span::styled(
+                    format!(
+                        "    {}",
+                        locale.format("run.rewards", &[("multiplier", &preset.reward_multiplier)])
+                    ),
+                    Style::default().fg(Color::Gray),
+                )));
+                lines.push(Line::from(""));
+            }
+            RunOption::Mutator(mutator) => {
+                let checkbox = if app.mutators.contains(&mutator) {
+                    "[x]"
+                } else {
+                    "[ ]"
+                };
+                lines.push(Line::from(Span::styled(
+                    format!("{}{} {}", prefix, checkbox, mutator.label(locale)),
+                    style,
+                )));
+            }
+        }
+    }
+
+    // Description du modificateur sélectionné, sous la liste
+    if let Some(RunOption::Mutator(mutator)) = selected {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            mutator.description(locale).to_string(),
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+
+    let options = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(locale.text("run.title")),
+        )
+        .wrap(Wrap { trim: false });
+
+    frame.render_widget(options, area);
+}
+
+/// Affiche l'éditeur de cartes
+fn render_map_editor(app: &App, frame: &mut Frame) {
+    let Some(editor) = &app.map_editor else {
+        return;
+    };
+
+    let [map_area, info_area] =
+        Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .areas(frame.area());
+
+    let map = editor.to_map();
+    let path_cells = map.path_cells();
+
+    let map_text: Vec<Line> = (0..editor.height as i32)
+        .map(|y| {
+            let spans: Vec<Span> = (0..editor.width as i32)
+                .map(|x| {
+                    let position = Position::new(x, y);
+                    let is_cell = |p: &Position| p.x == x && p.y == y;
+
+                    let mut style = Style::default();
+                    let mut symbol = "· ";
+
+                    if path_cells.iter().any(is_cell) {
+                        style = style.bg(Color::DarkGray).fg(Color::White);
+                        symbol = "  ";
+                    }
+
+                    let mut glyph = (symbol, None);
+                    let mut waypoint_label = None;
+
+                    if let Some(index) = editor.waypoints.iter().position(is_cell) {
+                        if index == 0 {
+                            glyph = app.glyphs.cell_for(START_KEY, &map.start_symbol);
+                        } else if index == editor.waypoints.len() - 1 {
+                            glyph = app.glyphs.cell_for(END_KEY, &map.end_symbol);
+                        } else {
+                            waypoint_label = Some(format!("{:<2}", index % 100));
+                        }
+                    }
+
+                    if let Some(decoration) =
+                        editor.decorations.iter().find(|d| is_cell(&d.position))
+                    {
+                        glyph = app.glyphs.cell(&decoration.symbol);
+                    }
+
+                    if let Some(color) = glyph.1 {
+                        style = style.fg(color);
+                    }
+
+                    if is_cell(&editor.cursor) {
+                        style = style.bg(Color::Yellow).add_modifier(Modifier::BOLD);
+                    }
+
+                    if !map.contains(&position) {
+                        style = style.fg(Color::Red);
+                    }
+
+                    match waypoint_label {
+                        Some(label) => Span::styled(label, style),
+                        None => Span::styled(glyph.0, style),
+                    }
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect();
+
+    let map_widget = Paragraph::new(map_text).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(format!(" {} ", editor.name)),
+    );
+
+    frame.render_widget(map_widget, map_area);
+
+    let highlight = Style::default()
+        .fg(Color::Yellow)
+        .add_modifier(Modifier::BOLD);
+
+    let name = if editor.editing_name {
+        Span::styled(format!("{}_", editor.name), highlight)
+    } else {
+        Span::raw(editor.name.clone())
+    };
+
+    let locale = app.locale;
+    let mut lines = vec![
+        Line::from(vec![
+            Span::raw(format!("{}: ", locale.text("editor.name"))),
+            name,
+        ]),
+        Line::from(locale.format(
+            "editor.size",
+            &[("width", &editor.width), ("height", &editor.height)],
+        )),
+        Line::from(locale.format(
+            "editor.cursor",
+            &[("x", &editor.cursor.x), ("y", &editor.cursor.y)],
+        )),
+        Line::from(vec![
+            Span::raw(format!("{}: ", locale.text("editor.tool"))),
+            Span::styled(editor.tool.label(locale), highlight),
+        ]),
+        Line::from(locale.format(
+            "editor.symbols",
+            &[
+                ("start", &START_SYMBOLS[editor.start_symbol]),
+                ("end", &END_SYMBOLS[editor.end_symbol]),
+                ("terrain", &TERRAIN_SYMBOLS[editor.terrain_symbol]),
+            ],
+        )),
+        Line::from(locale.format("editor.waypoints", &[("count", &editor.waypoints.len())])),
+        Line::from(""),
+        Line::from(format!("{}:", locale.text("monsters.title"))),
+    ];
+
+    for (i, ((key, _), enabled)) in ROSTER.iter().zip(&editor.roster).enumerate() {
+        let mark = if *enabled { "[x]" } else { "[ ]" };
+        lines.push(Line::from(format!("  {} {} {}", i + 1, mark, key)));
+    }
+
+    lines.push(Line::from(""));
+
+    match &editor.status {
+        Some(Ok(message)) => lines.push(Line::from(message.clone().green())),
+        Some(Err(message)) => lines.push(Line::from(message.clone().red())),
+        None => {}
+    }
+
+    lines.push(Line::from(""));
+    lines.extend(
+        editor_hints(app)
+            .into_iter()
+            .map(|hint| Line::from(hint.gray())),
+    );
+
+    let info = Paragraph::new(lines).wrap(Wrap { trim: true }).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title(locale.text("menu.map_editor")),
+    );
+
+    frame.render_widget(info, info_area);
+}
+
+/// Affiche l'écran des paramètres
+fn render_settings(app: &App, frame: &mut Frame) {
+    let locale = app.locale;
+    let mut lines = vec![
+        Line::from(Span::styled(
+            locale.text("menu.settings").to_uppercase(),
             Style::default()
-                .fg(Color::White)
+                .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+    ];
+
+    for (i, item) in SettingsItem::ALL.iter().enumerate() {
+        let text = match item {
+            SettingsItem::Back => item.label(locale).to_string(),
+            _ => format!(
+                "{}: < {} >",
+                item.label(locale),
+                app.settings.value_label(*item, locale)
+            ),
+        };
+
+        lines.push(if i == app.selected_index {
+            Line::from(Span::styled(
+                format!("> {}", text),
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD),
+            ))
+        } else {
+            Line::from(Span::styled(
+                format!("  {}", text),
+                Style::default().fg(Color::White),
+            ))
+        });
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        locale.text("settings.hint"),
+        Style::default().fg(Color::Gray),
+    )));
+    lines.push(Line::from(Span::styled(
+        locale.text("settings.next_game"),
+        Style::default().fg(Color::DarkGray),
+    )));
+
+    if let Some(error) = &app.settings_error {
+        lines.push(Line::from(""));
+        lines.push(Line::from(error.clone().red()));
+    }
+
+    let menu = Paragraph::new(lines)
+        .wrap(Wrap { trim: true })
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(locale.text("menu.settings")),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(menu, centered_rect(70, 80, frame.area()));
+}
+
+/// Affiche la liste des touches configurées par-dessus la vue courante
+fn render_help(app: &App, frame: &mut Frame) {
+    let locale = app.locale;
+    let (left_lines, right_lines) = key_binding_columns(app);
+
+    let footer = vec![
+        binding_line("1-9", locale.text("help.quick_build")),
+        Line::from(
+            locale
+                .format(
+                    "help.close",
+                    &[
+                        ("help", &app.keymap.label(KeyAction::Help, app.locale)),
+                        ("cancel", &app.keymap.label(KeyAction::Cancel, app.locale)),
+                    ],
+                )
+                .gray(),
         ),
-        Span::styled(" pour naviguer et ", Style::default().fg(Color::Gray)),
+    ];
+
+    let frame_area = frame.area();
+    let width = 90.min(frame_area.width);
+    let height = (left_lines.len() as u16 + footer.len() as u16 + 3).min(frame_area.height);
+    let area = Rect::new(
+        frame_area.x + (frame_area.width - width) / 2,
+        frame_area.y + (frame_area.height - height) / 2,
+        width,
+        height,
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(locale.text("help.keys"))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(area);
+
+    let [bindings_area, footer_area] =
+        Layout::vertical([Constraint::Min(1), Constraint::Length(footer.len() as u16)])
+            .areas(inner);
+
+    frame.render_widget(Clear, area);
+    frame.render_widget(block, area);
+    render_columns(frame, bindings_area, left_lines, right_lines);
+    frame.render_widget(Paragraph::new(footer), footer_area);
+}
+
+/// Ligne "touche  description" des listes de commandes
+fn binding_line(keys: &str, label: &str) -> Line<'static> {
+    Line::from(vec![
         Span::styled(
-            "Entrée",
+            format!("{:>8}  ", keys),
             Style::default()
-                .fg(Color::White)
+                .fg(Color::Yellow)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(" pour sélectionner.", Style::default().fg(Color::Gray)),
-    ]))
-    .alignment(ratatui::layout::Alignment::Center);
+        Span::raw(label.to_string()),
+    ])
+}
 
-    frame.render_widget(instructions, chunks[2]);
+/// Aide de l'éditeur de cartes, avec les touches du jeu de touches configuré
+fn editor_hints(app: &App) -> [String; 6] {
+    let locale = app.locale;
+    let key = |action| app.keymap.label(action, locale);
+    let editor_key = |action| app.keymap.editor_label(action, locale);
+    let pair = |first, second| format!("{}/{}", editor_key(first), editor_key(second));
+
+    [
+        locale.format(
+            "editor.hint.move",
+            &[
+                (
+                    "move",
+                    &[
+                        KeyAction::MoveUp,
+                        KeyAction::MoveDown,
+                        KeyAction::MoveLeft,
+                        KeyAction::MoveRight,
+                    ]
+                    .map(key)
+                    .join(" "),
+                ),
+                ("apply", &key(KeyAction::Confirm)),
+            ],
+        ),
+        locale.format(
+            "editor.hint.tools",
+            &[
+                ("tool", &editor_key(EditorAction::NextTool)),
+                ("undo", &editor_key(EditorAction::UndoWaypoint)),
+            ],
+        ),
+        locale.format(
+            "editor.hint.size",
+            &[
+                ("width", &pair(EditorAction::Wider, EditorAction::Narrower)),
+                ("height", &pair(EditorAction::Taller, EditorAction::Shorter)),
+                ("rename", &editor_key(EditorAction::Rename)),
+            ],
+        ),
+        locale.format(
+            "editor.hint.symbols",
+            &[
+                (
+                    "symbols",
+                    &pair(EditorAction::StartSymbol, EditorAction::EndSymbol),
+                ),
+                ("terrain", &editor_key(EditorAction::TerrainSymbol)),
+            ],
+        ),
+        locale.format(
+            "editor.hint.actions",
+            &[
+                ("validate", &editor_key(EditorAction::Validate)),
+                ("save", &editor_key(EditorAction::Save)),
+            ],
+        ),
+        locale.format("editor.hint.back", &[("back", &key(KeyAction::Cancel))]),
+    ]
+}
+
+/// Touches configurées, réparties en deux colonnes pour tenir sur un terminal de taille minimale
+fn key_binding_columns(app: &App) -> (Vec<Line<'static>>, Vec<Line<'static>>) {
+    let lines = KeyAction::ALL
+        .iter()
+        .map(|action| {
+            binding_line(
+                &app.keymap.label(*action, app.locale),
+                &action.label(app.locale),
+            )
+        })
+        .collect::<Vec<_>>();
+
+    let (left, right) = lines.split_at(lines.len().div_ceil(2));
+    (left.to_vec(), right.to_vec())
+}
+
+fn render_columns(frame: &mut Frame, area: Rect, left: Vec<Line>, right: Vec<Line>) {
+    let [left_area, right_area] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)]).areas(area);
+
+    frame.render_widget(Paragraph::new(left), left_area);
+    frame.render_widget(Paragraph::new(right), right_area);
+}
+
+/// Affiche l'écran d'aide : commandes, éléments, tours et monstres
+fn render_help_view(app: &App, frame: &mut Frame) {
+    let locale = app.locale;
+    let [tabs_area, content_area, hint_area] = Layout::vertical([
+        Constraint::Length(3),
+        Constraint::Min(1),
+        Constraint::Length(1),
+    ])
+    .areas(frame.area());
+
+    let tabs = HelpPage::ALL
+        .iter()
+        .flat_map(|page| {
+            let style = if *page == app.help_page {
+                Style::default()
+                    .fg(Color::Yellow)
+                    .add_modifier(Modifier::BOLD | Modifier::REVERSED)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            [
+                Span::styled(format!(" {} ", page.label(locale)), style),
+                Span::raw(" "),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    frame.render_widget(
+        Paragraph::new(Line::from(tabs))
+            .alignment(Alignment::Center)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(locale.text("menu.help")),
+            ),
+        tabs_area,
+    );
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(app.help_page.label(locale));
+    let inner = block.inner(content_area);
+    frame.render_widget(block, content_area);
+
+    match app.help_page {
+        HelpPage::Controls => {
+            let (left, mut right) = key_binding_columns(app);
+            right.push(binding_line("1-9", locale.text("help.quick_build")));
+            render_columns(frame, inner, left, right);
+        }
+        HelpPage::Elements => render_elements_help(app, frame, inner),
+        HelpPage::Towers => render_towers_help(app, frame, inner),
+        HelpPage::Monsters => render_monsters_help(app, frame, inner),
+    }
+
+    let hint = locale.format(
+        "help.hint",
+        &[
+            (
+                "pages",
+                &format!(
+                    "{} {}",
+                    app.keymap.label(KeyAction::MoveLeft, app.locale),
+                    app.keymap.label(KeyAction::MoveRight, app.locale)
+                ),
+            ),
+            ("back", &app.keymap.label(KeyAction::Cancel, app.locale)),
+        ],
+    );
+    frame.render_widget(
+        Paragraph::new(hint.gray()).alignment(Alignment::Center),
+        hint_area,
+    );
+}
+
+/// Tableau des multiplicateurs de dégâts entre éléments (attaquant en ligne, cible en colonne)
+fn render_elements_help(app: &App, frame: &mut Frame, area: Rect) {
+    let locale = app.locale;
+    let name = |element: &Element| {
+        locale
+            .text(&format!("element.{}", element.get_name()))
+            .to_string()
+    };
+
+    let mut header = vec![Span::styled(
+        format!("{:<10}", locale.text("help.elements.header")),
+        Style::default().fg(Color::Gray),
+    )];
+    header.extend(Element::ALL.iter().map(|element| {
+        Span::styled(
+            format!("{:>7}", truncate(&name(element), 6)),
+            Style::default().add_modifier(Modifier::BOLD),
+        )
+    }));
+
+    let mut lines = vec![Line::from(header)];
+
+    for attacker in Element::ALL {
+        let mut row = vec![Span::styled(
+            format!("{:<10}", truncate(&name(&attacker), 10)),
+            Style::default().add_modifier(Modifier::BOLD),
+        )];
+
+        row.extend(Element::ALL.iter().map(|target| {
+            let factor = attacker.effectiveness_against(target);
+            let color = if factor > 1.0 {
+                Color::Green
+            } else if factor < 1.0 {
+                Color::Red
+            } else {
+                Color::DarkGray
+            };
+
+            Span::styled(format!("{:>7}", format!("x{}", factor)), color)
+        }));
+
+        lines.push(Line::from(row));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(locale.text("help.elements.legend").gray()));
+
+    frame.render_widget(Paragraph::new(lines), area);
+}
+
+fn render_towers_help(app: &App, frame: &mut Frame, area: Rect) {
+    let mut lines = Vec::new();
+
+    for (i, tower) in app.available_towers.iter().enumerate() {
+        lines.push(Line::from(
+            app.glyphs
+                .text(&format!(
+                    "{}. {} {} - 💰 {}",
+                    i + 1,
+                    tower.symbol,
+                    tower.name,
+                    tower.cost
+                ))
+                .to_string()
+                .yellow()
+                .bold(),
+        ));
+
+        for stat in &tower.stats {
+            let max_level = stat
+                .upgrade
+                .as_ref()
+                .map_or("-".to_string(), |upgrade| upgrade.max_level.to_string());
+
+            lines.push(Line::from(
+                app.glyphs
+                    .text(&format!(
+                        "   {} {}",
+                        stat.icon,
+                        app.locale.format(
+                            "help.towers.stat",
+                            &[
+                                ("stat", &stat.label(app.locale)),
+                                ("value", &format!("{:.2}", stat.base)),
+                                ("max_level", &max_level),
+                            ],
+                        )
+                    ))
+                    .to_string(),
+            ));
+        }
+
+        lines.push(Line::from(""));
+    }
+
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
+}
+
+fn render_monsters_help(app: &App, frame: &mut Frame, area: Rect) {
+    let mut lines = Vec::new();
+
+    for (_, build) in ROSTER {
+        let monster = build(Position::initial());
+
+        lines.push(Line::from(
+            app.glyphs
+                .text(&format!("{} {}", monster.symbol, monster.name))
+                .to_string()
+                .yellow()
+                .bold(),
+        ));
+        lines.push(Line::from(format!(
+            "   {}",
+            app.locale.format(
+                "help.monsters.stats",
+                &[
+                    ("hp", &format!("{:.0}", monster.hp)),
+                    ("speed", &format!("{:.1}", monster.movement_speed)),
+                    ("armor", &format!("{:.0}", monster.armor * 100.0)),
+                    ("damage", &monster.damage_to_player),
+                ],
+            )
+        )));
+        lines.push(Line::from(""));
+    }
+
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), area);
+}
+
+fn truncate(text: &str, max: usize) -> String {
+    text.chars().take(max).collect()
 }
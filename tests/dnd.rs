@@ -0,0 +1,114 @@
+use rust_tower::domain::{
+    i18n::Locale,
+    ports::notifier::Notifier,
+    services::notifications::{
+        desktop::DesktopNotifier,
+        dnd::{AnyDndProbe, CommandDndProbe, DndProbe, FixedDndProbe},
+    },
+};
+
+/// Sonde en erreur, comme une commande absente ou une réponse illisible
+struct FailingDndProbe;
+
+impl DndProbe for FailingDndProbe {
+    fn is_dnd_enabled(&self, _locale: Locale) -> Result<bool, String> {
+        Err("Sonde indisponible".to_string())
+    }
+}
+
+fn parse(output: &str) -> Option<bool> {
+    match output.trim() {
+        "on" => Some(true),
+        "off" => Some(false),
+        _ => None,
+    }
+}
+
+#[test]
+fn fixed_probe_answers_its_value() {
+    assert_eq!(FixedDndProbe(true).is_dnd_enabled(Locale::Fr), Ok(true));
+    assert_eq!(FixedDndProbe(false).is_dnd_enabled(Locale::Fr), Ok(false));
+}
+
+#[test]
+fn command_probe_fails_when_the_program_is_missing() {
+    let probe = CommandDndProbe::new("tower-defense-missing-dnd-probe", &[], parse);
+
+    assert!(probe.is_dnd_enabled(Locale::Fr).is_err());
+}
+
+#[cfg(unix)]
+#[test]
+fn command_probe_parses_the_program_output() {
+    assert_eq!(
+        CommandDndProbe::new("echo", &["on"], parse).is_dnd_enabled(Locale::Fr),
+        Ok(true)
+    );
+    assert_eq!(
+        CommandDndProbe::new("echo", &["off"], parse).is_dnd_enabled(Locale::Fr),
+        Ok(false)
+    );
+    assert!(
+        CommandDndProbe::new("echo", &["unknown"], parse)
+            .is_dnd_enabled(Locale::Fr)
+            .is_err()
+    );
+}
+
+#[test]
+fn any_probe_is_enabled_as_soon_as_one_probe_is() {
+    let probe = AnyDndProbe::new(vec![
+        Box::new(FailingDndProbe),
+        Box::new(FixedDndProbe(false)),
+        Box::new(FixedDndProbe(true)),
+    ]);
+
+    assert_eq!(probe.is_dnd_enabled(Locale::Fr), Ok(true));
+}
+
+#[test]
+fn any_probe_falls_back_to_the_probes_that_answer() {
+    let probe = AnyDndProbe::new(vec![
+        Box::new(CommandDndProbe::new(
+            "tower-defense-missing-dnd-probe",
+            &[],
+            parse,
+        )),
+        Box::new(FixedDndProbe(false)),
+        Box::new(FailingDndProbe),
+    ]);
+
+    assert_eq!(probe.is_dnd_enabled(Locale::Fr), Ok(false));
+}
+
+#[test]
+fn any_probe_fails_when_no_probe_answers() {
+    assert!(AnyDndProbe::new(vec![]).is_dnd_enabled(Locale::Fr).is_err());
+    assert!(
+        AnyDndProbe::new(vec![Box::new(FailingDndProbe), Box::new(FailingDndProbe)])
+            .is_dnd_enabled(Locale::Fr)
+            .is_err()
+    );
+}
+
+#[test]
+fn desktop_notifier_is_silent_in_do_not_disturb_mode() {
+    let notifier = DesktopNotifier::new().with_dnd_probe(FixedDndProbe(true));
+
+    assert_eq!(notifier.is_dnd_enabled(), Ok(true));
+    assert!(!notifier.can_send_message());
+    // Rien n'est affiché, l'envoi réussit sans solliciter le bureau
+    assert_eq!(
+        notifier.send_notification("Tower Defense", "Vague 1"),
+        Ok(())
+    );
+}
+
+#[cfg(target_os = "linux")]
+#[test]
+fn desktop_notifier_sends_when_the_probe_fails() {
+    let notifier = DesktopNotifier::new().with_dnd_probe(FailingDndProbe);
+
+    assert!(notifier.is_dnd_enabled().is_err());
+    assert!(notifier.can_send_message());
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use rust_tower::{
+    application::engine::{monsters::basics::orc, towers::fire_tower::FireTower},
+    domain::{
+        entities::{
+            components::{Components, EntityId},
+            game::Game,
+            map::Map,
+            position::Position,
+            tower::{Tower, TowerStatType},
+            wave::Wave,
+        },
+        mediator::MediatorService,
+        services::notifications::recording::RecordingNotifier,
+    },
+};
+
+const FIRST: Position = Position { x: 3, y: 0 };
+const SECOND: Position = Position { x: 5, y: 0 };
+
+fn setup() -> Game {
+    let mediator = Arc::new(MediatorService::boxed(RecordingNotifier::new()));
+    let mut game = Game::new(mediator, vec![], 10, 1.0);
+    game.money = 10_000;
+
+    let start = Position::new(0, 2);
+    game.current_map = Some(Map::new(
+        "Couloir".to_string(),
+        String::new(),
+        "S".to_string(),
+        "E".to_string(),
+        vec![start, Position::new(19, 2)],
+        20,
+        5,
+        vec![orc(start)],
+        None,
+    ));
+
+    // Les deux tours sont copiées du même modèle, comme depuis le catalogue de l'interface
+    let template = FireTower::positionned(Position::new(0, 0));
+    for position in [FIRST, SECOND] {
+        game.place_tower(template.clone(), position)
+            .expect("case libre");
+    }
+
+    game
+}
+
+fn id_at(game: &Game, position: Position) -> EntityId {
+    game.tower_at(position).expect("tour construite").id
+}
+
+fn damage(game: &Game, id: EntityId) -> Option<f32> {
+    game.towers.entity(id)?.stat_block.damage
+}
+
+#[test]
+fn towers_built_from_one_template_get_their_own_id() {
+    let game = setup();
+    let template = FireTower::positionned(Position::new(0, 0));
+
+    let (first, second) = (id_at(&game, FIRST), id_at(&game, SECOND));
+    assert_ne!(first, second);
+    assert_ne!(first, template.id);
+    assert_eq!(game.towers.len(), 2);
+    assert_eq!(game.towers.entity(first).map(|t| t.position), Some(FIRST));
+    assert_eq!(game.towers.entity(second).map(|t| t.position), Some(SECOND));
+}
+
+#[test]
+fn each_tower_is_upgraded_by_its_own_id() {
+    let mut game = setup();
+    let (first, second) = (id_at(&game, FIRST), id_at(&game, SECOND));
+    let base = damage(&game, first);
+
+    game.upgrade_tower(first, TowerStatType::Damage).unwrap();
+    assert_ne!(damage(&game, first), base);
+    assert_eq!(damage(&game, second), base);
+
+    game.upgrade_tower(second, TowerStatType::Damage).unwrap();
+    assert_eq!(damage(&game, second), damage(&game, first));
+}
+
+#[test]
+fn each_tower_is_sold_on_its_own() {
+    let mut game = setup();
+    let (first, second) = (id_at(&game, FIRST), id_at(&game, SECOND));
+
+    game.sell_tower(FIRST).unwrap();
+    assert!(game.towers.entity(first).is_none());
+    assert_eq!(game.towers.entity(second).map(|t| t.position), Some(SECOND));
+
+    game.sell_tower(SECOND).unwrap();
+    assert!(game.towers.is_empty());
+}
+
+#[test]
+fn components_reject_a_duplicate_id() {
+    let tower = FireTower::positionned(Position::new(1, 1));
+    let mut towers: Components<Tower> = Components::new();
+
+    assert_eq!(towers.insert(tower.clone()), Some(tower.id));
+    assert_eq!(towers.insert(tower.clone()), None);
+    assert_eq!(towers.len(), 1);
+
+    let towers = Components::from(vec![tower.clone(), tower]);
+    assert_eq!(towers.len(), 1);
+}
+
+#[test]
+fn wave_monsters_copied_from_one_template_get_their_own_id() {
+    let template = orc(Position::new(0, 2));
+
+    for wave in [
+        Wave::new(Some(vec![template.clone(), template.clone()])),
+        Wave::with_staggered_spawn(vec![template.clone(), template.clone()], 0.5),
+    ] {
+        assert_eq!(wave.monsters.len(), 2);
+        assert_ne!(wave.monsters[0].id, wave.monsters[1].id);
+    }
+}
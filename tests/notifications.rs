@@ -0,0 +1,155 @@
+use std::{thread, time::Duration};
+
+use rust_tower::domain::{
+    i18n::Locale,
+    mediator::Mediator,
+    services::notifications::{
+        composite::CompositeNotifier,
+        policy::{Notification, NotificationCategory, NotificationPolicy},
+        recording::{RecordingNotifier, SentNotification},
+    },
+};
+
+const INTERVAL: Duration = Duration::from_millis(50);
+
+fn policy() -> NotificationPolicy {
+    NotificationPolicy {
+        min_interval: INTERVAL,
+        quiet_when_focused: false,
+        ..NotificationPolicy::default()
+    }
+}
+
+fn notification(category: NotificationCategory, message: &str) -> Notification {
+    Notification::new(category, "Tower Defense", message, Locale::Fr)
+}
+
+fn sent(message: &str) -> SentNotification {
+    SentNotification {
+        title: "Tower Defense".to_string(),
+        message: message.to_string(),
+    }
+}
+
+#[test]
+fn mediator_delivers_admitted_notifications() {
+    let recorder = RecordingNotifier::new();
+    let mediator = Mediator::new(recorder.clone()).with_policy(policy());
+
+    mediator
+        .notify(notification(NotificationCategory::Boss, "Un boss arrive"))
+        .unwrap();
+    mediator
+        .notify(notification(
+            NotificationCategory::LowLife,
+            "Plus que 3 vies",
+        ))
+        .unwrap();
+
+    assert_eq!(
+        recorder.sent(),
+        vec![sent("Un boss arrive"), sent("Plus que 3 vies")]
+    );
+}
+
+#[test]
+fn throttled_notifications_are_coalesced_on_flush() {
+    let recorder = RecordingNotifier::new();
+    let mediator = Mediator::new(recorder.clone()).with_policy(policy());
+
+    for message in ["Vague 1 terminée", "Vague 2 terminée", "Vague 3 terminée"] {
+        mediator
+            .notify(notification(NotificationCategory::WaveCleared, message))
+            .unwrap();
+    }
+    // Les deux dernières sont retenues tant que le délai n'est pas écoulé
+    mediator.flush().unwrap();
+    assert_eq!(recorder.sent(), vec![sent("Vague 1 terminée")]);
+
+    thread::sleep(INTERVAL);
+    mediator.flush().unwrap();
+    assert_eq!(
+        recorder.sent(),
+        vec![sent("Vague 1 terminée"), sent("2 vagues terminées")]
+    );
+}
+
+#[test]
+fn high_priority_notifications_are_never_throttled() {
+    let recorder = RecordingNotifier::new();
+    let mediator = Mediator::new(recorder.clone()).with_policy(policy());
+
+    mediator
+        .notify(notification(
+            NotificationCategory::GameOver,
+            "Partie perdue",
+        ))
+        .unwrap();
+    mediator
+        .notify(notification(
+            NotificationCategory::GameOver,
+            "Partie perdue",
+        ))
+        .unwrap();
+
+    assert_eq!(recorder.sent().len(), 2);
+}
+
+#[test]
+fn disabled_categories_are_dropped() {
+    let recorder = RecordingNotifier::new();
+    let mediator = Mediator::new(recorder.clone()).with_policy(NotificationPolicy {
+        disabled_categories: vec![NotificationCategory::MonsterDetected],
+        ..policy()
+    });
+
+    mediator
+        .notify(notification(
+            NotificationCategory::MonsterDetected,
+            "Monstre détecté",
+        ))
+        .unwrap();
+    thread::sleep(INTERVAL * 2);
+    mediator.flush().unwrap();
+
+    assert!(recorder.sent().is_empty());
+}
+
+#[test]
+fn focused_terminal_keeps_normal_notifications_in_game() {
+    let recorder = RecordingNotifier::new();
+    let mediator = Mediator::new(recorder.clone()).with_policy(NotificationPolicy {
+        quiet_when_focused: true,
+        ..policy()
+    });
+    mediator.set_focused(true);
+
+    // Le `RecordingNotifier` n'est pas affiché dans le terminal : il ignore ces notifications
+    mediator
+        .notify(notification(NotificationCategory::Boss, "Un boss arrive"))
+        .unwrap();
+    mediator
+        .notify(notification(
+            NotificationCategory::GameOver,
+            "Partie perdue",
+        ))
+        .unwrap();
+
+    assert_eq!(recorder.sent(), vec![sent("Partie perdue")]);
+}
+
+#[test]
+fn composite_fans_out_mediated_notifications() {
+    let (first, second) = (RecordingNotifier::new(), RecordingNotifier::new());
+    let composite = CompositeNotifier::new()
+        .with_notifier(first.clone())
+        .with_notifier(second.clone());
+    let mediator = Mediator::new(composite).with_policy(policy());
+
+    mediator
+        .notify(notification(NotificationCategory::Boss, "Un boss arrive"))
+        .unwrap();
+
+    assert_eq!(first.sent(), vec![sent("Un boss arrive")]);
+    assert_eq!(second.sent(), first.sent());
+}
@@ -0,0 +1,82 @@
+use rust_tower::{
+    application::engine::{
+        campaign,
+        maps::{
+            cave::CaveMap,
+            desert::DesertMap,
+            forest::ForestMap,
+            random::{MapDifficulty, RandomMap},
+        },
+    },
+    domain::entities::{map::Map, position::Position},
+};
+
+/// Pas d'échantillonnage du trajet, en cases
+const STEP: f32 = 0.05;
+
+fn maps() -> Vec<Map> {
+    let mut maps = vec![CaveMap::new(), ForestMap::new(), DesertMap::new()];
+    maps.extend(campaign::levels().iter().map(|level| (level.map)()));
+    for seed in 0..20 {
+        for difficulty in [
+            MapDifficulty::Easy,
+            MapDifficulty::Normal,
+            MapDifficulty::Hard,
+        ] {
+            maps.push(RandomMap::new(seed, 30, 20, difficulty).generate());
+        }
+    }
+    maps
+}
+
+#[test]
+fn monsters_walk_on_the_drawn_path() {
+    for map in maps() {
+        let cells = map.path_cells();
+        let samples = (map.path_length() / STEP).ceil() as usize;
+
+        for sample in 0..=samples {
+            let progress = sample as f32 * STEP;
+            let cell = map.point_at(progress).expect("trajet non vide").cell();
+            assert!(
+                cells.contains(&cell),
+                "{} : {:?} hors du chemin à {}",
+                map.name,
+                cell,
+                progress
+            );
+        }
+    }
+}
+
+#[test]
+fn towers_cannot_be_built_on_the_drawn_path() {
+    for map in maps() {
+        let cells = map.path_cells();
+
+        for x in 0..map.width as i32 {
+            for y in 0..map.height as i32 {
+                let position = Position::new(x, y);
+                assert_eq!(
+                    map.is_position_on_path(&position),
+                    cells.contains(&position),
+                    "{} : {:?}",
+                    map.name,
+                    position
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn diagonal_segments_turn_at_a_corner() {
+    let map = CaveMap::new();
+    let length = map.path_length();
+
+    assert_eq!(map.path_cells().len() as f32, length + 1.0);
+    assert_eq!(
+        map.point_at(length).map(|point| point.cell()),
+        map.waypoints.last().copied()
+    );
+}
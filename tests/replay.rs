@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use rust_tower::{
+    application::engine::{monsters::basics::orc, towers::fire_tower::FireTower},
+    domain::{
+        entities::{
+            command::{GameCommand, GameEngine},
+            event::{GameEvent, GameEventSubscriber, REPLAY_EVENT_LIMIT, Replay},
+            game::Game,
+            map::Map,
+            position::Position,
+        },
+        mediator::MediatorService,
+        services::notifications::recording::RecordingNotifier,
+    },
+};
+
+fn money_changed(total: u32) -> GameEvent {
+    GameEvent::MoneyChanged { delta: 1, total }
+}
+
+fn setup() -> Game {
+    let mediator = Arc::new(MediatorService::boxed(RecordingNotifier::new()));
+    let mut game = Game::new(mediator, vec![], 10, 1.0);
+    game.money = 10_000;
+
+    let start = Position::new(0, 2);
+    game.current_map = Some(Map::new(
+        "Couloir".to_string(),
+        String::new(),
+        "S".to_string(),
+        "E".to_string(),
+        vec![start, Position::new(19, 2)],
+        20,
+        5,
+        vec![orc(start)],
+        None,
+    ));
+
+    game
+}
+
+#[test]
+fn replay_forgets_the_oldest_events_beyond_its_limit() {
+    let mut replay = Replay::default().with_limit(3);
+
+    for total in 0..5 {
+        replay.on_event(&money_changed(total), total as f32);
+    }
+
+    assert_eq!(replay.recorded, 5);
+    let totals: Vec<u32> = replay
+        .events
+        .iter()
+        .map(|recorded| match recorded.event {
+            GameEvent::MoneyChanged { total, .. } => total,
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(totals, vec![2, 3, 4]);
+    assert_eq!(replay.since(3).count(), 2);
+    assert_eq!(replay.since(0).count(), 3);
+}
+
+#[test]
+fn long_games_keep_a_bounded_replay() {
+    let mut game = setup();
+
+    for total in 0..REPLAY_EVENT_LIMIT as u32 + 100 {
+        game.emit(money_changed(total));
+    }
+
+    assert_eq!(game.replay.events.len(), REPLAY_EVENT_LIMIT);
+    assert_eq!(game.replay.recorded, REPLAY_EVENT_LIMIT + 100);
+    assert!(game.logs.len() <= game.log_limit);
+}
+
+#[test]
+fn commands_return_their_events_once_the_replay_is_full() {
+    let mut game = setup();
+    // Chaque construction émet deux événements : la première est oubliée par la seconde
+    game.replay = Replay::default().with_limit(2);
+
+    for x in [3, 5] {
+        let events = game
+            .execute(GameCommand::PlaceTower {
+                tower: FireTower::positionned(Position::new(0, 0)),
+                position: Position::new(x, 0),
+            })
+            .expect("case libre");
+
+        assert_eq!(events.len(), 2);
+        assert!(matches!(events[0], GameEvent::TowerPlaced { .. }));
+        assert!(matches!(events[1], GameEvent::MoneyChanged { .. }));
+    }
+    assert_eq!(game.replay.events.len(), 2);
+    assert_eq!(game.replay.recorded, 4);
+}
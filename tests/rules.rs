@@ -0,0 +1,43 @@
+use rust_tower::{
+    application::engine::monsters::basics::{goblin, orc},
+    domain::entities::{
+        element::Element,
+        monster::Monster,
+        position::Position,
+        rules::{Difficulty, MAX_ARMOR, Mutator, RunRules},
+    },
+};
+
+const DAMAGE: f32 = 100.0;
+
+fn mitigated(rules: &RunRules, mut monster: Monster) -> f32 {
+    rules.apply_to_monster(&mut monster);
+    monster.mitigate_damage(DAMAGE, &Element::Neutral)
+}
+
+#[test]
+fn double_armor_reduces_damage_to_unarmored_monsters() {
+    let standard = RunRules::new(Difficulty::Normal, vec![]);
+    let double_armor = RunRules::new(Difficulty::Normal, vec![Mutator::DoubleArmor]);
+
+    for monster in [goblin(Position::new(0, 0)), orc(Position::new(0, 0))] {
+        assert_eq!(monster.armor, 0.0);
+        let before = mitigated(&standard, monster.clone());
+        let after = mitigated(&double_armor, monster);
+        assert!(after < before, "{} < {}", after, before);
+        assert!((after - 75.0).abs() < 1e-3, "{}", after);
+    }
+}
+
+#[test]
+fn double_armor_doubles_existing_armor_up_to_the_cap() {
+    let rules = RunRules::new(Difficulty::Normal, vec![Mutator::DoubleArmor]);
+
+    let mut armored = orc(Position::new(0, 0));
+    armored.armor = 0.3;
+    assert!((mitigated(&rules, armored.clone()) - 40.0).abs() < 1e-3);
+
+    armored.armor = 0.6;
+    rules.apply_to_monster(&mut armored);
+    assert_eq!(armored.armor, MAX_ARMOR);
+}
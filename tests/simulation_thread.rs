@@ -0,0 +1,120 @@
+use std::{
+    sync::{Arc, mpsc},
+    thread,
+};
+
+use rust_tower::{
+    application::engine::{
+        maps::terrain::Swamp,
+        monsters::basics::orc,
+        towers::{fire_tower::FireTower, mine_tower::MineTower, sentinel_tower::SentinelTower},
+    },
+    domain::{
+        entities::{
+            game::Game,
+            map::Map,
+            position::{Point, Position},
+            wave::Wave,
+        },
+        mediator::MediatorService,
+        services::notifications::recording::RecordingNotifier,
+    },
+};
+
+const FRAME: f32 = 1.0 / 30.0;
+const FRAMES: usize = 150;
+
+/// État de la partie transmis au thread de l'interface après chaque image
+struct Snapshot {
+    money: u32,
+    monsters: Vec<Point>,
+}
+
+impl Snapshot {
+    fn of(game: &Game) -> Self {
+        Self {
+            money: game.money,
+            monsters: game
+                .current_wave
+                .iter()
+                .flat_map(|wave| &wave.monsters)
+                .filter(|monster| monster.active)
+                .map(|monster| monster.position)
+                .collect(),
+        }
+    }
+}
+
+fn setup(notifier: RecordingNotifier) -> Game {
+    let mediator = Arc::new(MediatorService::boxed(notifier));
+    let mut game = Game::new(mediator, vec![], i32::MAX, 1.0);
+    game.money = 1_000;
+
+    let start = Position::new(0, 2);
+    game.current_map = Some(
+        Map::new(
+            "Couloir".to_string(),
+            String::new(),
+            "S".to_string(),
+            "E".to_string(),
+            vec![start, Position::new(19, 2)],
+            20,
+            5,
+            vec![orc(start)],
+            None,
+        )
+        .with_terrain(Arc::new(Swamp)),
+    );
+
+    for tower in [
+        FireTower::positionned(Position::new(5, 3)),
+        SentinelTower::positionned(Position::new(3, 1)),
+        MineTower::positionned(Position::new(15, 0)),
+    ] {
+        let position = tower.position;
+        game.place_tower(tower, position).expect("case libre");
+    }
+
+    let monsters = (0..5)
+        .map(|_| {
+            let mut monster = orc(start);
+            monster.hp = f32::MAX;
+            monster
+        })
+        .collect();
+    game.wave_index = 1;
+    game.current_wave = Some(Wave::with_staggered_spawn(monsters, 0.2));
+
+    game
+}
+
+#[test]
+fn game_runs_on_a_worker_thread_while_snapshots_are_rendered() {
+    let notifier = RecordingNotifier::new();
+    let game = setup(notifier.clone());
+    let money_after_setup = game.money;
+    let (sender, receiver) = mpsc::channel();
+
+    let worker = thread::spawn(move || {
+        let mut game = game;
+        for _ in 0..FRAMES {
+            game.update(FRAME);
+            if sender.send(Snapshot::of(&game)).is_err() {
+                break;
+            }
+        }
+        game
+    });
+
+    // Le thread de l'interface ne voit la partie qu'au travers des instantanés
+    let snapshots: Vec<Snapshot> = receiver.iter().collect();
+    let game = worker.join().expect("la simulation ne panique pas");
+
+    assert_eq!(snapshots.len(), FRAMES);
+    let last = snapshots.last().unwrap();
+    assert_eq!(last.money, game.money);
+    assert!(last.money > money_after_setup, "la mine a rapporté");
+    assert_eq!(last.monsters.len(), 5);
+    assert!(last.monsters.iter().all(|position| position.x > 0.0));
+    assert!(!notifier.sent().is_empty(), "la sentinelle a notifié");
+}
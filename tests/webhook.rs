@@ -0,0 +1,186 @@
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::TcpListener,
+    sync::mpsc::{self, Receiver},
+    thread,
+    time::{Duration, Instant},
+};
+
+use rust_tower::domain::{
+    i18n::Locale,
+    ports::notifier::{NotificationContext, Notifier},
+    services::notifications::webhook::WebhookNotifier,
+};
+use uuid::Uuid;
+
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Requête reçue par le serveur de test
+struct Received {
+    request_line: String,
+    headers: Vec<String>,
+    body: String,
+}
+
+/// Serveur HTTP local répondant successivement les statuts donnés
+///
+/// Renvoie l'adresse du webhook et les requêtes reçues.
+fn serve(statuses: &'static [u16]) -> (String, Receiver<Received>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/hooks/tower", listener.local_addr().unwrap());
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        for status in statuses {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut headers = Vec::new();
+            loop {
+                let mut header = String::new();
+                reader.read_line(&mut header).unwrap();
+                if header.trim().is_empty() {
+                    break;
+                }
+                headers.push(header.trim().to_string());
+            }
+
+            let length = headers
+                .iter()
+                .find_map(|header| header.strip_prefix("Content-Length: "))
+                .map_or(0, |length| length.parse().unwrap());
+            let mut body = vec![0; length];
+            reader.read_exact(&mut body).unwrap();
+
+            let response = format!("HTTP/1.1 {} Test\r\nContent-Length: 0\r\n\r\n", status);
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+
+            let received = Received {
+                request_line: request_line.trim().to_string(),
+                headers,
+                body: String::from_utf8(body).unwrap(),
+            };
+            if sender.send(received).is_err() {
+                break;
+            }
+        }
+    });
+
+    (url, receiver)
+}
+
+/// Attend que le thread d'envoi ait enregistré son résultat
+fn wait_for_error(notifier: &WebhookNotifier) -> Option<String> {
+    let start = Instant::now();
+    while start.elapsed() < TIMEOUT {
+        if let Some(error) = notifier.last_error() {
+            return Some(error);
+        }
+        thread::sleep(Duration::from_millis(10));
+    }
+    None
+}
+
+#[test]
+fn posts_the_notification_as_json() {
+    let (url, requests) = serve(&[200]);
+    let notifier = WebhookNotifier::new(&url, Locale::Fr).unwrap();
+    let context = NotificationContext {
+        game_id: Uuid::nil(),
+        wave: 12,
+        life: 3,
+    };
+
+    notifier
+        .send_with_context("Tower Defense", "Vague \"12\" terminée", &context)
+        .unwrap();
+    let request = requests.recv_timeout(TIMEOUT).unwrap();
+
+    assert_eq!(request.request_line, "POST /hooks/tower HTTP/1.1");
+    assert!(
+        request
+            .headers
+            .contains(&"Content-Type: application/json".to_string())
+    );
+    assert_eq!(
+        request.body,
+        "{\"title\":\"Tower Defense\",\"message\":\"Vague \\\"12\\\" terminée\",\
+         \"game_id\":\"00000000-0000-0000-0000-000000000000\",\"wave\":12,\"life\":3}"
+    );
+}
+
+#[test]
+fn posts_null_context_without_a_game() {
+    let (url, requests) = serve(&[204]);
+    let notifier = WebhookNotifier::new(&url, Locale::Fr).unwrap();
+
+    notifier
+        .send_notification("Tower Defense", "Bonjour")
+        .unwrap();
+    let request = requests.recv_timeout(TIMEOUT).unwrap();
+
+    assert_eq!(
+        request.body,
+        "{\"title\":\"Tower Defense\",\"message\":\"Bonjour\",\"game_id\":null,\"wave\":null,\"life\":null}"
+    );
+}
+
+#[test]
+fn retries_after_a_server_error() {
+    let (url, requests) = serve(&[500, 200]);
+    let notifier = WebhookNotifier::new(&url, Locale::Fr)
+        .unwrap()
+        .with_retries(2);
+
+    notifier.send_notification("Tower Defense", "Boss").unwrap();
+    let first = requests.recv_timeout(TIMEOUT).unwrap();
+    let second = requests.recv_timeout(TIMEOUT).unwrap();
+
+    assert_eq!(first.body, second.body);
+    // Le second envoi a réussi : aucune nouvelle tentative
+    assert!(requests.recv_timeout(Duration::from_secs(1)).is_err());
+    assert_eq!(notifier.last_error(), None);
+}
+
+#[test]
+fn keeps_the_error_once_retries_are_exhausted() {
+    let (url, requests) = serve(&[500, 503, 200]);
+    let notifier = WebhookNotifier::new(&url, Locale::Fr)
+        .unwrap()
+        .with_retries(1);
+
+    notifier.send_notification("Tower Defense", "Boss").unwrap();
+
+    assert!(requests.recv_timeout(TIMEOUT).is_ok());
+    assert!(requests.recv_timeout(TIMEOUT).is_ok());
+    assert_eq!(
+        wait_for_error(&notifier),
+        Some("Le webhook a répondu 503".to_string())
+    );
+    assert!(requests.recv_timeout(Duration::from_secs(1)).is_err());
+}
+
+#[test]
+fn sending_never_waits_for_the_server() {
+    // La connexion aboutit, mais personne ne lit la requête ni ne répond
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}/", listener.local_addr().unwrap());
+    let notifier = WebhookNotifier::new(&url, Locale::Fr)
+        .unwrap()
+        .with_timeout(Duration::from_millis(200))
+        .with_retries(0);
+
+    let start = Instant::now();
+    notifier.send_notification("Tower Defense", "Boss").unwrap();
+    assert!(start.elapsed() < Duration::from_millis(100));
+
+    let error = wait_for_error(&notifier).unwrap();
+    assert!(
+        error.starts_with("Réponse du webhook illisible"),
+        "{}",
+        error
+    );
+    drop(listener);
+}